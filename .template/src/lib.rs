@@ -0,0 +1,41 @@
+//! Day DAY_NUMBER
+
+use aoc_core::{Answer, Cursor, ParseError, Solution};
+
+/// Solves day DAY_NUMBER
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input = Vec<String>;
+    const DAY: u8 = DAY_NUMBER;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<String>, ParseError> {
+        v.enumerate()
+            .map(|(i, line)| Ok(Cursor::new(i + 1, &line).rest().to_string()))
+            .collect()
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, String> {
+        Ok(0.into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, String> {
+        Ok(0.into())
+    }
+}
+
+#[test]
+fn dayD_part1_test() {
+    let v = EXAMPLE;
+    let answer = DayNN::part1(&DayNN::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(ANSWER1, answer);
+}
+
+#[test]
+fn dayD_part2_test() {
+    let v = EXAMPLE;
+    let answer = DayNN::part2(&DayNN::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(ANSWER2, answer);
+}
@@ -0,0 +1,102 @@
+//! Puzzles whose state changes one step at a time, drawn as text frames so
+//! they can be watched in a terminal or saved for tests.
+
+use crate::{ParseError, Solution};
+
+/// A puzzle state that can be advanced and drawn
+pub trait Simulation {
+    /// Advances by one step, returning false once there is nothing left to do
+    fn step(&mut self) -> bool;
+
+    /// The current state, one line per row
+    fn frame(&self) -> String;
+}
+
+/// Parses an input and starts a simulation on it, see [`animate`]
+pub type Animator = fn(&str) -> Result<Box<dyn Simulation>, ParseError>;
+
+/// Parses `input` with `S` and builds the simulation `A` from the result
+pub fn animate<S, A>(input: &str) -> Result<Box<dyn Simulation>, ParseError>
+where
+    S: Solution,
+    A: Simulation + From<S::Input> + 'static,
+{
+    let parsed = S::parse(input.lines().map(String::from))?;
+    Ok(Box::new(A::from(parsed)))
+}
+
+/// The initial frame followed by one frame per step, until the simulation ends
+pub fn frames<'a>(sim: &'a mut (dyn Simulation + 'a)) -> impl Iterator<Item = String> + 'a {
+    let mut started = false;
+    std::iter::from_fn(move || {
+        if started && !sim.step() {
+            return None;
+        }
+        started = true;
+        Some(sim.frame())
+    })
+}
+
+#[cfg(test)]
+struct Countdown(u32);
+
+#[cfg(test)]
+impl Simulation for Countdown {
+    fn step(&mut self) -> bool {
+        if self.0 == 0 {
+            return false;
+        }
+        self.0 -= 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        format!("{}\n", self.0)
+    }
+}
+
+#[cfg(test)]
+impl From<Vec<u32>> for Countdown {
+    fn from(v: Vec<u32>) -> Countdown {
+        Countdown(v[0])
+    }
+}
+
+#[test]
+fn frames_test() {
+    let mut sim = Countdown(2);
+    assert_eq!(
+        vec!["2\n", "1\n", "0\n"],
+        frames(&mut sim).collect::<Vec<_>>()
+    );
+    assert_eq!(0, frames(&mut sim).skip(1).count());
+}
+
+#[test]
+fn animate_test() {
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input = Vec<u32>;
+        const DAY: u8 = 1;
+
+        fn parse(v: impl Iterator<Item = String>) -> Result<Vec<u32>, ParseError> {
+            v.enumerate()
+                .map(|(i, x)| crate::Cursor::new(i + 1, &x).number())
+                .collect()
+        }
+        fn part1(_: &Vec<u32>) -> Result<crate::Answer, String> {
+            Ok(0.into())
+        }
+        fn part2(_: &Vec<u32>) -> Result<crate::Answer, String> {
+            Ok(0.into())
+        }
+    }
+
+    let mut sim = animate::<Numbers, Countdown>("3").unwrap();
+    assert_eq!(4, frames(sim.as_mut()).count());
+    assert_eq!(
+        Some(ParseError::new(1, 1, "a number")),
+        animate::<Numbers, Countdown>("x").err()
+    );
+}
@@ -0,0 +1,95 @@
+use std::fmt;
+
+/// A puzzle answer. Wide enough that no part has to squeeze its result into
+/// an `i32`, and two answers are equal when they render the same.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::I64(n) => write!(f, "{}", n),
+            Answer::U64(n) => write!(f, "{}", n),
+            Answer::I128(n) => write!(f, "{}", n),
+            Answer::U128(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl Answer {
+    /// The name of the type the answer is held as
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::I64(_) => "i64",
+            Answer::U64(_) => "u64",
+            Answer::I128(_) => "i128",
+            Answer::U128(_) => "u128",
+            Answer::Str(_) => "string",
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Answer {}
+
+macro_rules! answer_from {
+    ($variant:ident, $wide:ty, $($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(n: $t) -> Answer {
+                Answer::$variant(n as $wide)
+            }
+        }
+
+        impl PartialEq<Answer> for $t {
+            fn eq(&self, other: &Answer) -> bool {
+                self.to_string() == other.to_string()
+            }
+        }
+    )*};
+}
+
+answer_from!(I64, i64, i8, i16, i32, i64, isize);
+answer_from!(U64, u64, u8, u16, u32, u64, usize);
+answer_from!(I128, i128, i128);
+answer_from!(U128, u128, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, other: &Answer) -> bool {
+        other.to_string().as_str() == *self
+    }
+}
+
+#[test]
+fn answer_test() {
+    assert_eq!("-5", Answer::from(-5).to_string());
+    assert_eq!(Answer::from(7u8), Answer::from(7i64));
+    assert_eq!(u128::MAX, Answer::from(u128::MAX));
+    assert_eq!("RZKZLPGH", Answer::from("RZKZLPGH"));
+    assert_ne!(Answer::from(1), Answer::from(2));
+    assert_eq!("u64", Answer::from(7usize).kind());
+    assert_eq!("string", Answer::from("RZKZLPGH").kind());
+}
@@ -0,0 +1,157 @@
+//! Cross-checks a day's parts against the other ways it knows of solving them
+//! (see [`Solution::alternatives`]), and cuts a disagreeing input down to the
+//! lines that matter.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Answer, ParseError, Solution};
+
+/// Parses an input and compares every way of solving each part, see [`parts`]
+pub type Comparer = fn(&str) -> Result<Option<Disagreement>, ParseError>;
+
+// One way of solving a part
+type Part<S> = fn(&<S as Solution>::Input) -> Result<Answer, String>;
+
+/// A part whose implementations didn't all give the same answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: u8,
+    /// Each implementation's name and answer, starting with the part itself.
+    /// An implementation that panicked answers `panicked`, and one that failed
+    /// answers `error: ` and its message.
+    pub answers: Vec<(&'static str, String)>,
+}
+
+/// Parses `input` and solves each part that has alternatives every way the day
+/// knows, returning the first part where they disagree. A panic counts as an
+/// answer, so it is reported rather than stopping the comparison.
+pub fn parts<S: Solution>(input: &str) -> Result<Option<Disagreement>, ParseError> {
+    let parsed = S::parse(input.lines().map(String::from))?;
+    let mains: [Part<S>; 2] = [S::part1, S::part2];
+    let solve = |solve: Part<S>| {
+        panic::catch_unwind(AssertUnwindSafe(|| match solve(&parsed) {
+            Ok(answer) => answer.to_string(),
+            Err(message) => format!("error: {}", message),
+        }))
+        .unwrap_or(String::from("panicked"))
+    };
+
+    for (part, main) in (1..).zip(mains) {
+        let alternatives = S::alternatives(part);
+        if alternatives.is_empty() {
+            continue;
+        }
+
+        let expected = solve(main);
+        let mut answers = vec![("part", expected.clone())];
+        for (name, alternative) in alternatives {
+            answers.push((name, solve(alternative)));
+        }
+        if answers.iter().any(|(_, answer)| *answer != expected) {
+            return Ok(Some(Disagreement { part, answers }));
+        }
+    }
+    Ok(None)
+}
+
+/// Removes as many lines from `input` as it can while `fails` still holds,
+/// trying large runs of lines first. At least one line is always kept.
+pub fn minimize(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let mut lines = input.lines().collect::<Vec<_>>();
+    let join =
+        |lines: &[&str]| -> String { lines.iter().map(|line| format!("{}\n", line)).collect() };
+
+    let mut run = lines.len() / 2;
+    while run > 0 {
+        let mut i = 0;
+        while i < lines.len() {
+            let end = (i + run).min(lines.len());
+            if end - i < lines.len() {
+                let kept = [&lines[..i], &lines[end..]].concat();
+                if fails(&join(&kept)) {
+                    lines = kept;
+                    continue;
+                }
+            }
+            i += run;
+        }
+        run /= 2;
+    }
+    join(&lines)
+}
+
+#[cfg(test)]
+struct Evens;
+
+#[cfg(test)]
+impl Solution for Evens {
+    type Input = Vec<u32>;
+    const DAY: u8 = 1;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<u32>, ParseError> {
+        v.enumerate()
+            .map(|(i, x)| crate::Cursor::new(i + 1, &x).number())
+            .collect()
+    }
+    fn part1(input: &Vec<u32>) -> Result<Answer, String> {
+        Ok(input.iter().filter(|n| *n % 2 == 0).count().into())
+    }
+    fn part2(input: &Vec<u32>) -> Result<Answer, String> {
+        Ok(input.len().into())
+    }
+
+    fn alternatives(part: u8) -> Vec<(&'static str, fn(&Vec<u32>) -> Result<Answer, String>)> {
+        match part {
+            // Wrong about anything over 100, and can't count past 1000
+            1 => vec![("bits", |input| {
+                assert!(!input.contains(&0), "no zeros");
+                if input.contains(&1000) {
+                    return Err(String::from("too big"));
+                }
+                Ok(input
+                    .iter()
+                    .filter(|&&n| n & 1 == 0 || n > 100)
+                    .count()
+                    .into())
+            })],
+            _ => vec![],
+        }
+    }
+}
+
+#[test]
+fn parts_test() {
+    assert_eq!(Ok(None), parts::<Evens>("1\n2\n4"));
+    assert_eq!(
+        Ok(Some(Disagreement {
+            part: 1,
+            answers: vec![("part", String::from("1")), ("bits", String::from("2"))],
+        })),
+        parts::<Evens>("1\n2\n101")
+    );
+    assert_eq!(
+        Some(String::from("panicked")),
+        parts::<Evens>("0").unwrap().map(|d| d.answers[1].1.clone())
+    );
+    assert_eq!(
+        Some(String::from("error: too big")),
+        parts::<Evens>("1000")
+            .unwrap()
+            .map(|d| d.answers[1].1.clone())
+    );
+    assert!(parts::<Evens>("x").is_err());
+}
+
+#[test]
+fn minimize_test() {
+    let fails = |input: &str| matches!(parts::<Evens>(input), Ok(Some(_)));
+    let input = "1\n2\n3\n105\n4\n5\n6\n7\n8\n9\n";
+    assert_eq!("105\n", minimize(input, fails));
+
+    // Both lines are needed
+    let input = "3\n1\n4\n1\n5\n9\n2\n6\n";
+    let both = |input: &str| input.contains("4\n") && input.contains("9\n");
+    assert_eq!("4\n9\n", minimize(input, both));
+
+    assert_eq!("1\n", minimize("1\n", |_| true));
+}
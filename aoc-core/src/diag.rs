@@ -0,0 +1,68 @@
+//! Numbers a solver reports about the work behind its answer, such as how many
+//! states a search explored. Nothing is kept unless a caller is collecting.
+
+use std::{cell::RefCell, collections::BTreeMap};
+
+/// Each recorded name with its value
+pub type Diagnostics = BTreeMap<String, u64>;
+
+thread_local! {
+    // One entry per `collect` in progress on this thread, innermost last
+    static COLLECTING: RefCell<Vec<Diagnostics>> = const { RefCell::new(vec![]) };
+}
+
+/// Runs `f`, returning what it recorded on this thread along with its result
+pub fn collect<T>(f: impl FnOnce() -> T) -> (T, Diagnostics) {
+    COLLECTING.with(|c| c.borrow_mut().push(Diagnostics::new()));
+    let result = f();
+    let diagnostics = COLLECTING.with(|c| c.borrow_mut().pop().unwrap_or_default());
+    (result, diagnostics)
+}
+
+/// Sets `name` to `value`
+pub fn record(name: &str, value: u64) {
+    with_current(|d| {
+        d.insert(name.to_string(), value);
+    });
+}
+
+/// Adds `n` to `name`
+pub fn add(name: &str, n: u64) {
+    with_current(|d| *d.entry(name.to_string()).or_default() += n);
+}
+
+/// Raises `name` to `value` if it's higher, for high-water marks
+pub fn max(name: &str, value: u64) {
+    with_current(|d| {
+        let current = d.entry(name.to_string()).or_default();
+        *current = (*current).max(value);
+    });
+}
+
+fn with_current(f: impl FnOnce(&mut Diagnostics)) {
+    COLLECTING.with(|c| {
+        if let Some(current) = c.borrow_mut().last_mut() {
+            f(current);
+        }
+    });
+}
+
+#[test]
+fn collect_test() {
+    record("ignored", 1);
+
+    let (answer, outer) = collect(|| {
+        add("states", 2);
+        let ((), inner) = collect(|| record("states", 7));
+        assert_eq!(Diagnostics::from([(String::from("states"), 7)]), inner);
+        add("states", 3);
+        max("peak", 4);
+        max("peak", 2);
+        42
+    });
+    assert_eq!(42, answer);
+    assert_eq!(
+        Diagnostics::from([(String::from("peak"), 4), (String::from("states"), 5)]),
+        outer
+    );
+}
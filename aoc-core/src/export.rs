@@ -0,0 +1,301 @@
+//! Writes puzzle states out as pictures. Each cell of a grid holds a level,
+//! which picks a colour from a palette: 0 is the background.
+
+use crate::{Grid, Pos};
+use std::{
+    fs, io,
+    io::prelude::*,
+    path::{Path, PathBuf},
+};
+
+pub type Rgb = (u8, u8, u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Binary colour bitmap
+    Ppm,
+    /// Binary greyscale bitmap
+    Pgm,
+    Svg,
+}
+
+impl Format {
+    /// Picks a format from a file extension
+    pub fn from_path(path: &Path) -> Result<Format, String> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => Ok(Format::Ppm),
+            Some("pgm") => Ok(Format::Pgm),
+            Some("svg") => Ok(Format::Svg),
+            _ => Err(format!(
+                "{}: expected a .ppm, .pgm or .svg file",
+                path.display()
+            )),
+        }
+    }
+}
+
+/// Colours for each level. Levels past the end cycle through every colour but
+/// the background, so labelled regions stay distinguishable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette(Vec<Rgb>);
+
+impl Default for Palette {
+    fn default() -> Palette {
+        Palette(vec![
+            (0x00, 0x00, 0x00),
+            (0xff, 0xff, 0xff),
+            (0xe6, 0x39, 0x46),
+            (0xf4, 0xa2, 0x61),
+            (0xe9, 0xc4, 0x6a),
+            (0x2a, 0x9d, 0x8f),
+            (0x45, 0x7b, 0x9d),
+            (0x9b, 0x5d, 0xe5),
+        ])
+    }
+}
+
+impl Palette {
+    /// Parses comma-separated hex colours, e.g. `000000,ffffff`
+    pub fn parse(s: &str) -> Result<Palette, String> {
+        let colours = s
+            .split(',')
+            .map(|hex| {
+                let hex = hex.trim().trim_start_matches('#');
+                let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+                match (hex.len(), channel(0), channel(2), channel(4)) {
+                    (6, Some(r), Some(g), Some(b)) => Ok((r, g, b)),
+                    _ => Err(format!("Invalid colour {}", hex)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if colours.len() < 2 {
+            return Err(String::from("A palette needs at least two colours"));
+        }
+        Ok(Palette(colours))
+    }
+
+    pub fn colour(&self, level: usize) -> Rgb {
+        match self.0.get(level) {
+            Some(&rgb) => rgb,
+            None => self.0[1 + (level - 1) % (self.0.len() - 1)],
+        }
+    }
+
+    /// The brightness of a level's colour
+    pub fn grey(&self, level: usize) -> u8 {
+        let (r, g, b) = self.colour(level);
+        (0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64).round() as u8
+    }
+}
+
+/// How to draw a grid: its colours and how many pixels wide each cell is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    pub palette: Palette,
+    pub scale: usize,
+}
+
+impl Default for Export {
+    fn default() -> Export {
+        Export {
+            palette: Palette::default(),
+            scale: 1,
+        }
+    }
+}
+
+impl Export {
+    pub fn write(
+        &self,
+        grid: &Grid<usize>,
+        format: Format,
+        out: &mut impl Write,
+    ) -> io::Result<()> {
+        let (width, height) = (grid.width() * self.scale, grid.height() * self.scale);
+        match format {
+            Format::Ppm => {
+                write!(out, "P6\n{} {}\n255\n", width, height)?;
+                self.pixels(grid, out, |level| {
+                    let (r, g, b) = self.palette.colour(level);
+                    vec![r, g, b]
+                })
+            }
+            Format::Pgm => {
+                write!(out, "P5\n{} {}\n255\n", width, height)?;
+                self.pixels(grid, out, |level| vec![self.palette.grey(level)])
+            }
+            Format::Svg => self.svg(grid, out),
+        }
+    }
+
+    /// Writes to `path`, in the format its extension names
+    pub fn save(&self, grid: &Grid<usize>, path: &Path) -> Result<(), String> {
+        let format = Format::from_path(path)?;
+        let error = |e: io::Error| format!("{}: {}", path.display(), e);
+        let mut out = io::BufWriter::new(fs::File::create(path).map_err(error)?);
+        self.write(grid, format, &mut out).map_err(error)?;
+        out.flush().map_err(error)
+    }
+
+    fn pixels(
+        &self,
+        grid: &Grid<usize>,
+        out: &mut impl Write,
+        pixel: impl Fn(usize) -> Vec<u8>,
+    ) -> io::Result<()> {
+        let mut row = vec![];
+        for y in 0..grid.height() {
+            row.clear();
+            for x in 0..grid.width() {
+                let bytes = pixel(grid[(x, y)]);
+                for _ in 0..self.scale {
+                    row.extend(&bytes);
+                }
+            }
+            for _ in 0..self.scale {
+                out.write_all(&row)?;
+            }
+        }
+        Ok(())
+    }
+
+    // A background rectangle, then one square per cell that isn't background
+    fn svg(&self, grid: &Grid<usize>, out: &mut impl Write) -> io::Result<()> {
+        let hex = |level: usize| {
+            let (r, g, b) = self.palette.colour(level);
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        };
+        let s = self.scale;
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}">"#,
+            grid.width() * s,
+            grid.height() * s
+        )?;
+        writeln!(
+            out,
+            r#"<rect width="100%" height="100%" fill="{}"/>"#,
+            hex(0)
+        )?;
+        for ((x, y), &level) in grid.cells().filter(|&(_, &level)| level > 0) {
+            writeln!(
+                out,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                x * s,
+                y * s,
+                s,
+                s,
+                hex(level)
+            )?;
+        }
+        writeln!(out, "</svg>")
+    }
+}
+
+/// A grid just big enough to hold every point, with level 1 where there is one
+pub fn points(points: impl IntoIterator<Item = Pos>) -> Grid<usize> {
+    let points = points.into_iter().collect::<Vec<_>>();
+    let width = points.iter().map(|p| p.0 + 1).max().unwrap_or(0);
+    let height = points.iter().map(|p| p.1 + 1).max().unwrap_or(0);
+    let mut grid = Grid::new(width, height, 0);
+    for pos in points {
+        grid[pos] = 1;
+    }
+    grid
+}
+
+#[test]
+fn palette_test() {
+    let palette = Palette::parse("000000, #ff8000,0000ff").unwrap();
+    assert_eq!((255, 128, 0), palette.colour(1));
+    assert_eq!((0, 0, 255), palette.colour(2));
+    assert_eq!((255, 128, 0), palette.colour(3));
+    assert_eq!(0, palette.grey(0));
+    assert_eq!(151, palette.grey(1));
+
+    assert!(Palette::parse("000000").is_err());
+    assert!(Palette::parse("000000,fff").is_err());
+    assert!(Palette::parse("000000,gggggg").is_err());
+}
+
+#[test]
+fn write_test() {
+    let grid = points([(1, 0), (0, 1)]);
+    let export = Export {
+        palette: Palette::parse("000000,ffffff").unwrap(),
+        scale: 2,
+    };
+
+    let mut pgm = vec![];
+    export.write(&grid, Format::Pgm, &mut pgm).unwrap();
+    let mut expected = b"P5\n4 4\n255\n".to_vec();
+    expected.extend([
+        0, 0, 255, 255, 0, 0, 255, 255, 255, 255, 0, 0, 255, 255, 0, 0,
+    ]);
+    assert_eq!(expected, pgm);
+
+    let mut ppm = vec![];
+    export.write(&grid, Format::Ppm, &mut ppm).unwrap();
+    assert_eq!(b"P6\n4 4\n255\n".len() + 4 * 4 * 3, ppm.len());
+
+    let mut svg = vec![];
+    export.write(&grid, Format::Svg, &mut svg).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains(r##"<rect x="2" y="0" width="2" height="2" fill="#ffffff"/>"##));
+    assert_eq!(3, svg.matches("<rect").count());
+}
+
+#[test]
+fn format_test() {
+    assert_eq!(
+        Ok(Format::Svg),
+        Format::from_path(Path::new("out/day13.svg"))
+    );
+    assert!(Format::from_path(Path::new("day13.png")).is_err());
+}
+
+/// Reads `--export <path>`, `--scale <n>` and `--palette <colours>` flags
+pub fn parse_args(args: &[String]) -> Result<(Option<PathBuf>, Export), String> {
+    let mut path = None;
+    let mut export = Export::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("{} needs a value", arg))?;
+        match arg.as_str() {
+            "--export" => {
+                Format::from_path(Path::new(value))?;
+                path = Some(PathBuf::from(value));
+            }
+            "--scale" => {
+                export.scale = value
+                    .parse()
+                    .ok()
+                    .filter(|&scale| scale > 0)
+                    .ok_or(format!("Invalid scale {}", value))?
+            }
+            "--palette" => export.palette = Palette::parse(value)?,
+            _ => return Err(format!("Invalid argument {}", arg)),
+        }
+    }
+
+    if path.is_none() && export != Export::default() {
+        return Err(String::from("--scale and --palette need --export"));
+    }
+    Ok((path, export))
+}
+
+#[test]
+fn parse_args_test() {
+    let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+    let (path, export) = parse_args(&args("--export a.svg --scale 4")).unwrap();
+    assert_eq!(Some(PathBuf::from("a.svg")), path);
+    assert_eq!(4, export.scale);
+    assert_eq!((None, Export::default()), parse_args(&[]).unwrap());
+
+    assert!(parse_args(&args("--export a.png")).is_err());
+    assert!(parse_args(&args("--export a.svg --scale 0")).is_err());
+    assert!(parse_args(&args("--scale 2")).is_err());
+    assert!(parse_args(&args("--export")).is_err());
+}
@@ -0,0 +1,144 @@
+//! Support for writing synthetic puzzle inputs: a small seeded random number
+//! generator, so the same seed always gives the same input, and named size
+//! parameters.
+
+use std::{cell::RefCell, collections::HashMap};
+
+/// Writes one day's puzzle input
+pub type Generator = fn(&mut Rng, &Params) -> String;
+
+/// SplitMix64, which is plenty for inputs and keeps its output stable across
+/// platforms and releases
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 to `n - 1`
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "below(0)");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    /// A number from `lo` to `hi`, inclusive
+    pub fn range(&mut self, lo: i64, hi: i64) -> i64 {
+        assert!(lo <= hi, "range({}, {})", lo, hi);
+        lo + (self.next_u64() % (hi - lo + 1) as u64) as i64
+    }
+
+    /// True `percent` times out of a hundred
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Size parameters given as `name=value`. Generators ask for each one with a
+/// default, which lets the caller spot names that no generator asked for.
+#[derive(Debug, Default)]
+pub struct Params {
+    values: HashMap<String, usize>,
+    asked: RefCell<Vec<String>>,
+}
+
+impl Params {
+    pub fn parse(args: &[String]) -> Result<Params, String> {
+        let mut values = HashMap::new();
+        for arg in args {
+            let (name, value) = arg
+                .split_once('=')
+                .ok_or(format!("Expected name=value, got {}", arg))?;
+            let value = value
+                .parse()
+                .map_err(|_| format!("Invalid value for {}: {}", name, value))?;
+            values.insert(name.to_string(), value);
+        }
+        Ok(Params {
+            values,
+            asked: RefCell::new(vec![]),
+        })
+    }
+
+    pub fn get(&self, name: &str, default: usize) -> usize {
+        self.asked.borrow_mut().push(name.to_string());
+        self.values.get(name).copied().unwrap_or(default)
+    }
+
+    /// Like `get`, but never less than `min`
+    pub fn at_least(&self, name: &str, default: usize, min: usize) -> usize {
+        self.get(name, default).max(min)
+    }
+
+    /// Every name that was asked for so far
+    pub fn asked(&self) -> Vec<String> {
+        self.asked.borrow().clone()
+    }
+
+    /// Names that were given but never asked for
+    pub fn unused(&self) -> Vec<&str> {
+        let asked = self.asked.borrow();
+        let mut unused = self
+            .values
+            .keys()
+            .filter(|name| !asked.contains(name))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        unused.sort();
+        unused
+    }
+}
+
+#[test]
+fn rng_test() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+    let first = (0..5).map(|_| a.next_u64()).collect::<Vec<_>>();
+    assert_eq!(first, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(first[0], Rng::new(8).next_u64());
+
+    // Known output, so a change to the generator (and every input) shows up
+    assert_eq!(0xe220a8397b1dcdaf, Rng::new(0).next_u64());
+
+    for _ in 0..100 {
+        assert!((-3..=3).contains(&a.range(-3, 3)));
+        assert!(a.below(10) < 10);
+    }
+
+    let mut items = (0..10).collect::<Vec<_>>();
+    a.shuffle(&mut items);
+    items.sort();
+    assert_eq!((0..10).collect::<Vec<_>>(), items);
+}
+
+#[test]
+fn params_test() {
+    let params = Params::parse(&[String::from("boards=3"), String::from("size=9")]).unwrap();
+    assert_eq!(3, params.get("boards", 100));
+    assert_eq!(5, params.get("numbers", 5));
+    assert_eq!(vec!["size"], params.unused());
+    assert_eq!(vec!["boards", "numbers"], params.asked());
+    assert_eq!(2, params.at_least("numbers", 1, 2));
+
+    assert!(Params::parse(&[String::from("boards")]).is_err());
+    assert!(Params::parse(&[String::from("boards=-1")]).is_err());
+}
@@ -0,0 +1,304 @@
+use crate::{Cursor, ParseError};
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// (x, y), with (0, 0) in the top left
+pub type Pos = (usize, usize);
+
+const DIRS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const DIRS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A dense, row-major 2D grid. Neighbours stop at the edges unless the grid
+/// was made `wrapping`, in which case they continue on the opposite side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrap: bool,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(width, height, |_| fill.clone())
+    }
+
+    /// Builds a grid by calling `f` for every position in row order
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(f((x, y)));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+            wrap: false,
+        }
+    }
+
+    /// Parses one row per line, mapping each character with `cell`. Every row
+    /// must be as wide as the first.
+    pub fn parse(
+        lines: impl Iterator<Item = String>,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_from(1, lines, expected, cell)
+    }
+
+    /// Like `parse`, for grids that start on `line` of the input
+    pub fn parse_from(
+        line: usize,
+        lines: impl Iterator<Item = String>,
+        expected: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (i, text) in lines.enumerate() {
+            let mut c = Cursor::new(line + i, &text);
+            if height == 0 {
+                while !c.is_empty() {
+                    cells.push(c.char_map(expected, &cell)?);
+                }
+                width = cells.len();
+            } else {
+                for _ in 0..width {
+                    cells.push(c.char_map(expected, &cell)?);
+                }
+                c.end()?;
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+            wrap: false,
+        })
+    }
+
+    /// Makes neighbours and steps wrap around the edges, like a torus
+    pub fn wrapping(mut self) -> Grid<T> {
+        self.wrap = true;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Looks up a position that may lie outside the grid, e.g. one step past an edge
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.get((x as usize, y as usize))
+    }
+
+    /// Every position in row order
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i % width, i / width))
+    }
+
+    /// Every cell in row order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Every position alongside its cell, in row order
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            wrap: self.wrap,
+        }
+    }
+
+    /// Moves `pos` by `(dx, dy)`, or `None` if that leaves a non-wrapping grid
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let x = x as isize + dx;
+        let y = y as isize + dy;
+        let (w, h) = (self.width as isize, self.height as isize);
+        if self.wrap {
+            Some((x.rem_euclid(w) as usize, y.rem_euclid(h) as usize))
+        } else if x >= 0 && x < w && y >= 0 && y < h {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Up, right, down and left of `pos`
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS4.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// All eight positions surrounding `pos`, including diagonals
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRS8.iter().filter_map(move |&d| self.step(pos, d))
+    }
+}
+
+impl Grid<u32> {
+    /// Parses rows of decimal digits
+    pub fn parse_digits(
+        lines: impl Iterator<Item = String>,
+        expected: &str,
+    ) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(lines, expected, |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", pos, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("{:?} is outside a {}x{} grid", pos, width, height),
+        }
+    }
+}
+
+/// Renders one line per row, each ending in a newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn lines(rows: &[&str]) -> impl Iterator<Item = String> {
+    rows.iter()
+        .map(|r| r.to_string())
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+#[test]
+fn parse_test() {
+    let grid = Grid::parse_digits(lines(&["123", "456"]), "a digit").unwrap();
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!(6, grid[(2, 1)]);
+    assert_eq!(Some(&4), grid.get_signed(0, 1));
+    assert_eq!(None, grid.get_signed(-1, 1));
+    assert_eq!("123\n456\n", grid.to_string());
+
+    assert_eq!(
+        Err(ParseError::new(2, 2, "a digit")),
+        Grid::parse_digits(lines(&["123", "4x6"]), "a digit")
+    );
+    assert_eq!(
+        Err(ParseError::new(2, 3, "a digit")),
+        Grid::parse_digits(lines(&["123", "45"]), "a digit")
+    );
+    assert_eq!(
+        Err(ParseError::new(5, 4, "end of line")),
+        Grid::parse_from(4, lines(&["123", "4567"]), "a digit", |c| c.to_digit(10))
+    );
+}
+
+#[test]
+fn neighbours_test() {
+    let grid = Grid::new(3, 3, 0);
+    assert_eq!(
+        vec![(1, 0), (0, 1)],
+        grid.neighbours4((0, 0)).collect::<Vec<_>>()
+    );
+    assert_eq!(3, grid.neighbours8((0, 0)).count());
+    assert_eq!(8, grid.neighbours8((1, 1)).count());
+
+    let grid = grid.wrapping();
+    assert_eq!(
+        vec![(0, 2), (1, 0), (0, 1), (2, 0)],
+        grid.neighbours4((0, 0)).collect::<Vec<_>>()
+    );
+    assert_eq!(Some((0, 1)), grid.step((2, 1), (1, 0)));
+}
+
+#[test]
+fn map_test() {
+    let grid = Grid::from_fn(2, 2, |(x, y)| x + y * 2);
+    assert_eq!(vec![0, 1, 2, 3], grid.iter().copied().collect::<Vec<_>>());
+    assert_eq!(
+        vec![((1, 1), &3)],
+        grid.cells().filter(|(_, &c)| c == 3).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        "FT\nFT\n",
+        grid.map(|c| if c % 2 == 1 { 'T' } else { 'F' }).to_string()
+    );
+}
@@ -0,0 +1,108 @@
+//! Reads puzzle inputs from files, directories or stdin. Files compressed with
+//! gzip or zstd are recognised by their first bytes, whatever they're called.
+
+use std::{
+    fs,
+    io::{self, prelude::*},
+    path::{Path, PathBuf},
+};
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// Decompresses `bytes` if they are gzip or zstd, and reads them as text
+pub fn decode(bytes: Vec<u8>) -> io::Result<String> {
+    let bytes = if bytes.starts_with(GZIP_MAGIC) {
+        let mut out = vec![];
+        flate2::read::MultiGzDecoder::new(&bytes[..]).read_to_end(&mut out)?;
+        out
+    } else if bytes.starts_with(ZSTD_MAGIC) {
+        zstd::decode_all(&bytes[..])?
+    } else {
+        bytes
+    };
+    String::from_utf8(bytes).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "stream did not contain valid UTF-8",
+        )
+    })
+}
+
+pub fn read(path: &Path) -> Result<String, String> {
+    fs::read(path)
+        .and_then(decode)
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+pub fn read_stdin() -> Result<String, String> {
+    let mut bytes = vec![];
+    io::stdin()
+        .read_to_end(&mut bytes)
+        .and_then(|_| decode(bytes))
+        .map_err(|e| format!("stdin: {}", e))
+}
+
+/// Replaces each directory with the files directly inside it, sorted by name.
+/// Hidden files are left out.
+pub fn expand(paths: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let mut files = vec![];
+    for path in paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+            continue;
+        }
+
+        let error = |e: io::Error| format!("{}: {}", path.display(), e);
+        let mut inside = vec![];
+        for entry in fs::read_dir(path).map_err(error)? {
+            let entry = entry.map_err(error)?;
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if !hidden && entry.file_type().map_err(error)?.is_file() {
+                inside.push(entry.path());
+            }
+        }
+        if inside.is_empty() {
+            return Err(format!("{}: no inputs in directory", path.display()));
+        }
+        inside.sort();
+        files.extend(inside);
+    }
+    Ok(files)
+}
+
+#[test]
+fn decode_test() {
+    let text = "199\n200\n208\n";
+    assert_eq!(text, decode(text.as_bytes().to_vec()).unwrap());
+
+    let mut gzip = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+    gzip.write_all(text.as_bytes()).unwrap();
+    assert_eq!(text, decode(gzip.finish().unwrap()).unwrap());
+
+    let zstd = zstd::encode_all(text.as_bytes(), 0).unwrap();
+    assert_eq!(text, decode(zstd).unwrap());
+
+    assert!(decode(vec![0xff, 0xfe]).is_err());
+    assert!(decode(vec![0x1f, 0x8b, 0x00]).is_err());
+}
+
+#[test]
+fn expand_test() {
+    let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    for name in ["b.txt", "a.txt.gz", ".hidden"] {
+        fs::write(dir.join(name), "").unwrap();
+    }
+
+    let other = PathBuf::from("other.txt");
+    assert_eq!(
+        vec![dir.join("a.txt.gz"), dir.join("b.txt"), other.clone()],
+        expand(&[dir.clone(), other]).unwrap()
+    );
+    assert!(expand(&[dir.join("nested")]).is_err());
+    assert!(read(&dir.join("missing")).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
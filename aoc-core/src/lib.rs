@@ -0,0 +1,420 @@
+use std::{
+    env, fmt,
+    path::{Path, PathBuf},
+    process,
+    time::Instant,
+};
+
+use export::Export;
+use rayon::prelude::*;
+
+pub mod anim;
+mod answer;
+pub mod compare;
+pub mod diag;
+pub mod export;
+pub mod gen;
+mod grid;
+pub mod input;
+mod parse;
+pub mod repl;
+mod report;
+pub mod search;
+
+pub use answer::Answer;
+pub use grid::{Grid, Pos};
+pub use parse::{Cursor, ParseError};
+pub use report::{Output, Report};
+
+/// A single day's puzzle. Input is parsed once and then shared by both parts.
+pub trait Solution {
+    type Input;
+    /// The day of the puzzle, 1 to 25
+    const DAY: u8;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Self::Input, ParseError>;
+    /// The answer to part 1, or why this input doesn't have one
+    fn part1(input: &Self::Input) -> Result<Answer, String>;
+    /// The answer to part 2, or why this input doesn't have one
+    fn part2(input: &Self::Input) -> Result<Answer, String>;
+
+    /// The puzzle state after `part` as a grid of palette levels, for days
+    /// worth looking at (see [`export`])
+    fn picture(_input: &Self::Input, _part: u8) -> Option<Grid<usize>> {
+        None
+    }
+
+    /// Other ways of solving `part`, each with a name, which should always
+    /// agree with it (see [`compare`])
+    #[allow(clippy::type_complexity)]
+    fn alternatives(_part: u8) -> Vec<(&'static str, fn(&Self::Input) -> Result<Answer, String>)> {
+        vec![]
+    }
+}
+
+/// Why a part has no answer: the input didn't parse, or the part couldn't
+/// solve it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Part(String),
+}
+
+impl SolveError {
+    /// Renders the error, pointing into `source` if it didn't parse
+    pub fn report(&self, source: &str) -> String {
+        match self {
+            SolveError::Parse(e) => e.report(source),
+            SolveError::Part(message) => message.clone(),
+        }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::Part(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> SolveError {
+        SolveError::Parse(e)
+    }
+}
+
+/// Parses `input` and runs the requested part, returning the rendered answer,
+/// or `None` if `part` is not 1 or 2.
+pub fn solve<S: Solution>(part: u8, input: &str) -> Result<Option<String>, SolveError> {
+    if part != 1 && part != 2 {
+        return Ok(None);
+    }
+    let parsed = S::parse(input.lines().map(String::from))?;
+    let answer = match part {
+        1 => S::part1(&parsed),
+        _ => S::part2(&parsed),
+    };
+    answer
+        .map(|answer| Some(answer.to_string()))
+        .map_err(SolveError::Part)
+}
+
+/// Like [`solve`], but also times the part and collects what it records with
+/// [`diag`]. The report's `input` is left for the caller to fill in.
+pub fn report<S: Solution>(part: u8, input: &str) -> Result<Option<Report>, SolveError> {
+    if part != 1 && part != 2 {
+        return Ok(None);
+    }
+    let start = Instant::now();
+    let (answer, diagnostics) = diag::collect(|| {
+        let parsed = S::parse(input.lines().map(String::from))?;
+        let answer = match part {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed),
+        };
+        answer.map_err(SolveError::Part)
+    });
+    let elapsed = start.elapsed();
+    let answer = answer?;
+
+    Ok(Some(Report {
+        schema: report::SCHEMA,
+        day: S::DAY,
+        part,
+        input: None,
+        input_hash: report::hash(input),
+        answer: answer.to_string(),
+        answer_type: answer.kind(),
+        elapsed_ns: elapsed.as_nanos() as u64,
+        diagnostics,
+    }))
+}
+
+/// Parses `input` without solving it, for checking that a parser accepts or
+/// rejects it cleanly
+pub fn check<S: Solution>(input: &str) -> Result<(), ParseError> {
+    S::parse(input.lines().map(String::from)).map(|_| ())
+}
+
+/// Parses `input` and draws the picture for `part`, or `None` if there isn't one
+pub fn picture<S: Solution>(part: u8, input: &str) -> Result<Option<Grid<usize>>, ParseError> {
+    let parsed = S::parse(input.lines().map(String::from))?;
+    Ok(S::picture(&parsed, part))
+}
+
+/// Entry point shared by every `dayNN` binary: `dayNN [part] [path ...]`.
+/// The part defaults to 1 and the input to stdin. Paths may be directories,
+/// standing for every file in them, and may be gzip or zstd compressed.
+/// Several inputs are solved in parallel, each answer labelled with its path.
+/// `--format json` prints a [`Report`] per input instead of the bare answer,
+/// and `--stats` follows the answer with what the solver recorded in [`diag`].
+/// A single input's state can also be saved as a picture with `--export <path>`.
+pub fn main<S: Solution>() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = parse_args(&args).unwrap_or_else(|message| fail(&message));
+
+    let batch = args.paths.len() > 1 || args.paths.iter().any(|p| p.is_dir());
+    if !batch {
+        let path = args.paths.first();
+        let input = match path {
+            Some(path) => input::read(path),
+            None => input::read_stdin(),
+        };
+        let input = input.unwrap_or_else(|message| fail(&message));
+        return main_single::<S>(&args, path.map(PathBuf::as_path), &input);
+    }
+
+    if args.export.is_some() {
+        fail("--export needs a single input");
+    }
+    if args.part != 1 && args.part != 2 {
+        fail(&format!("Invalid part {}", args.part));
+    }
+    let paths = input::expand(&args.paths).unwrap_or_else(|message| fail(&message));
+    let results = paths
+        .par_iter()
+        .map(|path| {
+            let input = input::read(path)?;
+            render::<S>(args.part, &input, Some(path), args.format, args.stats)
+                .map(Option::unwrap_or_default)
+                .map_err(|e| format!("{}: {}", path.display(), e.report(&input)))
+        })
+        .collect::<Vec<_>>();
+
+    let mut failed = false;
+    for (path, result) in paths.iter().zip(results) {
+        match result {
+            Ok(line) if args.format == Output::Json => println!("{}", line),
+            // Multi-line answers (e.g. day 13's letters) start on their own line
+            Ok(answer) if answer.contains('\n') => println!("{}:\n{}", path.display(), answer),
+            Ok(answer) => println!("{}: {}", path.display(), answer),
+            Err(message) => {
+                eprintln!("{}", message);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn main_single<S: Solution>(args: &Args, path: Option<&Path>, input: &str) {
+    let part = args.part;
+    match render::<S>(part, input, path, args.format, args.stats) {
+        Ok(Some(answer)) => println!("{}", answer),
+        Ok(None) if args.format == Output::Json => fail(&format!("Invalid part {}", part)),
+        Ok(None) => println!("Invalid part {}", part),
+        Err(e) => fail(&e.report(input)),
+    }
+
+    if let Some((path, export)) = &args.export {
+        match picture::<S>(part, input) {
+            Ok(Some(grid)) => export.save(&grid, path).unwrap_or_else(|m| fail(&m)),
+            Ok(None) => fail("There is no picture for this day"),
+            Err(e) => fail(&e.report(input)),
+        }
+    }
+}
+
+// The answer as it's printed: bare, followed by its stats, or as a JSON report
+fn render<S: Solution>(
+    part: u8,
+    input: &str,
+    path: Option<&Path>,
+    format: Output,
+    stats: bool,
+) -> Result<Option<String>, SolveError> {
+    match format {
+        Output::Text if stats => Ok(
+            report::<S>(part, input)?.map(|report| match report.stats() {
+                stats if stats.is_empty() => report.answer,
+                stats => format!(
+                    "{}\n{}",
+                    report.answer.trim_end_matches('\n'),
+                    stats.trim_end()
+                ),
+            }),
+        ),
+        Output::Text => solve::<S>(part, input),
+        Output::Json => Ok(report::<S>(part, input)?.map(|mut report| {
+            report.input = path.map(|path| path.display().to_string());
+            report.to_json()
+        })),
+    }
+}
+
+#[derive(Debug)]
+struct Args {
+    part: u8,
+    paths: Vec<PathBuf>,
+    format: Output,
+    stats: bool,
+    export: Option<(PathBuf, Export)>,
+}
+
+// A leading number is the part, anything else that isn't a flag is an input
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut part = None;
+    let mut paths = vec![];
+    let mut format = Output::Text;
+    let mut stats = false;
+    let mut export_args = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = Output::parse(args.next().ok_or("--format needs a value")?)?;
+            }
+            "--stats" => stats = true,
+            "--export" | "--scale" | "--palette" => {
+                export_args.push(arg.clone());
+                export_args.extend(args.next().cloned());
+            }
+            _ if part.is_none() && paths.is_empty() && arg.parse::<u8>().is_ok() => {
+                part = arg.parse().ok()
+            }
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+
+    let export = match export::parse_args(&export_args)? {
+        (Some(path), export) => Some((path, export)),
+        (None, _) => None,
+    };
+    Ok(Args {
+        part: part.unwrap_or(1),
+        paths,
+        format,
+        stats,
+        export,
+    })
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        const DAY: u8 = 1;
+
+        fn parse(v: impl Iterator<Item = String>) -> Result<Vec<i32>, ParseError> {
+            v.enumerate()
+                .map(|(i, x)| Cursor::new(i + 1, &x).number())
+                .collect()
+        }
+        fn part1(input: &Vec<i32>) -> Result<Answer, String> {
+            input
+                .iter()
+                .try_fold(0i32, |sum, &n| sum.checked_add(n))
+                .map(Answer::from)
+                .ok_or(String::from("The sum overflowed"))
+        }
+        fn part2(input: &Vec<i32>) -> Result<Answer, String> {
+            Ok(input.len().into())
+        }
+    }
+
+    #[test]
+    fn solve_test() {
+        assert_eq!(Ok(Some(String::from("6"))), solve::<Sum>(1, "1\n2\n3"));
+        assert_eq!(Ok(Some(String::from("3"))), solve::<Sum>(2, "1\n2\n3"));
+        assert_eq!(Ok(None), solve::<Sum>(3, "1\n2\n3"));
+        assert_eq!(
+            Err(SolveError::Parse(ParseError::new(2, 1, "a number"))),
+            solve::<Sum>(1, "1\nx\n3")
+        );
+
+        let overflow = solve::<Sum>(1, "2147483647\n1");
+        assert_eq!(
+            Err(SolveError::Part(String::from("The sum overflowed"))),
+            overflow
+        );
+        assert_eq!(
+            "The sum overflowed",
+            overflow.unwrap_err().report("2147483647\n1")
+        );
+    }
+
+    #[test]
+    fn report_test() {
+        let sum = report::<Sum>(1, "1\n2\n3").unwrap().unwrap();
+        assert_eq!((1, 1), (sum.day, sum.part));
+        assert_eq!(("6", "i64"), (sum.answer.as_str(), sum.answer_type));
+        assert_eq!(report::hash("1\n2\n3"), sum.input_hash);
+        assert_eq!(None, report::<Sum>(3, "1").unwrap());
+        assert!(report::<Sum>(1, "x").is_err());
+        assert!(report::<Sum>(1, "2147483647\n1").is_err());
+    }
+
+    #[test]
+    fn render_test() {
+        struct Counted;
+
+        impl Solution for Counted {
+            type Input = Vec<i32>;
+            const DAY: u8 = 1;
+
+            fn parse(v: impl Iterator<Item = String>) -> Result<Vec<i32>, ParseError> {
+                Sum::parse(v)
+            }
+            fn part1(input: &Vec<i32>) -> Result<Answer, String> {
+                diag::record("numbers", input.len() as u64);
+                Sum::part1(input)
+            }
+            fn part2(input: &Vec<i32>) -> Result<Answer, String> {
+                Sum::part2(input)
+            }
+        }
+
+        let render = |part, stats| render::<Counted>(part, "1\n2", None, Output::Text, stats);
+        assert_eq!(Ok(Some(String::from("3"))), render(1, false));
+        assert_eq!(Ok(Some(String::from("3\nnumbers  2"))), render(1, true));
+        assert_eq!(Ok(Some(String::from("2"))), render(2, true));
+    }
+
+    #[test]
+    fn parse_args_test() {
+        let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+        let parsed = parse_args(&[]).unwrap();
+        assert_eq!(1, parsed.part);
+        assert!(parsed.paths.is_empty());
+
+        let parsed = parse_args(&args("2 a.txt inputs/ --export a.svg")).unwrap();
+        assert_eq!(2, parsed.part);
+        assert_eq!(
+            vec![PathBuf::from("a.txt"), PathBuf::from("inputs/")],
+            parsed.paths
+        );
+        assert_eq!(Some(PathBuf::from("a.svg")), parsed.export.map(|(p, _)| p));
+
+        let parsed = parse_args(&args("input 2")).unwrap();
+        assert_eq!(1, parsed.part);
+        assert_eq!(
+            vec![PathBuf::from("input"), PathBuf::from("2")],
+            parsed.paths
+        );
+
+        assert!(parse_args(&args("1 --scale 2")).is_err());
+
+        assert_eq!(
+            Output::Json,
+            parse_args(&args("--format json")).unwrap().format
+        );
+        assert!(parse_args(&args("--format yaml")).is_err());
+        assert!(parse_args(&args("2 --stats")).unwrap().stats);
+        assert!(parse_args(&args("--format")).is_err());
+    }
+}
@@ -0,0 +1,240 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Where and why a puzzle input failed to parse. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Renders the error with the offending source line and a caret under the column
+    pub fn report(&self, source: &str) -> String {
+        let mut out = self.to_string();
+        if let Some(text) = source.lines().nth(self.line.wrapping_sub(1)) {
+            let gutter = self.line.to_string().len();
+            out += &format!("\n{:>gutter$} | {}", self.line, text, gutter = gutter);
+            out += &format!(
+                "\n{:>gutter$} | {:>column$}",
+                "",
+                "^",
+                gutter = gutter,
+                column = self.column
+            );
+        }
+        out
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Reads one line of input left to right, keeping track of the column for errors
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    line: usize,
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: usize, text: &'a str) -> Cursor<'a> {
+        Cursor { line, text, pos: 0 }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// An error at the current column
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.line, self.column(), expected)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    /// Consumes and returns everything left on the line
+    pub fn rest(&mut self) -> &'a str {
+        let rest = &self.text[self.pos..];
+        self.pos = self.text.len();
+        rest
+    }
+
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = &self.text[self.pos..];
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    pub fn skip_spaces(&mut self) {
+        self.take_while(|c| c == ' ');
+    }
+
+    /// Consumes `lit` if the line continues with it
+    pub fn eat(&mut self, lit: &str) -> bool {
+        if self.text[self.pos..].starts_with(lit) {
+            self.pos += lit.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn literal(&mut self, lit: &str) -> Result<(), ParseError> {
+        if self.eat(lit) {
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}`", lit)))
+        }
+    }
+
+    pub fn char(&mut self) -> Result<char, ParseError> {
+        let c = self.peek().ok_or_else(|| self.error("a character"))?;
+        self.pos += c.len_utf8();
+        Ok(c)
+    }
+
+    /// Consumes a character that `f` maps to a value, e.g. `|c| c.to_digit(10)`
+    pub fn char_map<T>(
+        &mut self,
+        expected: &str,
+        f: impl Fn(char) -> Option<T>,
+    ) -> Result<T, ParseError> {
+        match self.peek().and_then(&f) {
+            Some(value) => {
+                self.pos += self.peek().map_or(0, char::len_utf8);
+                Ok(value)
+            }
+            None => Err(self.error(expected)),
+        }
+    }
+
+    /// An optionally signed run of digits
+    pub fn number<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = self.pos;
+        let column = self.column();
+        self.eat("-");
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        let text = &self.text[start..self.pos];
+
+        match text.parse::<T>() {
+            Ok(n) if !digits.is_empty() => Ok(n),
+            _ => {
+                self.pos = start;
+                Err(ParseError::new(self.line, column, "a number"))
+            }
+        }
+    }
+
+    /// A non-empty run of ASCII letters
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        let column = self.column();
+        match self.take_while(|c| c.is_ascii_alphabetic()) {
+            "" => Err(ParseError::new(self.line, column, "a word")),
+            word => Ok(word),
+        }
+    }
+
+    /// Consumes whichever of `options` comes next
+    pub fn one_of<'o>(&mut self, options: &[&'o str]) -> Result<&'o str, ParseError> {
+        for &option in options {
+            if self.eat(option) {
+                return Ok(option);
+            }
+        }
+        let names = options
+            .iter()
+            .map(|o| format!("`{}`", o))
+            .collect::<Vec<_>>();
+        Err(self.error(format!("one of {}", names.join(", "))))
+    }
+
+    /// One or more items separated by `sep`
+    pub fn separated<T>(
+        &mut self,
+        sep: &str,
+        mut item: impl FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut items = vec![item(self)?];
+        while self.eat(sep) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    pub fn end(&self) -> Result<(), ParseError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[test]
+fn cursor_test() {
+    let mut c = Cursor::new(3, "0,9 -> -5,x");
+    assert_eq!(vec![0, 9], c.separated(",", |c| c.number::<i32>()).unwrap());
+    c.literal(" -> ").unwrap();
+    assert_eq!(-5, c.number::<i32>().unwrap());
+    c.literal(",").unwrap();
+    assert_eq!(
+        ParseError::new(3, 11, "a number"),
+        c.number::<i32>().unwrap_err()
+    );
+    assert_eq!(ParseError::new(3, 11, "end of line"), c.end().unwrap_err());
+    assert_eq!("x", c.rest());
+    assert!(c.end().is_ok());
+}
+
+#[test]
+fn one_of_test() {
+    let mut c = Cursor::new(1, "up 3");
+    assert_eq!("up", c.one_of(&["forward", "down", "up"]).unwrap());
+    assert_eq!(
+        "line 1, column 3: expected one of `a`, `b`",
+        c.one_of(&["a", "b"]).unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn report_test() {
+    let error = ParseError::new(2, 3, "a number");
+    assert_eq!(
+        "line 2, column 3: expected a number\n2 | 1,x\n  |   ^",
+        error.report("0,0\n1,x\n")
+    );
+
+    // Past the end of the input there's no line to show
+    let error = ParseError::new(9, 1, "a number");
+    assert_eq!("line 9, column 1: expected a number", error.report("0,0\n"));
+}
@@ -0,0 +1,242 @@
+//! Commands for poking at a parsed input from `aoc repl`. The input is parsed
+//! once, and each day adds its own commands by implementing [`Explore`] for
+//! the state they work on.
+
+use std::time::Instant;
+
+use crate::{anim::Simulation, ParseError, Solution};
+
+/// A puzzle state that commands can be run against
+pub trait Explore {
+    /// The usage of each command the day adds, with a line saying what it does
+    fn commands(&self) -> Vec<(&'static str, &'static str)>;
+
+    /// Runs `command` with its arguments, returning what to print, or `None`
+    /// if the day has no such command
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>>;
+}
+
+/// The commands [`simulate`] understands, for days exploring a [`Simulation`]
+pub const SIMULATION_COMMANDS: &[(&str, &str)] = &[
+    (
+        "step [n]",
+        "Advance n steps (default 1) and show the result",
+    ),
+    ("show", "Show the current state"),
+];
+
+/// Runs `step` and `show` against a simulation
+pub fn simulate(
+    sim: &mut dyn Simulation,
+    command: &str,
+    args: &[&str],
+) -> Option<Result<String, String>> {
+    match command {
+        "step" => Some(count(args).map(|n| steps(sim, n))),
+        "show" => Some(Ok(sim.frame())),
+        _ => None,
+    }
+}
+
+/// Advances up to `n` steps and shows the result, saying so if it ran out
+pub fn steps(sim: &mut dyn Simulation, n: usize) -> String {
+    let taken = (0..n).take_while(|_| sim.step()).count();
+    match taken {
+        _ if taken < n => format!("Stopped after {} of {} steps\n{}", taken, n, sim.frame()),
+        _ => sim.frame(),
+    }
+}
+
+/// The number a command was given, or 1 if it wasn't given one
+pub fn count(args: &[&str]) -> Result<usize, String> {
+    match args {
+        [] => Ok(1),
+        [n] => n.parse().map_err(|_| format!("Invalid count {}", n)),
+        _ => Err(String::from("Expected at most one count")),
+    }
+}
+
+/// A session that runs command lines, see [`explore`]
+pub trait Shell {
+    /// Runs one line, returning what to print
+    fn exec(&mut self, line: &str) -> Result<String, String>;
+}
+
+/// Parses an input and starts a session on it, see [`explore`]
+pub type Explorer = fn(&str) -> Result<Box<dyn Shell>, ParseError>;
+
+/// Parses `input` with `S` and starts a session exploring `E` built from the
+/// result. Besides `E`'s commands, a session can solve either part of the
+/// original input and start `E` over.
+pub fn explore<S, E>(input: &str) -> Result<Box<dyn Shell>, ParseError>
+where
+    S: Solution + 'static,
+    E: Explore + From<S::Input> + 'static,
+{
+    let parse = || S::parse(input.lines().map(String::from));
+    Ok(Box::new(Session::<S, E> {
+        input: input.to_string(),
+        parsed: parse()?,
+        state: E::from(parse()?),
+    }))
+}
+
+const SESSION_COMMANDS: &[(&str, &str)] = &[
+    ("part1", "Solve part 1 of the input"),
+    ("part2", "Solve part 2 of the input"),
+    ("reset", "Start over from the input"),
+    ("help", "List the commands"),
+    ("quit", "Leave"),
+];
+
+struct Session<S: Solution, E> {
+    input: String,
+    parsed: S::Input,
+    state: E,
+}
+
+impl<S: Solution, E: Explore + From<S::Input>> Session<S, E> {
+    fn help(&self) -> String {
+        let commands = self.state.commands();
+        let all = commands.iter().chain(SESSION_COMMANDS);
+        let width = all.clone().map(|(usage, _)| usage.len()).max().unwrap_or(0);
+        all.map(|(usage, about)| format!("{:<width$}  {}\n", usage, about))
+            .collect()
+    }
+
+    fn solve(&self, part: u8) -> Result<String, String> {
+        let start = Instant::now();
+        let answer = match part {
+            1 => S::part1(&self.parsed),
+            _ => S::part2(&self.parsed),
+        };
+        let answer = answer?.to_string();
+        let separator = if answer.contains('\n') { '\n' } else { ' ' };
+        Ok(format!("{}{}({:?})", answer, separator, start.elapsed()))
+    }
+}
+
+impl<S: Solution, E: Explore + From<S::Input>> Shell for Session<S, E> {
+    fn exec(&mut self, line: &str) -> Result<String, String> {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+
+        match command {
+            "part1" => self.solve(1),
+            "part2" => self.solve(2),
+            "reset" => {
+                // This parsed when the session started, so it won't fail now
+                let parsed = S::parse(self.input.lines().map(String::from))
+                    .map_err(|e| e.report(&self.input))?;
+                self.state = E::from(parsed);
+                Ok(String::new())
+            }
+            "help" => Ok(self.help()),
+            _ => self
+                .state
+                .run(command, args)
+                .unwrap_or(Err(format!("Unknown command {} (try help)", command))),
+        }
+    }
+}
+
+#[cfg(test)]
+struct Countdown(u32);
+
+#[cfg(test)]
+impl Simulation for Countdown {
+    fn step(&mut self) -> bool {
+        if self.0 == 0 {
+            return false;
+        }
+        self.0 -= 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        format!("{}\n", self.0)
+    }
+}
+
+#[cfg(test)]
+impl From<Vec<u32>> for Countdown {
+    fn from(v: Vec<u32>) -> Countdown {
+        Countdown(v.iter().sum())
+    }
+}
+
+#[cfg(test)]
+impl Explore for Countdown {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        let mut commands = SIMULATION_COMMANDS.to_vec();
+        commands.push(("add <n>", "Count down from n more"));
+        commands
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>> {
+        match command {
+            "add" => Some(count(args).map(|n| {
+                self.0 += n as u32;
+                self.frame()
+            })),
+            _ => simulate(self, command, args),
+        }
+    }
+}
+
+#[test]
+fn count_test() {
+    assert_eq!(Ok(1), count(&[]));
+    assert_eq!(Ok(12), count(&["12"]));
+    assert!(count(&["x"]).is_err());
+    assert!(count(&["1", "2"]).is_err());
+}
+
+#[test]
+fn explore_test() {
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input = Vec<u32>;
+        const DAY: u8 = 1;
+
+        fn parse(v: impl Iterator<Item = String>) -> Result<Vec<u32>, ParseError> {
+            v.enumerate()
+                .map(|(i, x)| crate::Cursor::new(i + 1, &x).number())
+                .collect()
+        }
+        fn part1(input: &Vec<u32>) -> Result<crate::Answer, String> {
+            Ok(input.len().into())
+        }
+        fn part2(_: &Vec<u32>) -> Result<crate::Answer, String> {
+            Ok(0.into())
+        }
+    }
+
+    let mut shell = explore::<Numbers, Countdown>("2\n3").unwrap();
+    assert_eq!(Ok(String::from("5\n")), shell.exec("show"));
+    assert_eq!(Ok(String::from("3\n")), shell.exec("step 2"));
+    assert_eq!(Ok(String::from("6\n")), shell.exec("  add   3 "));
+    assert_eq!(
+        Ok(String::from("Stopped after 6 of 10 steps\n0\n")),
+        shell.exec("step 10")
+    );
+    assert_eq!(Ok(String::new()), shell.exec("reset"));
+    assert_eq!(Ok(String::from("5\n")), shell.exec("show"));
+    assert!(shell.exec("part1").unwrap().starts_with("2 ("));
+    assert_eq!(Ok(String::new()), shell.exec(""));
+
+    let help = shell.exec("help").unwrap();
+    assert!(help.starts_with("step [n]  Advance"));
+    assert!(help.contains("\nadd <n>   Count down from n more\n"));
+    assert!(help.contains("\npart1     Solve part 1"));
+
+    assert_eq!(
+        Err(String::from("Unknown command fold (try help)")),
+        shell.exec("fold 3")
+    );
+    assert!(shell.exec("step x").is_err());
+    assert!(explore::<Numbers, Countdown>("x").is_err());
+}
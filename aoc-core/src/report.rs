@@ -0,0 +1,124 @@
+//! Answers as JSON, one object per solved part, so runs can be ingested
+//! without scraping the plain output. Fields may be added but are never
+//! renamed or removed without bumping `schema`.
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::diag::Diagnostics;
+
+/// Version of the report layout
+pub const SCHEMA: u32 = 1;
+
+/// How answers are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    /// Just the answer
+    Text,
+    /// One [`Report`] per line
+    Json,
+}
+
+impl Output {
+    pub fn parse(s: &str) -> Result<Output, String> {
+        match s {
+            "text" => Ok(Output::Text),
+            "json" => Ok(Output::Json),
+            _ => Err(format!("Invalid format {} (expected text or json)", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub schema: u32,
+    pub day: u8,
+    pub part: u8,
+    /// Path of the input file, or `None` for stdin
+    pub input: Option<String>,
+    /// SHA-256 of the input text, in hex
+    pub input_hash: String,
+    pub answer: String,
+    /// `i64`, `u64`, `i128`, `u128` or `string`
+    pub answer_type: &'static str,
+    /// Parsing and solving together
+    pub elapsed_ns: u64,
+    pub diagnostics: Diagnostics,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("reports always serialize")
+    }
+
+    /// The diagnostics as aligned `name  value` lines, for printing after the
+    /// answer. Empty if the solver recorded none.
+    pub fn stats(&self) -> String {
+        let name_width = self.diagnostics.keys().map(String::len).max();
+        let value_width = self.diagnostics.values().map(|v| v.to_string().len()).max();
+        let (Some(name_width), Some(value_width)) = (name_width, value_width) else {
+            return String::new();
+        };
+        self.diagnostics
+            .iter()
+            .map(|(name, value)| format!("{:<name_width$}  {:>value_width$}\n", name, value))
+            .collect()
+    }
+}
+
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[test]
+fn hash_test() {
+    assert_eq!(
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        hash("")
+    );
+}
+
+#[cfg(test)]
+fn report(diagnostics: &[(&str, u64)]) -> Report {
+    Report {
+        schema: SCHEMA,
+        day: 23,
+        part: 1,
+        input: None,
+        input_hash: hash(""),
+        answer: String::from("12521"),
+        answer_type: "u64",
+        elapsed_ns: 1500,
+        diagnostics: diagnostics
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value))
+            .collect(),
+    }
+}
+
+#[test]
+fn stats_test() {
+    let report = report(&[("states_explored", 12345), ("frontier_peak", 67)]);
+    assert_eq!(
+        "frontier_peak       67\nstates_explored  12345\n",
+        report.stats()
+    );
+    assert_eq!("", self::report(&[]).stats());
+}
+
+#[test]
+fn to_json_test() {
+    let report = report(&[("states_explored", 9)]);
+    assert_eq!(
+        concat!(
+            r#"{"schema":1,"day":23,"part":1,"input":null,"#,
+            r#""input_hash":"e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855","#,
+            r#""answer":"12521","answer_type":"u64","elapsed_ns":1500,"#,
+            r#""diagnostics":{"states_explored":9}}"#
+        ),
+        report.to_json()
+    );
+}
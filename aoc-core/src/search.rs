@@ -0,0 +1,335 @@
+//! Graph searches over a successor function, so puzzles only have to describe
+//! how to get from one state to the next.
+
+use crate::diag;
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The outcome of a search, along with how much work it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search<N, C> {
+    /// Every state from the start to the goal, inclusive, and the total cost
+    pub found: Option<(Vec<N>, C)>,
+    /// Number of states taken off the frontier and expanded
+    pub explored: usize,
+    /// The most states that were waiting on the frontier at once
+    pub peak_frontier: usize,
+    /// Frontier entries dropped because a cheaper way to their state was
+    /// found after they were queued
+    pub stale: usize,
+}
+
+impl<N, C: Copy> Search<N, C> {
+    /// Records how much work the search took with [`diag`]
+    pub fn record(&self) {
+        diag::record("states_explored", self.explored as u64);
+        diag::max("frontier_peak", self.peak_frontier as u64);
+        diag::add("stale_entries_skipped", self.stale as u64);
+    }
+
+    pub fn cost(&self) -> Option<C> {
+        self.found.as_ref().map(|&(_, cost)| cost)
+    }
+
+    pub fn path(&self) -> Option<&[N]> {
+        self.found.as_ref().map(|(path, _)| path.as_slice())
+    }
+}
+
+/// The outcome of `count_paths`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathCount {
+    pub paths: usize,
+    /// Number of distinct states visited
+    pub explored: usize,
+    /// Visits to a state whose count was already known
+    pub memo_hits: usize,
+}
+
+impl PathCount {
+    /// Records how much work the count took with [`diag`]
+    pub fn record(&self) {
+        diag::record("states_explored", self.explored as u64);
+        diag::add("memo_hits", self.memo_hits as u64);
+    }
+}
+
+/// Every state seen so far, in the order it was first reached, with the index
+/// of the state it was reached from
+struct Visited<N, C> {
+    states: Vec<(N, Option<usize>, C)>,
+    index: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Visited<N, C> {
+    fn new(start: N, cost: C) -> Visited<N, C> {
+        Visited {
+            states: vec![(start.clone(), None, cost)],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Vec<N> {
+        let mut path = vec![];
+        loop {
+            let (state, parent, _) = &self.states[i];
+            path.push(state.clone());
+            match parent {
+                Some(parent) => i = *parent,
+                None => break,
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Cheapest path from `start` to any state accepted by `success`, where
+/// `successors` yields each next state with the (non-negative) cost of moving to it
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), success)
+}
+
+/// Like `dijkstra`, but explores states in order of cost so far plus
+/// `heuristic`, which must never overestimate the remaining cost
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut success: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), zero, 0))]);
+    let mut visited = Visited::new(start, zero);
+    let (mut explored, mut peak_frontier, mut stale) = (0, 1, 0);
+
+    while let Some(Reverse((_, cost, i))) = frontier.pop() {
+        // A cheaper way here was found after this entry was queued
+        if cost > visited.states[i].2 {
+            stale += 1;
+            continue;
+        }
+        explored += 1;
+
+        let state = visited.states[i].0.clone();
+        if success(&state) {
+            return Search {
+                found: Some((visited.path(i), cost)),
+                explored,
+                peak_frontier,
+                stale,
+            };
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let j = match visited.index.entry(next) {
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if visited.states[j].2 <= next_cost {
+                        continue;
+                    }
+                    visited.states[j].1 = Some(i);
+                    visited.states[j].2 = next_cost;
+                    j
+                }
+                Entry::Vacant(e) => {
+                    let j = visited.states.len();
+                    visited.states.push((e.key().clone(), Some(i), next_cost));
+                    e.insert(j);
+                    j
+                }
+            };
+            let estimate = next_cost + heuristic(&visited.states[j].0);
+            frontier.push(Reverse((estimate, next_cost, j)));
+        }
+        peak_frontier = peak_frontier.max(frontier.len());
+    }
+
+    Search {
+        found: None,
+        explored,
+        peak_frontier,
+        stale,
+    }
+}
+
+/// Shortest path by number of steps, visiting each state at most once
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut frontier = VecDeque::from([0]);
+    let mut visited = Visited::new(start, 0);
+    let (mut explored, mut peak_frontier) = (0, 1);
+
+    while let Some(i) = frontier.pop_front() {
+        explored += 1;
+
+        let (state, _, steps) = visited.states[i].clone();
+        if success(&state) {
+            return Search {
+                found: Some((visited.path(i), steps)),
+                explored,
+                peak_frontier,
+                stale: 0,
+            };
+        }
+
+        for next in successors(&state) {
+            if let Entry::Vacant(e) = visited.index.entry(next) {
+                let j = visited.states.len();
+                visited.states.push((e.key().clone(), Some(i), steps + 1));
+                e.insert(j);
+                frontier.push_back(j);
+            }
+        }
+        peak_frontier = peak_frontier.max(frontier.len());
+    }
+
+    Search {
+        found: None,
+        explored,
+        peak_frontier,
+        stale: 0,
+    }
+}
+
+/// Number of distinct paths from `start` to states accepted by `success`,
+/// which stop the path. The successor graph must not contain cycles.
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> PathCount
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    fn count<N: Clone + Eq + Hash, I: IntoIterator<Item = N>>(
+        state: &N,
+        successors: &mut impl FnMut(&N) -> I,
+        success: &mut impl FnMut(&N) -> bool,
+        memo: &mut HashMap<N, usize>,
+        hits: &mut usize,
+    ) -> usize {
+        if let Some(&paths) = memo.get(state) {
+            *hits += 1;
+            return paths;
+        }
+        let paths = if success(state) {
+            1
+        } else {
+            let mut paths = 0;
+            for next in successors(state) {
+                paths += count(&next, successors, success, memo, hits);
+            }
+            paths
+        };
+        memo.insert(state.clone(), paths);
+        paths
+    }
+
+    let (mut memo, mut memo_hits) = (HashMap::new(), 0);
+    let paths = count(
+        &start,
+        &mut successors,
+        &mut success,
+        &mut memo,
+        &mut memo_hits,
+    );
+    PathCount {
+        paths,
+        explored: memo.len(),
+        memo_hits,
+    }
+}
+
+// 1 -(2)-> 2 -(2)-> 4
+// 1 -(5)-> 3 -(1)-> 4
+#[cfg(test)]
+fn weighted(n: &u32) -> Vec<(u32, u32)> {
+    match n {
+        1 => vec![(2, 2), (3, 5)],
+        2 => vec![(4, 2)],
+        3 => vec![(4, 1)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn dijkstra_test() {
+    let search = dijkstra(1, weighted, |&n| n == 4);
+    assert_eq!(Some((vec![1, 2, 4], 4)), search.found);
+    assert_eq!(2, search.peak_frontier);
+    assert_eq!(None, dijkstra(1, weighted, |&n| n == 5).found);
+
+    // 3 is queued straight from 1, then again more cheaply through 2
+    let shortcut = |&n: &u32| match n {
+        1 => vec![(2, 1), (3, 5)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    };
+    let search = dijkstra(1, shortcut, |_| false);
+    assert_eq!(3, search.explored);
+    assert_eq!(1, search.stale);
+}
+
+#[test]
+fn astar_test() {
+    let search = astar(1, weighted, |&n| 4 - n.min(4), |&n| n == 4);
+    assert_eq!(Some(&[1, 2, 4][..]), search.path());
+    assert_eq!(Some(4), search.cost());
+
+    // A perfect heuristic only explores the path itself
+    let exact = |n: &i32| (10 - n).unsigned_abs();
+    let search = astar(0, |&n| [(n - 1, 1), (n + 1, 1)], exact, |&n| n == 10);
+    assert_eq!(Some(10), search.cost());
+    assert_eq!(11, search.explored);
+}
+
+#[test]
+fn bfs_test() {
+    let search = bfs(
+        1,
+        |&n| weighted(&n).into_iter().map(|(n, _)| n),
+        |&n| n == 4,
+    );
+    assert_eq!(Some((vec![1, 2, 4], 2)), search.found);
+    assert_eq!(4, search.explored);
+}
+
+#[test]
+fn count_paths_test() {
+    let counted = count_paths(
+        1,
+        |&n| weighted(&n).into_iter().map(|(n, _)| n),
+        |&n| n == 4,
+    );
+    assert_eq!(2, counted.paths);
+    assert_eq!(4, counted.explored);
+    assert_eq!(1, counted.memo_hits);
+}
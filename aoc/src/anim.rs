@@ -0,0 +1,251 @@
+use std::{
+    fs, io,
+    io::prelude::*,
+    path::Path,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    anim::{frames, Simulation},
+    input,
+};
+
+use crate::days;
+
+const CLEAR: &str = "\x1b[2J\x1b[H";
+const CONTROLS: &str = "enter: pause/resume  s: step  q: quit";
+
+// The slowest frame rate, one frame an hour
+const MAX_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug)]
+struct Options {
+    day: u8,
+    input: Option<String>,
+    /// Time between frames
+    interval: Duration,
+    limit: Option<usize>,
+    frames: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        day: 0,
+        input: None,
+        interval: Duration::from_millis(100),
+        limit: None,
+        frames: None,
+    };
+    let mut day = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .cloned()
+                .ok_or(format!("{} needs a value", name))
+        };
+        match arg.as_str() {
+            "--input" => options.input = Some(value("--input")?),
+            "--frames" => options.frames = Some(value("--frames")?),
+            "--fps" => {
+                let fps = value("--fps")?;
+                options.interval = fps
+                    .parse()
+                    .ok()
+                    .filter(|&fps: &f64| fps > 0.0)
+                    .and_then(|fps| Duration::try_from_secs_f64(1.0 / fps).ok())
+                    .filter(|&interval| interval <= MAX_INTERVAL)
+                    .ok_or(format!(
+                        "Invalid frame rate {} (expected at least one frame an hour)",
+                        fps
+                    ))?;
+            }
+            "--limit" => {
+                let limit = value("--limit")?;
+                options.limit = Some(
+                    limit
+                        .parse()
+                        .map_err(|_| format!("Invalid frame limit {}", limit))?,
+                );
+            }
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u8>()
+                        .map_err(|_| format!("Invalid argument {}", arg))?,
+                )
+            }
+            _ => return Err(String::from(crate::USAGE)),
+        }
+    }
+
+    options.day = day.ok_or(String::from(crate::USAGE))?;
+    Ok(options)
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let options = parse_args(args)?;
+
+    let animate =
+        days::animator(options.day).ok_or(format!("No animation for day {}", options.day))?;
+    let path = options
+        .input
+        .clone()
+        .unwrap_or_else(|| days::input_path(options.day));
+    let input = input::read(Path::new(&path))?;
+    let mut sim = animate(&input).map_err(|e| format!("{}: {}", path, e.report(&input)))?;
+
+    let limit = options.limit.unwrap_or(usize::MAX);
+    match &options.frames {
+        Some(dir) => {
+            let written = write_frames(Path::new(dir), sim.as_mut(), limit)?;
+            println!("Wrote {} frames to {}", written, dir);
+            Ok(())
+        }
+        None => play(sim.as_mut(), options.interval, limit),
+    }
+}
+
+/// Saves each frame as `frame-NNNN.txt` in `dir`, returning how many were written
+fn write_frames(dir: &Path, sim: &mut dyn Simulation, limit: usize) -> Result<usize, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    let mut written = 0;
+    for (n, frame) in frames(sim).take(limit).enumerate() {
+        let path = dir.join(format!("frame-{:04}.txt", n));
+        fs::write(&path, frame).map_err(|e| format!("{}: {}", path.display(), e))?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    Pause,
+    Step,
+    Quit,
+}
+
+fn control(line: &str) -> Option<Control> {
+    match line.trim() {
+        "" | "p" => Some(Control::Pause),
+        "s" => Some(Control::Step),
+        "q" => Some(Control::Quit),
+        _ => None,
+    }
+}
+
+// Lines typed on stdin while the animation runs
+fn controls() -> Receiver<Control> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Some(control) = line.ok().as_deref().and_then(control) else {
+                continue;
+            };
+            if tx.send(control).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+/// Redraws the terminal with each frame, `interval` apart
+fn play(sim: &mut dyn Simulation, interval: Duration, limit: usize) -> Result<(), String> {
+    let mut controls = Some(controls());
+    let mut paused = false;
+
+    for (n, frame) in frames(sim).take(limit).enumerate() {
+        print!("{}{}", CLEAR, frame);
+        println!(
+            "frame {}{}  ({})",
+            n,
+            if paused { " (paused)" } else { "" },
+            CONTROLS
+        );
+        io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let next = Instant::now() + interval;
+        loop {
+            let Some(rx) = &controls else {
+                thread::sleep(next.saturating_duration_since(Instant::now()));
+                break;
+            };
+            let received = if paused {
+                rx.recv().map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                rx.recv_timeout(next.saturating_duration_since(Instant::now()))
+            };
+            match received {
+                Ok(Control::Pause) => paused = !paused,
+                Ok(Control::Step) => {
+                    paused = true;
+                    break;
+                }
+                Ok(Control::Quit) => return Ok(()),
+                Err(RecvTimeoutError::Timeout) => break,
+                // Nothing more can be typed, so play through to the end
+                Err(RecvTimeoutError::Disconnected) => {
+                    controls = None;
+                    paused = false;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn parse_args_test() {
+    let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+    let options = parse_args(&args("25")).unwrap();
+    assert_eq!(25, options.day);
+    assert_eq!(Duration::from_millis(100), options.interval);
+    assert_eq!(None, options.frames);
+
+    let options = parse_args(&args("11 --fps 2.5 --limit 5 --frames out")).unwrap();
+    assert_eq!(Duration::from_millis(400), options.interval);
+    assert_eq!(Some(5), options.limit);
+    assert_eq!(Some(String::from("out")), options.frames);
+
+    assert!(parse_args(&args("11 --fps 0")).is_err());
+    assert!(parse_args(&args("11 --fps 1e-20")).is_err());
+    assert!(parse_args(&args("11 --fps 0.0001")).is_err());
+    assert!(parse_args(&args("11 --fps NaN")).is_err());
+    let options = parse_args(&args("11 --fps 0.001")).unwrap();
+    assert_eq!(Duration::from_secs(1000), options.interval);
+    assert!(parse_args(&args("11 12")).is_err());
+    assert!(parse_args(&args("--fps 2")).is_err());
+}
+
+#[test]
+fn control_test() {
+    assert_eq!(Some(Control::Pause), control(""));
+    assert_eq!(Some(Control::Step), control("s\n"));
+    assert_eq!(Some(Control::Quit), control("q"));
+    assert_eq!(None, control("x"));
+}
+
+#[test]
+fn write_frames_test() {
+    let dir = std::env::temp_dir().join(format!("aoc-anim-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    let mut sim = (days::animator(25).unwrap())("..>>.").unwrap();
+    assert_eq!(3, write_frames(&dir, sim.as_mut(), 3).unwrap());
+    assert_eq!(
+        "..>.>\n",
+        fs::read_to_string(dir.join("frame-0001.txt")).unwrap()
+    );
+    assert_eq!(
+        ">..>.\n",
+        fs::read_to_string(dir.join("frame-0002.txt")).unwrap()
+    );
+    assert!(!dir.join("frame-0003.txt").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,50 @@
+use std::{collections::BTreeMap, fs};
+
+/// Expected answers keyed by `(day, part)`
+pub type Answers = BTreeMap<(u8, u8), String>;
+
+pub const PATH: &str = "answers.txt";
+
+// One `<day> <part> <answer>` per line; newlines in an answer are written as `\n`
+pub fn parse(text: &str) -> Result<Answers, String> {
+    let mut answers = Answers::new();
+
+    for (i, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.splitn(3, ' ');
+        let mut number = || fields.next().and_then(|f| f.parse::<u8>().ok());
+        let (day, part) = match (number(), number()) {
+            (Some(day), Some(part)) => (day, part),
+            _ => {
+                return Err(format!(
+                    "{}:{}: expected <day> <part> <answer>",
+                    PATH,
+                    i + 1
+                ))
+            }
+        };
+        let answer = fields.next().unwrap_or_default().replace("\\n", "\n");
+
+        answers.insert((day, part), answer);
+    }
+
+    Ok(answers)
+}
+
+pub fn load() -> Result<Answers, String> {
+    let text = fs::read_to_string(PATH).map_err(|e| format!("{}: {}", PATH, e))?;
+    parse(&text)
+}
+
+#[test]
+fn parse_test() {
+    let answers = parse("# comment\n01 1 1688\n\n13 2 █ █\\n ██\\n\n").unwrap();
+    assert_eq!(Some(&String::from("1688")), answers.get(&(1, 1)));
+    assert_eq!(Some(&String::from("█ █\n ██\n")), answers.get(&(13, 2)));
+    assert_eq!(2, answers.len());
+
+    assert!(parse("01 x 1688").is_err());
+}
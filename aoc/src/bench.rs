@@ -0,0 +1,245 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use aoc_core::input;
+use serde::{Deserialize, Serialize};
+
+use crate::{days, run::format_duration};
+
+const LATEST: &str = "target/bench/latest.json";
+const BASELINE: &str = "target/bench/baseline.json";
+
+// A median this much slower than the baseline's is called out as a regression
+const REGRESSION: f64 = 1.10;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Report {
+    runs: usize,
+    results: Vec<Timing>,
+}
+
+/// Timings of one part in nanoseconds, including parsing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Timing {
+    day: u8,
+    part: u8,
+    min: u64,
+    median: u64,
+    max: u64,
+}
+
+impl Timing {
+    fn from(day: u8, part: u8, mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+        let nanos = |d: &Duration| d.as_nanos() as u64;
+
+        Timing {
+            day,
+            part,
+            min: nanos(&samples[0]),
+            median: nanos(&samples[samples.len() / 2]),
+            max: nanos(&samples[samples.len() - 1]),
+        }
+    }
+}
+
+struct Options {
+    days: Vec<u8>,
+    parts: Vec<u8>,
+    runs: usize,
+    baseline: String,
+    save_baseline: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut runs = 10;
+    let mut baseline = String::from(BASELINE);
+    let mut save_baseline = false;
+    let mut positional = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => {
+                runs = args
+                    .next()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--runs needs a positive number")?
+            }
+            "--baseline" => baseline = args.next().ok_or("--baseline needs a path")?.clone(),
+            "--save-baseline" => save_baseline = true,
+            _ => positional.push(
+                arg.parse::<u8>()
+                    .map_err(|_| format!("Invalid argument {}", arg))?,
+            ),
+        }
+    }
+
+    let (days, parts) = match positional.as_slice() {
+        [] => (days::all().collect(), vec![1, 2]),
+        [day] => (vec![*day], vec![1, 2]),
+        [day, part] => (vec![*day], vec![*part]),
+        _ => return Err(String::from(crate::USAGE)),
+    };
+    if let Some(part) = parts.iter().find(|&&p| p != 1 && p != 2) {
+        return Err(format!("Invalid part {}", part));
+    }
+
+    Ok(Options {
+        days,
+        parts,
+        runs,
+        baseline,
+        save_baseline,
+    })
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let options = parse_args(args)?;
+    let baseline = load(&options.baseline).ok();
+
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Part", "Min", "Median", "Max", "Baseline"
+    );
+
+    let mut results = vec![];
+    for &day in &options.days {
+        let solve = days::solver(day).ok_or(format!("No solver for day {}", day))?;
+        let path = days::input_path(day);
+        let input = input::read(Path::new(&path))?;
+
+        for &part in &options.parts {
+            // One untimed run to warm caches and surface parse errors
+            solve(part, &input).map_err(|e| format!("{}: {}", path, e.report(&input)))?;
+
+            let samples = (0..options.runs)
+                .map(|_| {
+                    let start = Instant::now();
+                    let _ = solve(part, &input);
+                    start.elapsed()
+                })
+                .collect();
+
+            let timing = Timing::from(day, part, samples);
+            let previous = baseline
+                .as_ref()
+                .and_then(|b| b.results.iter().find(|t| t.day == day && t.part == part));
+            print_row(&timing, previous);
+            results.push(timing);
+        }
+    }
+
+    let report = Report {
+        runs: options.runs,
+        results,
+    };
+    save(LATEST, &report)?;
+    println!("Wrote {}", LATEST);
+    if options.save_baseline {
+        // Parts that weren't benchmarked this time keep their old baseline
+        let mut merged = baseline.map_or(vec![], |b| b.results);
+        merged.retain(|t| {
+            !report
+                .results
+                .iter()
+                .any(|r| (r.day, r.part) == (t.day, t.part))
+        });
+        merged.extend(report.results);
+        merged.sort_by_key(|t| (t.day, t.part));
+
+        let baseline = Report {
+            runs: report.runs,
+            results: merged,
+        };
+        save(&options.baseline, &baseline)?;
+        println!("Saved baseline to {}", options.baseline);
+    }
+
+    Ok(())
+}
+
+fn print_row(timing: &Timing, baseline: Option<&Timing>) {
+    let ns = |n: u64| format_duration(Duration::from_nanos(n));
+
+    println!(
+        "{:>3}  {:>4}  {:>10}  {:>10}  {:>10}  {:>10}",
+        timing.day,
+        timing.part,
+        ns(timing.min),
+        ns(timing.median),
+        ns(timing.max),
+        baseline.map_or(String::from("-"), |b| compare(timing, b)),
+    );
+}
+
+// Relative change of the median, e.g. "+12.5% !" for a regression
+fn compare(timing: &Timing, baseline: &Timing) -> String {
+    let ratio = timing.median as f64 / baseline.median.max(1) as f64;
+    let flag = if ratio > REGRESSION { " !" } else { "" };
+    format!("{:+.1}%{}", (ratio - 1.0) * 100.0, flag)
+}
+
+fn load(path: &str) -> Result<Report, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn save(path: &str, report: &Report) -> Result<(), String> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    }
+    let json = serde_json::to_string_pretty(report).map_err(|e| e.to_string())?;
+    fs::write(path, json + "\n").map_err(|e| format!("{}: {}", path, e))
+}
+
+#[test]
+fn timing_test() {
+    let samples = [30, 10, 20, 50, 40].map(Duration::from_nanos).to_vec();
+    let timing = Timing::from(3, 1, samples);
+
+    assert_eq!((10, 30, 50), (timing.min, timing.median, timing.max));
+}
+
+#[test]
+fn compare_test() {
+    let timing = |median| Timing {
+        day: 1,
+        part: 1,
+        min: 0,
+        median,
+        max: 0,
+    };
+
+    assert_eq!("+0.0%", compare(&timing(100), &timing(100)));
+    assert_eq!("-50.0%", compare(&timing(50), &timing(100)));
+    assert_eq!("+25.0% !", compare(&timing(125), &timing(100)));
+}
+
+#[test]
+fn report_json_test() {
+    let report = Report {
+        runs: 5,
+        results: vec![Timing {
+            day: 19,
+            part: 2,
+            min: 1,
+            median: 2,
+            max: 3,
+        }],
+    };
+    let json = serde_json::to_string(&report).unwrap();
+
+    assert_eq!(
+        r#"{"runs":5,"results":[{"day":19,"part":2,"min":1,"median":2,"max":3}]}"#,
+        json
+    );
+    assert_eq!(
+        report.results,
+        serde_json::from_str::<Report>(&json).unwrap().results
+    );
+}
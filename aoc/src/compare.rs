@@ -0,0 +1,158 @@
+use aoc_core::compare::{minimize, Disagreement};
+
+use crate::{days, gen};
+
+#[derive(Debug)]
+struct Options {
+    day: u8,
+    seeds: u64,
+    params: Vec<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        day: 0,
+        seeds: 100,
+        params: vec![],
+    };
+    let mut day = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seeds" => {
+                let seeds = args.next().ok_or("--seeds needs a value")?;
+                options.seeds = seeds
+                    .parse()
+                    .ok()
+                    .filter(|&seeds| seeds > 0)
+                    .ok_or(format!("Invalid number of seeds {}", seeds))?;
+            }
+            _ if arg.contains('=') => options.params.push(arg.clone()),
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u8>()
+                        .map_err(|_| format!("Invalid argument {}", arg))?,
+                )
+            }
+            _ => return Err(String::from(crate::USAGE)),
+        }
+    }
+
+    options.day = day.ok_or(String::from(crate::USAGE))?;
+    Ok(options)
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let options = parse_args(args)?;
+
+    // Panics count as answers, and minimizing can set off hundreds of them
+    std::panic::set_hook(Box::new(|_| {}));
+    match first_disagreement(&options)? {
+        None => {
+            println!(
+                "Day {}: every implementation agrees on {} generated inputs",
+                options.day, options.seeds
+            );
+            Ok(())
+        }
+        Some(found) => {
+            print!("{}", found);
+            Err(format!(
+                "Day {} part {} implementations disagree",
+                options.day, found.disagreement.part
+            ))
+        }
+    }
+}
+
+/// A generated input the implementations disagree on, cut down
+struct Found {
+    seed: u64,
+    lines: usize,
+    input: String,
+    disagreement: Disagreement,
+}
+
+impl std::fmt::Display for Found {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answers = &self.disagreement.answers;
+        let width = answers
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        writeln!(
+            f,
+            "Seed {}: part {} answers differ on {} of its {} lines",
+            self.seed,
+            self.disagreement.part,
+            self.input.lines().count(),
+            self.lines
+        )?;
+        for (name, answer) in answers {
+            writeln!(f, "  {:<width$}  {}", name, answer)?;
+        }
+        writeln!(f, "Input:")?;
+        write!(f, "{}", self.input)
+    }
+}
+
+/// Compares every implementation on each seed's input, and minimizes the
+/// first input they disagree on
+fn first_disagreement(options: &Options) -> Result<Option<Found>, String> {
+    let day = options.day;
+    let compare = days::comparer(day).ok_or(format!("Day {} has no alternatives", day))?;
+    let report = |input: &str, e: aoc_core::ParseError| e.report(input);
+
+    for seed in 0..options.seeds {
+        let input = gen::generate(day, seed, &options.params)?;
+        let Some(first) = compare(&input).map_err(|e| report(&input, e))? else {
+            continue;
+        };
+
+        // Keep to inputs where the same part still disagrees
+        let part = first.part;
+        let fails = |input: &str| matches!(compare(input), Ok(Some(d)) if d.part == part);
+        let small = minimize(&input, fails);
+        let disagreement = compare(&small)
+            .map_err(|e| report(&small, e))?
+            .unwrap_or(first);
+        return Ok(Some(Found {
+            seed,
+            lines: input.lines().count(),
+            input: small,
+            disagreement,
+        }));
+    }
+    Ok(None)
+}
+
+#[test]
+fn parse_args_test() {
+    let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+    let options = parse_args(&args("3")).unwrap();
+    assert_eq!(3, options.day);
+    assert_eq!(100, options.seeds);
+
+    let options = parse_args(&args("22 steps=30 --seeds 5")).unwrap();
+    assert_eq!(5, options.seeds);
+    assert_eq!(vec!["steps=30"], options.params);
+
+    assert!(parse_args(&args("3 --seeds 0")).is_err());
+    assert!(parse_args(&args("3 4")).is_err());
+    assert!(parse_args(&args("--seeds 4")).is_err());
+}
+
+#[test]
+fn first_disagreement_test() {
+    let args = |s: &str| parse_args(&s.split_whitespace().map(String::from).collect::<Vec<_>>());
+
+    for options in ["3 lines=40 --seeds 20", "22 steps=30 init=10 --seeds 5"] {
+        let found = first_disagreement(&args(options).unwrap()).unwrap();
+        assert!(found.is_none(), "{}", found.unwrap());
+    }
+    assert!(first_disagreement(&args("4").unwrap()).is_err());
+    assert!(first_disagreement(&args("3 size=1").unwrap()).is_err());
+}
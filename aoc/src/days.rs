@@ -0,0 +1,318 @@
+use aoc_core::{
+    anim::{animate, Animator},
+    compare::Comparer,
+    gen::Generator,
+    repl::{explore, Explorer},
+    Grid, ParseError, Report, SolveError,
+};
+
+/// Parses an input and solves one part of a day, see [`aoc_core::solve`]
+pub type Solver = fn(u8, &str) -> Result<Option<String>, SolveError>;
+
+/// Parses an input without solving it, see [`aoc_core::check`]
+pub type Parser = fn(&str) -> Result<(), ParseError>;
+
+/// Solves one part with timing and diagnostics, see [`aoc_core::report`]
+pub type Reporter = fn(u8, &str) -> Result<Option<Report>, SolveError>;
+
+/// Builds the solver, parser and reporter tables from one list of days
+macro_rules! days {
+    ($($day:literal => $solution:ty,)*) => {
+        static SOLVERS: &[(u8, Solver)] = &[$(($day, aoc_core::solve::<$solution>)),*];
+        static PARSERS: &[(u8, Parser)] = &[$(($day, aoc_core::check::<$solution>)),*];
+        static REPORTERS: &[(u8, Reporter)] = &[$(($day, aoc_core::report::<$solution>)),*];
+    };
+}
+
+// `aoc new` adds each new day to the end of this list
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+}
+
+/// The solver for `day` (1-based), if there is one
+pub fn solver(day: u8) -> Option<Solver> {
+    SOLVERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, solve)| *solve)
+}
+
+/// Every day number known to the runner
+pub fn all() -> impl Iterator<Item = u8> {
+    SOLVERS.iter().map(|(day, _)| *day)
+}
+
+/// Where a day's puzzle input lives, relative to the workspace root
+pub fn input_path(day: u8) -> String {
+    format!("day{:02}/input", day)
+}
+
+/// The parser for `day`, if there is one
+pub fn parser(day: u8) -> Option<Parser> {
+    PARSERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, check)| *check)
+}
+
+/// The reporter for `day`, if there is one
+pub fn reporter(day: u8) -> Option<Reporter> {
+    REPORTERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, report)| *report)
+}
+
+/// Days that can be watched with `aoc anim`, see [`aoc_core::anim::animate`]
+static ANIMATIONS: &[(u8, Animator)] = &[
+    (11, animate::<day11::Day11, day11::Flashes>),
+    (13, animate::<day13::Day13, day13::Folding>),
+    (20, animate::<day20::Day20, day20::Enhancement>),
+    (23, animate::<day23::Day23, day23::Moves>),
+    (25, animate::<day25::Day25, day25::Herds>),
+];
+
+/// The animation for `day`, if it has one
+pub fn animator(day: u8) -> Option<Animator> {
+    ANIMATIONS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, animate)| *animate)
+}
+
+/// Days with commands for `aoc repl`, see [`aoc_core::repl::explore`]
+static EXPLORERS: &[(u8, Explorer)] = &[
+    (11, explore::<day11::Day11, day11::Flashes>),
+    (12, explore::<day12::Day12, day12::Cave>),
+    (13, explore::<day13::Day13, day13::Folding>),
+    (20, explore::<day20::Day20, day20::Enhancement>),
+    (23, explore::<day23::Day23, day23::Moves>),
+    (25, explore::<day25::Day25, day25::Herds>),
+];
+
+/// The REPL session for `day`, if it has one
+pub fn explorer(day: u8) -> Option<Explorer> {
+    EXPLORERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, explore)| *explore)
+}
+
+/// Parses an input and draws the state after one part, see [`aoc_core::picture`]
+pub type Painter = fn(u8, &str) -> Result<Option<Grid<usize>>, ParseError>;
+
+/// Days with a picture for `aoc run --export`
+static PICTURES: &[(u8, Painter)] = &[
+    (5, aoc_core::picture::<day05::Day05>),
+    (9, aoc_core::picture::<day09::Day09>),
+    (13, aoc_core::picture::<day13::Day13>),
+    (20, aoc_core::picture::<day20::Day20>),
+];
+
+/// The picture for `day`, if it has one
+pub fn painter(day: u8) -> Option<Painter> {
+    PICTURES
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, paint)| *paint)
+}
+
+/// Days with more than one way of solving a part, for `aoc compare`, see
+/// [`aoc_core::compare::parts`]
+static COMPARERS: &[(u8, Comparer)] = &[
+    (3, aoc_core::compare::parts::<day03::Day03>),
+    (22, aoc_core::compare::parts::<day22::Day22>),
+];
+
+/// The cross-check for `day`, if it has alternatives to check
+pub fn comparer(day: u8) -> Option<Comparer> {
+    COMPARERS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, compare)| *compare)
+}
+
+/// Input generators for `aoc gen`, see [`aoc_core::gen`]
+static GENERATORS: &[(u8, Generator)] = &[
+    (1, day01::generate),
+    (2, day02::generate),
+    (3, day03::generate),
+    (4, day04::generate),
+    (5, day05::generate),
+    (6, day06::generate),
+    (7, day07::generate),
+    (8, day08::generate),
+    (9, day09::generate),
+    (10, day10::generate),
+    (11, day11::generate),
+    (12, day12::generate),
+    (13, day13::generate),
+    (14, day14::generate),
+    (15, day15::generate),
+    (16, day16::generate),
+    (17, day17::generate),
+    (18, day18::generate),
+    (19, day19::generate),
+    (20, day20::generate),
+    (21, day21::generate),
+    (22, day22::generate),
+    (23, day23::generate),
+    (24, day24::generate),
+    (25, day25::generate),
+];
+
+/// The input generator for `day`, if it has one
+pub fn generator(day: u8) -> Option<Generator> {
+    GENERATORS
+        .iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, generate)| *generate)
+}
+
+// Small enough that every day's generator and parser run quickly in tests
+#[cfg(test)]
+const SMALL: &[&str] = &[
+    "attempts=1",
+    "beacons=2",
+    "boards=3",
+    "commands=20",
+    "crabs=20",
+    "depth=3",
+    "depths=20",
+    "displays=5",
+    "dots=30",
+    "elements=4",
+    "fish=20",
+    "folds=3",
+    "height=8",
+    "init=5",
+    "length=30",
+    "lines=20",
+    "max=100",
+    "numbers=4",
+    "reach=60",
+    "scanners=3",
+    "size=50",
+    "steps=30",
+    "template=10",
+    "width=12",
+];
+
+#[cfg(test)]
+fn small_input(day: u8, seed: u64) -> String {
+    let params = SMALL.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+    let params = aoc_core::gen::Params::parse(&params).unwrap();
+    generator(day).unwrap()(&mut aoc_core::gen::Rng::new(seed), &params)
+}
+
+/// A change to one character of an input, at an index taken modulo its length
+#[cfg(test)]
+#[derive(Debug, Clone)]
+enum Edit {
+    Delete(usize),
+    Insert(usize, char),
+    Replace(usize, char),
+    Truncate(usize),
+}
+
+#[cfg(test)]
+fn edit() -> impl proptest::strategy::Strategy<Value = Edit> {
+    use proptest::prelude::*;
+
+    // Characters the parsers care about, and now and then anything at all
+    let c = prop_oneof![
+        4 => proptest::sample::select("0189-,.#>v[]=| \nxyzABCD".chars().collect::<Vec<_>>()),
+        1 => any::<char>(),
+    ];
+    prop_oneof![
+        any::<usize>().prop_map(Edit::Delete),
+        (any::<usize>(), c.clone()).prop_map(|(i, c)| Edit::Insert(i, c)),
+        (any::<usize>(), c).prop_map(|(i, c)| Edit::Replace(i, c)),
+        any::<usize>().prop_map(Edit::Truncate),
+    ]
+}
+
+#[cfg(test)]
+fn apply(input: &str, edits: &[Edit]) -> String {
+    let mut chars = input.chars().collect::<Vec<_>>();
+    for edit in edits {
+        let at = |i: usize, len: usize| if len == 0 { 0 } else { i % len };
+        match *edit {
+            Edit::Delete(i) if !chars.is_empty() => {
+                chars.remove(at(i, chars.len()));
+            }
+            Edit::Insert(i, c) => chars.insert(at(i, chars.len() + 1), c),
+            Edit::Replace(i, c) if !chars.is_empty() => {
+                let i = at(i, chars.len());
+                chars[i] = c;
+            }
+            Edit::Truncate(i) => chars.truncate(at(i, chars.len() + 1)),
+            _ => {}
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn generated_inputs_parse(day in 1u8..=25, seed: u64) {
+        let input = small_input(day, seed);
+        proptest::prop_assert_eq!(Ok(()), parser(day).unwrap()(&input));
+    }
+
+    // Parsers may reject a damaged input, but only with an error
+    #[test]
+    fn edited_inputs_never_panic(
+        day in 1u8..=25,
+        seed: u64,
+        edits in proptest::collection::vec(edit(), 1..6),
+    ) {
+        let _ = parser(day).unwrap()(&apply(&small_input(day, seed), &edits));
+    }
+
+    #[test]
+    fn any_input_never_panics(day in 1u8..=25, input in "\\PC*") {
+        let _ = parser(day).unwrap()(&input);
+    }
+}
+
+#[test]
+fn explorers_test() {
+    // Day 23 solves the burrow before its first command, which is slow in a
+    // debug build and covered by its own tests
+    for &(day, explore) in EXPLORERS.iter().filter(|(day, _)| *day != 23) {
+        let mut shell = explore(&small_input(day, 1)).unwrap();
+        let help = shell.exec("help").unwrap();
+        let commands = help
+            .lines()
+            .filter_map(|line| line.split_whitespace().next());
+        for command in commands.filter(|&c| c != "quit" && !c.starts_with("part")) {
+            assert!(shell.exec(command).is_ok(), "day {} {}", day, command);
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::days;
+
+const BASE_URL: &str = "https://adventofcode.com/2021";
+const USER_AGENT: &str = "github.com/jgoz/aoc-2021 input fetcher";
+
+// Minimum gap between requests, shared across runs through a stamp file
+const INTERVAL: Duration = Duration::from_secs(10);
+const STAMP: &str = "target/fetch/last-request";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Fetched {
+    Cached,
+    Downloaded(usize),
+}
+
+struct RateLimit {
+    stamp: PathBuf,
+    interval: Duration,
+}
+
+impl RateLimit {
+    fn now() -> u128 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_millis())
+    }
+
+    /// Sleeps until `interval` has passed since the last recorded request
+    fn wait(&self) {
+        let last = fs::read_to_string(&self.stamp)
+            .ok()
+            .and_then(|s| s.trim().parse::<u128>().ok());
+
+        if let Some(last) = last {
+            let elapsed = Self::now().saturating_sub(last);
+            let interval = self.interval.as_millis();
+            if elapsed < interval {
+                let remaining = Duration::from_millis((interval - elapsed) as u64);
+                eprintln!(
+                    "Waiting {:.1}s before the next request",
+                    remaining.as_secs_f64()
+                );
+                thread::sleep(remaining);
+            }
+        }
+    }
+
+    fn record(&self) -> Result<(), String> {
+        if let Some(dir) = self.stamp.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        fs::write(&self.stamp, Self::now().to_string())
+            .map_err(|e| format!("{}: {}", self.stamp.display(), e))
+    }
+}
+
+struct Fetcher {
+    base_url: String,
+    session: String,
+    limit: RateLimit,
+}
+
+impl Fetcher {
+    fn fetch(&self, day: u8, dest: &Path, force: bool) -> Result<Fetched, String> {
+        let cached = fs::metadata(dest).is_ok_and(|m| m.len() > 0);
+        if cached && !force {
+            return Ok(Fetched::Cached);
+        }
+
+        self.limit.wait();
+        let url = format!("{}/day/{}/input", self.base_url, day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        self.limit.record()?;
+
+        let body = match response {
+            Ok(response) => response.into_string().map_err(|e| e.to_string())?,
+            Err(ureq::Error::Status(code, _)) => {
+                return Err(format!(
+                    "{} returned {}, is the session token valid?",
+                    url, code
+                ))
+            }
+            Err(e) => return Err(format!("{}: {}", url, e)),
+        };
+
+        fs::write(dest, &body).map_err(|e| format!("{}: {}", dest.display(), e))?;
+        Ok(Fetched::Downloaded(body.len()))
+    }
+}
+
+/// The session cookie from `AOC_SESSION`, or from the config file `aocdl` used
+fn session() -> Result<String, String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let home = env::var("HOME").map_err(|_| "AOC_SESSION is not set")?;
+    let path = Path::new(&home).join(".config/aocdl/config.json");
+    let text = fs::read_to_string(&path)
+        .map_err(|_| format!("AOC_SESSION is not set and {} is missing", path.display()))?;
+    let config: serde_json::Value =
+        serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    config["session-cookie"]
+        .as_str()
+        .map(String::from)
+        .ok_or(format!("{} has no \"session-cookie\"", path.display()))
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let (day, force) = match args {
+        [day] => (day, false),
+        [day, flag] if flag == "--force" => (day, true),
+        _ => return Err(String::from(crate::USAGE)),
+    };
+    let day = day
+        .parse::<u8>()
+        .ok()
+        .filter(|&d| days::all().any(|x| x == d))
+        .ok_or(format!("Invalid day {}", day))?;
+
+    let fetcher = Fetcher {
+        base_url: String::from(BASE_URL),
+        session: session()?,
+        limit: RateLimit {
+            stamp: PathBuf::from(STAMP),
+            interval: INTERVAL,
+        },
+    };
+    let path = days::input_path(day);
+
+    match fetcher.fetch(day, Path::new(&path), force)? {
+        Fetched::Cached => println!("{} already exists, use --force to download it again", path),
+        Fetched::Downloaded(bytes) => println!("Wrote {} bytes to {}", bytes, path),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+        time::Instant,
+    };
+
+    // Answers one request with `status` and `body`, handing back the raw request
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buf = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buf).unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn fetcher(base_url: String, dir: &Path, interval: Duration) -> Fetcher {
+        Fetcher {
+            base_url,
+            session: String::from("abc123"),
+            limit: RateLimit {
+                stamp: dir.join("stamp"),
+                interval,
+            },
+        }
+    }
+
+    #[test]
+    fn fetch_test() {
+        let dir = scratch("fetch");
+        let (url, server) = serve_once("200 OK", "199\n200\n208\n");
+        let fetcher = fetcher(url, &dir, Duration::ZERO);
+        let dest = dir.join("input");
+
+        assert_eq!(Ok(Fetched::Downloaded(12)), fetcher.fetch(1, &dest, false));
+        assert_eq!("199\n200\n208\n", fs::read_to_string(&dest).unwrap());
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc123\r\n"));
+        assert!(request.contains(USER_AGENT));
+
+        // The server is gone, so this only works if nothing is requested
+        assert_eq!(Ok(Fetched::Cached), fetcher.fetch(1, &dest, false));
+    }
+
+    #[test]
+    fn fetch_error_test() {
+        let dir = scratch("error");
+        let (url, server) = serve_once("400 Bad Request", "Puzzle inputs differ by user.");
+        let fetcher = fetcher(url, &dir, Duration::ZERO);
+        let dest = dir.join("input");
+
+        let error = fetcher.fetch(2, &dest, false).unwrap_err();
+        assert!(error.ends_with("/day/2/input returned 400, is the session token valid?"));
+        assert!(!dest.exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn rate_limit_test() {
+        let dir = scratch("limit");
+        let limit = RateLimit {
+            stamp: dir.join("stamp"),
+            interval: Duration::from_millis(300),
+        };
+
+        let start = Instant::now();
+        limit.wait();
+        assert!(start.elapsed() < Duration::from_millis(300));
+
+        limit.record().unwrap();
+        limit.wait();
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+}
@@ -0,0 +1,147 @@
+use std::fs;
+
+use aoc_core::gen::{Params, Rng};
+
+use crate::days;
+
+#[derive(Debug)]
+struct Options {
+    day: u8,
+    seed: u64,
+    output: Option<String>,
+    params: Vec<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        day: 0,
+        seed: 0,
+        output: None,
+        params: vec![],
+    };
+    let mut day = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .cloned()
+                .ok_or(format!("{} needs a value", name))
+        };
+        match arg.as_str() {
+            "--output" => options.output = Some(value("--output")?),
+            "--seed" => {
+                let seed = value("--seed")?;
+                options.seed = seed.parse().map_err(|_| format!("Invalid seed {}", seed))?;
+            }
+            _ if arg.contains('=') => options.params.push(arg.clone()),
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u8>()
+                        .map_err(|_| format!("Invalid argument {}", arg))?,
+                )
+            }
+            _ => return Err(String::from(crate::USAGE)),
+        }
+    }
+
+    options.day = day.ok_or(String::from(crate::USAGE))?;
+    Ok(options)
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let options = parse_args(args)?;
+    let input = generate(options.day, options.seed, &options.params)?;
+
+    match &options.output {
+        Some(path) => {
+            fs::write(path, &input).map_err(|e| format!("{}: {}", path, e))?;
+            println!("Wrote {} lines to {}", input.lines().count(), path);
+        }
+        None => print!("{}", input),
+    }
+    Ok(())
+}
+
+/// Writes the day's input, failing on parameters its generator doesn't use or
+/// input the day can't parse
+pub fn generate(day: u8, seed: u64, params: &[String]) -> Result<String, String> {
+    let generate = days::generator(day).ok_or(format!("No generator for day {}", day))?;
+    let params = Params::parse(params)?;
+    let input = generate(&mut Rng::new(seed), &params);
+
+    let unused = params.unused();
+    if !unused.is_empty() {
+        let takes = match params.asked() {
+            asked if asked.is_empty() => String::from("it takes none"),
+            asked => format!("it takes {}", asked.join(", ")),
+        };
+        return Err(format!(
+            "Day {} has no parameter {} ({})",
+            day,
+            unused.join(", "),
+            takes
+        ));
+    }
+
+    // Anything written should be accepted, so a generator bug shows up here
+    // rather than as a confusing error from the solver later
+    if let Some(parse) = days::parser(day) {
+        parse(&input).map_err(|e| {
+            format!(
+                "Day {} generated input that doesn't parse: {}",
+                day,
+                e.report(&input)
+            )
+        })?;
+    }
+    Ok(input)
+}
+
+#[test]
+fn parse_args_test() {
+    let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+    let options = parse_args(&args("4")).unwrap();
+    assert_eq!(4, options.day);
+    assert_eq!(0, options.seed);
+    assert!(options.params.is_empty());
+
+    let options = parse_args(&args("5 lines=10 --seed 7 size=20 --output in.txt")).unwrap();
+    assert_eq!(7, options.seed);
+    assert_eq!(vec!["lines=10", "size=20"], options.params);
+    assert_eq!(Some(String::from("in.txt")), options.output);
+
+    assert!(parse_args(&args("5 --seed x")).is_err());
+    assert!(parse_args(&args("5 6")).is_err());
+    assert!(parse_args(&args("lines=10")).is_err());
+}
+
+#[test]
+fn generate_test() {
+    let args = |s: &str| parse_args(&s.split_whitespace().map(String::from).collect::<Vec<_>>());
+    let generate = |options: &Options| generate(options.day, options.seed, &options.params);
+
+    let input = generate(&args("4 boards=3 --seed 9").unwrap()).unwrap();
+    assert_eq!(
+        input,
+        generate(&args("4 boards=3 --seed 9").unwrap()).unwrap()
+    );
+    assert_ne!(
+        input,
+        generate(&args("4 boards=3 --seed 10").unwrap()).unwrap()
+    );
+    assert_eq!(1 + 3 * 6, input.lines().count());
+
+    assert_eq!(
+        Err(String::from(
+            "Day 4 has no parameter size (it takes boards, numbers)"
+        )),
+        generate(&args("4 size=3").unwrap())
+    );
+    assert_eq!(
+        Err(String::from("Day 21 has no parameter x (it takes none)")),
+        generate(&args("21 x=1").unwrap())
+    );
+    assert!(generate(&args("26").unwrap()).is_err());
+}
@@ -0,0 +1,65 @@
+use std::{env, process};
+
+mod anim;
+mod answers;
+mod bench;
+mod compare;
+mod days;
+mod fetch;
+mod gen;
+mod new;
+mod repl;
+mod run;
+mod verify;
+
+const USAGE: &str = "\
+Usage: aoc <command> [args]
+
+Commands:
+    run <day> [part] [--input <path>]   Solve one day (both parts if no part is given)
+          [--export <path>]             Save a picture of the part (days 5, 9, 13 and 20)
+          [--scale <n>] [--palette <c>] as .ppm, .pgm or .svg, e.g. --palette 000000,ffffff
+          [--format json]               Print one JSON report per part, with timing and diagnostics
+          [--stats]                     Follow each answer with its diagnostics
+    run --all                           Solve every day
+    verify [day]                        Check answers against answers.txt
+    bench [day] [part] [--runs <n>]     Time each part (all days if no day is given)
+          [--baseline <path>]           Compare with a saved run (default target/bench/baseline.json)
+          [--save-baseline]             Save this run as the baseline
+    fetch <day> [--force]               Download a day's input (session from AOC_SESSION)
+    new <day> [--example <path>]        Create a day crate and register it with the runner
+          [--part1 <answer>] [--part2 <answer>]
+    anim <day> [--input <path>]         Watch a day's simulation (days 11, 13, 20, 23 and 25)
+          [--fps <n>] [--limit <n>]     Frame rate (default 10) and most frames to show
+          [--frames <dir>]              Save frames as text files instead of drawing them
+    gen <day> [name=value ...]          Write a random input sized by the parameters, e.g.
+          [--seed <n>]                  gen 4 boards=1000 (the same seed gives the same input)
+          [--output <path>]             Write to a file instead of stdout. Day 24 always has 14
+                                        blocks; instructions=<n> pads each with lines that do nothing
+    compare <day> [name=value ...]      Check that every way of solving a part agrees on
+          [--seeds <n>]                 generated inputs (days 3 and 22; default 100 seeds)
+    repl <day> [--input <path>]         Load an input and explore it with commands such as
+                                        part1, step, show or fold 3 (days 11, 12, 13, 20, 23
+                                        and 25; help lists them all)";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run::main(&args[1..]),
+        Some("verify") => verify::main(&args[1..]),
+        Some("bench") => bench::main(&args[1..]),
+        Some("fetch") => fetch::main(&args[1..]),
+        Some("new") => new::main(&args[1..]),
+        Some("anim") => anim::main(&args[1..]),
+        Some("gen") => gen::main(&args[1..]),
+        Some("compare") => compare::main(&args[1..]),
+        Some("repl") => repl::main(&args[1..]),
+        _ => Err(String::from(USAGE)),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,235 @@
+use std::{fs, path::Path};
+
+use toml_edit::{value, Array, DocumentMut, InlineTable, Value};
+
+use crate::days;
+
+struct Options {
+    day: u8,
+    example: Option<String>,
+    answers: [Option<String>; 2],
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut day = None;
+    let mut example = None;
+    let mut answers = [None, None];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut next = || args.next().cloned().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--example" => {
+                let path = next()?;
+                example = Some(fs::read_to_string(&path).map_err(|e| format!("{}: {}", path, e))?);
+            }
+            "--part1" => answers[0] = Some(next()?),
+            "--part2" => answers[1] = Some(next()?),
+            _ if day.is_none() => day = arg.parse::<u8>().ok().filter(|&d| d > 0),
+            _ => return Err(format!("Invalid argument {}", arg)),
+        }
+    }
+
+    let day = day.ok_or(crate::USAGE)?;
+    Ok(Options {
+        day,
+        example,
+        answers,
+    })
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let options = parse_args(args)?;
+    if days::solver(options.day).is_some() {
+        return Err(format!("Day {} already exists", options.day));
+    }
+
+    scaffold(Path::new("."), &options)?;
+    println!("Created day{:02}", options.day);
+    Ok(())
+}
+
+fn scaffold(root: &Path, options: &Options) -> Result<(), String> {
+    let name = format!("day{:02}", options.day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let example = example_vec(options.example.as_deref());
+    let [answer1, answer2] = &options.answers;
+    let fill = |text: String| {
+        text.replace("DayNN", &format!("Day{:02}", options.day))
+            .replace("dayDD", &name)
+            .replace("dayD", &format!("day{}", options.day))
+            .replace("DAY_NUMBER", &options.day.to_string())
+            .replace("EXAMPLE", &example)
+            .replace("ANSWER1", &answer_literal(answer1.as_deref()))
+            .replace("ANSWER2", &answer_literal(answer2.as_deref()))
+    };
+
+    fs::create_dir_all(dir.join("src")).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    for file in ["Cargo.toml", "src/main.rs", "src/lib.rs"] {
+        let template = read(&root.join(".template").join(file))?;
+        write(&dir.join(file), &fill(template))?;
+    }
+
+    add_member(&root.join("Cargo.toml"), &name)?;
+    add_dependency(&root.join("aoc/Cargo.toml"), &name)?;
+    register(&root.join("aoc/src/days.rs"), options.day)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    fs::write(path, text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn edit_toml(path: &Path, edit: impl FnOnce(&mut DocumentMut) -> Option<()>) -> Result<(), String> {
+    let mut doc = read(path)?
+        .parse::<DocumentMut>()
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    edit(&mut doc).ok_or(format!("{}: unexpected layout", path.display()))?;
+    write(path, &doc.to_string())
+}
+
+// Keeps the one-member-per-line layout of the workspace manifest
+fn add_member(path: &Path, name: &str) -> Result<(), String> {
+    edit_toml(path, |doc| {
+        let members: &mut Array = doc
+            .get_mut("workspace")?
+            .get_mut("members")?
+            .as_array_mut()?;
+        if members.iter().any(|m| m.as_str() == Some(name)) {
+            return Some(());
+        }
+        members.push_formatted(Value::from(name).decorated("\n    ", ""));
+        members.set_trailing_comma(true);
+        members.set_trailing("\n");
+        Some(())
+    })
+}
+
+fn add_dependency(path: &Path, name: &str) -> Result<(), String> {
+    edit_toml(path, |doc| {
+        let deps = doc.get_mut("dependencies")?.as_table_mut()?;
+        let mut dep = InlineTable::new();
+        dep.insert("path", format!("../{}", name).into());
+        deps.insert(name, value(dep));
+        Some(())
+    })
+}
+
+// Adds the day to the end of the `days!` list the runner's tables are built from
+fn register(path: &Path, day: u8) -> Result<(), String> {
+    let text = read(path)?;
+    let entry = format!("    {} => day{:02}::Day{:02},\n", day, day, day);
+    if text.contains(&entry) {
+        return Ok(());
+    }
+
+    let start = text
+        .find("\ndays! {\n")
+        .ok_or(format!("{}: no days! list", path.display()))?;
+    let end = start
+        + 1
+        + text[start + 1..]
+            .find("\n}")
+            .ok_or(format!("{}: days! list isn't closed", path.display()))?
+        + 1;
+    write(path, &format!("{}{}{}", &text[..end], entry, &text[end..]))
+}
+
+// The example input as the `vec![...]` of lines the day tests parse
+fn example_vec(example: Option<&str>) -> String {
+    let lines = example.map_or(vec![""], |e| e.lines().collect());
+    let strings = lines
+        .iter()
+        .map(|line| format!("        String::from({:?}),\n", line))
+        .collect::<String>();
+    format!("vec![\n{}    ]", strings)
+}
+
+// Integers too big for the default i32 literal get a suffix
+fn answer_literal(answer: Option<&str>) -> String {
+    let answer = answer.unwrap_or("0");
+    if answer.parse::<i32>().is_ok() {
+        answer.to_string()
+    } else if answer.parse::<i64>().is_ok() {
+        format!("{}_i64", answer)
+    } else if answer.parse::<u64>().is_ok() {
+        format!("{}_u64", answer)
+    } else {
+        format!("{:?}", answer)
+    }
+}
+
+#[test]
+fn answer_literal_test() {
+    assert_eq!("0", answer_literal(None));
+    assert_eq!("-7", answer_literal(Some("-7")));
+    assert_eq!("26984457539_i64", answer_literal(Some("26984457539")));
+    assert_eq!(
+        "18446744073709551615_u64",
+        answer_literal(Some("18446744073709551615"))
+    );
+    assert_eq!("\"RZKZLPGH\"", answer_literal(Some("RZKZLPGH")));
+}
+
+#[test]
+fn scaffold_test() {
+    let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    fs::create_dir_all(root.join("aoc/src")).unwrap();
+
+    // A copy of the real template and a cut-down workspace around it
+    let template = Path::new(env!("CARGO_MANIFEST_DIR")).join("../.template");
+    fs::create_dir_all(root.join(".template/src")).unwrap();
+    for file in ["Cargo.toml", "src/main.rs", "src/lib.rs"] {
+        fs::copy(template.join(file), root.join(".template").join(file)).unwrap();
+    }
+    let workspace = "[workspace]\n\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n";
+    write(&root.join("Cargo.toml"), workspace).unwrap();
+    let manifest = "[package]\nname = \"aoc\"\n\n[dependencies]\nday01 = { path = \"../day01\" }\n";
+    write(&root.join("aoc/Cargo.toml"), manifest).unwrap();
+    let registry = "macro_rules! days {}\n\ndays! {\n    1 => day01::Day01,\n}\n\nfn solver() {}\n";
+    write(&root.join("aoc/src/days.rs"), registry).unwrap();
+
+    let options = Options {
+        day: 26,
+        example: Some(String::from("1,2\n3,4\n")),
+        answers: [Some(String::from("10")), None],
+    };
+    scaffold(&root, &options).unwrap();
+
+    assert_eq!(
+        "[workspace]\n\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day26\",\n]\n",
+        read(&root.join("Cargo.toml")).unwrap()
+    );
+    assert!(read(&root.join("aoc/Cargo.toml"))
+        .unwrap()
+        .ends_with("day01 = { path = \"../day01\" }\nday26 = { path = \"../day26\" }\n"));
+    let registry = read(&root.join("aoc/src/days.rs")).unwrap();
+    assert_eq!(
+        "macro_rules! days {}\n\ndays! {\n    1 => day01::Day01,\n    26 => day26::Day26,\n}\n\nfn solver() {}\n",
+        registry
+    );
+
+    assert!(read(&root.join("day26/Cargo.toml"))
+        .unwrap()
+        .contains("name = \"day26\""));
+    assert!(read(&root.join("day26/src/main.rs"))
+        .unwrap()
+        .contains("day26::Day26"));
+    let lib = read(&root.join("day26/src/lib.rs")).unwrap();
+    assert!(lib.contains("fn day26_part1_test()"));
+    assert!(lib.contains("const DAY: u8 = 26;"));
+    assert!(lib.contains("String::from(\"1,2\"),\n        String::from(\"3,4\"),\n    ];"));
+    assert!(lib.contains("assert_eq!(10, answer);"));
+    assert!(lib.contains("assert_eq!(0, answer);"));
+
+    assert!(scaffold(&root, &options).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
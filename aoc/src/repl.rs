@@ -0,0 +1,123 @@
+use std::{
+    io::{self, prelude::*},
+    path::Path,
+};
+
+use aoc_core::{input, repl::Shell};
+
+use crate::days;
+
+#[derive(Debug)]
+struct Options {
+    day: u8,
+    input: Option<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut input = None;
+    let mut day = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = Some(args.next().cloned().ok_or("--input needs a path")?),
+            _ if day.is_none() => {
+                day = Some(
+                    arg.parse::<u8>()
+                        .map_err(|_| format!("Invalid argument {}", arg))?,
+                )
+            }
+            _ => return Err(String::from(crate::USAGE)),
+        }
+    }
+
+    Ok(Options {
+        day: day.ok_or(String::from(crate::USAGE))?,
+        input,
+    })
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let options = parse_args(args)?;
+
+    let explore = days::explorer(options.day).ok_or(format!("No REPL for day {}", options.day))?;
+    let path = options
+        .input
+        .clone()
+        .unwrap_or_else(|| days::input_path(options.day));
+    let input = input::read(Path::new(&path))?;
+    let mut shell = explore(&input).map_err(|e| format!("{}: {}", path, e.report(&input)))?;
+
+    let prompt = format!("day{:02}> ", options.day);
+    session(
+        shell.as_mut(),
+        &prompt,
+        io::stdin().lock(),
+        &mut io::stdout(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Runs each line read from `lines` until `quit` or the end of input
+fn session(
+    shell: &mut dyn Shell,
+    prompt: &str,
+    lines: impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let mut lines = lines.lines();
+    loop {
+        write!(out, "{}", prompt)?;
+        out.flush()?;
+        let Some(line) = lines.next().transpose()? else {
+            writeln!(out)?;
+            return Ok(());
+        };
+
+        match line.trim() {
+            "quit" | "exit" => return Ok(()),
+            line => match shell.exec(line) {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => writeln!(out, "{}", output.trim_end_matches('\n'))?,
+                Err(message) => writeln!(out, "error: {}", message)?,
+            },
+        }
+    }
+}
+
+#[test]
+fn parse_args_test() {
+    let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+    let options = parse_args(&args("13")).unwrap();
+    assert_eq!(13, options.day);
+    assert_eq!(None, options.input);
+
+    let options = parse_args(&args("20 --input small.txt")).unwrap();
+    assert_eq!(Some(String::from("small.txt")), options.input);
+
+    assert!(parse_args(&args("13 14")).is_err());
+    assert!(parse_args(&args("13 --input")).is_err());
+    assert!(parse_args(&args("--input a.txt")).is_err());
+}
+
+#[test]
+fn session_test() {
+    let input = "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0\n\nfold along y=7\nfold along x=5";
+    let mut shell = (days::explorer(13).unwrap())(input).unwrap();
+
+    let mut out = vec![];
+    let commands = "fold\ndots\n\nfold 3\nwhat\nquit\ndots\n";
+    session(shell.as_mut(), "> ", commands.as_bytes(), &mut out).unwrap();
+    assert_eq!(
+        concat!(
+            "> █ ██  █  █ \n█   █      \n      █   █\n█   █      \n █ █  █ ███\n",
+            "> 17 dots after 1 of 2 folds\n",
+            "> ",
+            "> Stopped after 1 of 3 steps\n█████\n█   █\n█   █\n█   █\n█████\n",
+            "> error: Unknown command what (try help)\n",
+            "> "
+        ),
+        String::from_utf8(out).unwrap()
+    );
+}
@@ -0,0 +1,212 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_core::{
+    export::{self, Export},
+    input, Output,
+};
+
+use crate::days;
+
+struct Options {
+    days: Vec<u8>,
+    parts: Vec<u8>,
+    input: Option<String>,
+    format: Output,
+    stats: bool,
+    export: Option<(PathBuf, Export)>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut all = false;
+    let mut input = None;
+    let mut format = Output::Text;
+    let mut stats = false;
+    let mut export_args = vec![];
+    let mut positional = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input" => match args.next() {
+                Some(path) => input = Some(path.clone()),
+                None => return Err(String::from("--input needs a path")),
+            },
+            "--format" => {
+                format = Output::parse(args.next().ok_or("--format needs a value")?)?;
+            }
+            "--stats" => stats = true,
+            "--export" | "--scale" | "--palette" => {
+                export_args.push(arg.clone());
+                export_args.extend(args.next().cloned());
+            }
+            _ => positional.push(
+                arg.parse::<u8>()
+                    .map_err(|_| format!("Invalid argument {}", arg))?,
+            ),
+        }
+    }
+
+    let (days, parts) = match (all, positional.as_slice()) {
+        (true, []) => (days::all().collect(), vec![1, 2]),
+        (false, [day]) => (vec![*day], vec![1, 2]),
+        (false, [day, part]) => (vec![*day], vec![*part]),
+        (true, _) => return Err(String::from("--all doesn't take a day or part")),
+        (false, _) => return Err(String::from(crate::USAGE)),
+    };
+
+    if all && input.is_some() {
+        return Err(String::from("--input can only be used with a single day"));
+    }
+    let export = match export::parse_args(&export_args)? {
+        (Some(path), _) if parts.len() > 1 => {
+            return Err(format!("{}: --export needs a day and part", path.display()))
+        }
+        (Some(path), export) => Some((path, export)),
+        (None, _) => None,
+    };
+    if let Some(part) = parts.iter().find(|&&p| p != 1 && p != 2) {
+        return Err(format!("Invalid part {}", part));
+    }
+
+    Ok(Options {
+        days,
+        parts,
+        input,
+        format,
+        stats,
+        export,
+    })
+}
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let options = parse_args(args)?;
+
+    if options.format == Output::Text {
+        println!(
+            "{:>3}  {:>4}  {:<20}  {:>10}",
+            "Day", "Part", "Answer", "Time"
+        );
+    }
+    for &day in &options.days {
+        let solve = days::solver(day).ok_or(format!("No solver for day {}", day))?;
+        let path = options
+            .input
+            .clone()
+            .unwrap_or_else(|| days::input_path(day));
+        let input = input::read(Path::new(&path))?;
+
+        for &part in &options.parts {
+            if options.format == Output::Json || options.stats {
+                let report = days::reporter(day).ok_or(format!("No solver for day {}", day))?;
+                let mut report = report(part, &input)
+                    .map_err(|e| format!("{}: {}", path, e.report(&input)))?
+                    .ok_or(format!("Invalid part {}", part))?;
+                report.input = Some(path.clone());
+                match options.format {
+                    Output::Json => println!("{}", report.to_json()),
+                    Output::Text => {
+                        let elapsed = Duration::from_nanos(report.elapsed_ns);
+                        print_row(day, part, &report.answer, elapsed);
+                        print_stats(&report.stats());
+                    }
+                }
+                continue;
+            }
+
+            let start = Instant::now();
+            let answer = solve(part, &input)
+                .map_err(|e| format!("{}: {}", path, e.report(&input)))?
+                .unwrap_or_default();
+            print_row(day, part, &answer, start.elapsed());
+        }
+
+        if let Some((export_path, export)) = &options.export {
+            let paint = days::painter(day).ok_or(format!("No picture for day {}", day))?;
+            let grid = paint(options.parts[0], &input)
+                .map_err(|e| format!("{}: {}", path, e.report(&input)))?
+                .ok_or(format!("No picture for day {}", day))?;
+            export.save(&grid, export_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Multi-line answers (e.g. day 13's letters) continue under the answer column
+fn print_row(day: u8, part: u8, answer: &str, elapsed: Duration) {
+    let mut lines = answer.lines();
+    let first = lines.next().unwrap_or_default();
+    println!(
+        "{:>3}  {:>4}  {:<20}  {:>10}",
+        day,
+        part,
+        first,
+        format_duration(elapsed)
+    );
+    for line in lines {
+        println!("{:>3}  {:>4}  {}", "", "", line);
+    }
+}
+
+// Stats go under the answer column, like the rest of a multi-line answer
+fn print_stats(stats: &str) {
+    for line in stats.lines() {
+        println!("{:>3}  {:>4}  {}", "", "", line);
+    }
+}
+
+pub(crate) fn format_duration(d: Duration) -> String {
+    let micros = d.as_micros();
+    if micros < 1_000 {
+        format!("{}µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", d.as_secs_f64())
+    }
+}
+
+#[test]
+fn parse_args_test() {
+    let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+    let options = parse_args(&args("5 2")).unwrap();
+    assert_eq!(vec![5], options.days);
+    assert_eq!(vec![2], options.parts);
+
+    let options = parse_args(&args("5 --input foo.txt")).unwrap();
+    assert_eq!(vec![1, 2], options.parts);
+    assert_eq!(Some(String::from("foo.txt")), options.input);
+    assert_eq!(Output::Text, options.format);
+
+    let options = parse_args(&args("--all --format json")).unwrap();
+    assert_eq!(Output::Json, options.format);
+    assert!(parse_args(&args("5 --format csv")).is_err());
+    assert!(!options.stats);
+    assert!(parse_args(&args("15 --stats")).unwrap().stats);
+
+    let options = parse_args(&args("--all")).unwrap();
+    assert_eq!(25, options.days.len());
+
+    assert!(parse_args(&args("5 3")).is_err());
+    assert!(parse_args(&args("--all 5")).is_err());
+    assert!(parse_args(&args("--all --input foo.txt")).is_err());
+
+    let options = parse_args(&args("13 2 --export out.svg --scale 8")).unwrap();
+    let (path, export) = options.export.unwrap();
+    assert_eq!(PathBuf::from("out.svg"), path);
+    assert_eq!(8, export.scale);
+    assert!(parse_args(&args("13 --export out.svg")).is_err());
+    assert!(parse_args(&args("--all --export out.svg")).is_err());
+}
+
+#[test]
+fn format_duration_test() {
+    assert_eq!("12µs", format_duration(Duration::from_micros(12)));
+    assert_eq!("1.50ms", format_duration(Duration::from_micros(1_500)));
+    assert_eq!("2.00s", format_duration(Duration::from_secs(2)));
+}
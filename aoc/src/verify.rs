@@ -0,0 +1,61 @@
+use std::path::Path;
+
+use aoc_core::input;
+
+use crate::{answers, days};
+
+pub fn main(args: &[String]) -> Result<(), String> {
+    let answers = answers::load()?;
+    let only = match args {
+        [] => None,
+        [day] => Some(
+            day.parse::<u8>()
+                .map_err(|_| format!("Invalid day {}", day))?,
+        ),
+        _ => return Err(String::from(crate::USAGE)),
+    };
+
+    let mut checked = 0;
+    let mut failures = 0;
+    for day in days::all().filter(|&d| only.is_none_or(|o| o == d)) {
+        let expected: Vec<_> = answers.range((day, 0)..=(day, u8::MAX)).collect();
+        if expected.is_empty() {
+            println!("Day {:>2}: no recorded answers", day);
+            continue;
+        }
+
+        let solve = days::solver(day).ok_or(format!("No solver for day {}", day))?;
+        let path = days::input_path(day);
+        let input = input::read(Path::new(&path))?;
+
+        for (&(_, part), want) in expected {
+            checked += 1;
+            match solve(part, &input) {
+                Ok(Some(got)) if &got == want => {}
+                Ok(got) => {
+                    failures += 1;
+                    println!("Day {:>2} part {}: expected", day, part);
+                    println!("{}", want);
+                    println!("but got");
+                    println!("{}", got.unwrap_or(format!("(no part {})", part)));
+                }
+                Err(e) => {
+                    failures += 1;
+                    println!(
+                        "Day {:>2} part {}: {}: {}",
+                        day,
+                        part,
+                        path,
+                        e.report(&input)
+                    );
+                }
+            }
+        }
+    }
+
+    println!("{}/{} answers match", checked - failures, checked);
+    if failures > 0 {
+        return Err(format!("{} answer(s) changed", failures));
+    }
+    Ok(())
+}
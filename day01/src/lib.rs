@@ -0,0 +1,386 @@
+//! Day 1: Sonar Sweep. Counts how often the sea floor depth increases, one
+//! reading at a time and over a sliding window of three. [`Windows`] tracks
+//! windows of any size as the readings stream in, and [`profile`] annotates
+//! each reading with the depths so far and anything that stands out.
+
+use std::{
+    cmp::Ordering,
+    io::{self, Write},
+};
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// Counts the increases between readings, then between windows of three
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    const DAY: u8 = 1;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<i32>, ParseError> {
+        v.enumerate().map(|(i, x)| depth(i + 1, &x)).collect()
+    }
+
+    fn part1(depths: &Vec<i32>) -> Result<Answer, String> {
+        Ok(increases(depths, 1).into())
+    }
+
+    fn part2(depths: &Vec<i32>) -> Result<Answer, String> {
+        Ok(increases(depths, 3).into())
+    }
+}
+
+fn increases(depths: &[i32], size: usize) -> usize {
+    sweep(depths.iter().copied(), size)
+        .map(|sweep| sweep.increases)
+        .expect("the parts' window sizes are positive")
+}
+
+/// Parses the reading on line number `line`
+pub fn depth(line: usize, text: &str) -> Result<i32, ParseError> {
+    let mut c = Cursor::new(line, text);
+    let depth = c.number()?;
+    c.end()?;
+    Ok(depth)
+}
+
+/// How the sum of a sliding window changed each time it moved
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Sweep {
+    /// How many readings the window sums
+    pub size: usize,
+    /// Moves that made the sum bigger
+    pub increases: usize,
+    /// Moves that made the sum smaller
+    pub decreases: usize,
+    /// Moves that left the sum as it was
+    pub unchanged: usize,
+    /// The most increases in a row
+    pub longest_run: usize,
+}
+
+/// Sliding windows of several sizes over one stream of readings. Two windows
+/// of the same size overlap in all but their first and last readings, so a
+/// window's sum changes as the newest reading compares with the one `size`
+/// readings back, and only the last few readings need keeping.
+pub struct Windows {
+    /// The last readings, as a ring buffer that grows with each reading until
+    /// it's as long as the largest window, so a huge window costs nothing
+    /// until that many readings have come in
+    recent: Vec<i32>,
+    longest: usize,
+    /// Where the next reading goes in `recent`
+    next: usize,
+    seen: usize,
+    sweeps: Vec<Sweep>,
+    runs: Vec<usize>,
+}
+
+impl Windows {
+    /// Windows of each size in `sizes`, which fails if there are none or one
+    /// of them is 0
+    pub fn new(sizes: &[usize]) -> Result<Windows, String> {
+        if let Some(size) = sizes.iter().find(|&&size| size == 0) {
+            return Err(format!("Invalid window size {}", size));
+        }
+        let longest = *sizes.iter().max().ok_or("No window sizes")?;
+        Ok(Windows {
+            recent: vec![],
+            longest,
+            next: 0,
+            seen: 0,
+            sweeps: sizes
+                .iter()
+                .map(|&size| Sweep {
+                    size,
+                    ..Sweep::default()
+                })
+                .collect(),
+            runs: vec![0; sizes.len()],
+        })
+    }
+
+    /// Moves every window on by one reading
+    pub fn push(&mut self, depth: i32) {
+        let len = self.recent.len();
+        for (sweep, run) in self.sweeps.iter_mut().zip(&mut self.runs) {
+            if self.seen < sweep.size {
+                continue;
+            }
+            match depth.cmp(&self.recent[(self.next + len - sweep.size) % len]) {
+                Ordering::Greater => {
+                    sweep.increases += 1;
+                    *run += 1;
+                    sweep.longest_run = sweep.longest_run.max(*run);
+                }
+                Ordering::Less => {
+                    sweep.decreases += 1;
+                    *run = 0;
+                }
+                Ordering::Equal => {
+                    sweep.unchanged += 1;
+                    *run = 0;
+                }
+            }
+        }
+        if len < self.longest {
+            self.recent.push(depth);
+        } else {
+            self.recent[self.next] = depth;
+        }
+        self.next = (self.next + 1) % self.longest;
+        self.seen += 1;
+    }
+
+    /// Each window's counts so far, in the order their sizes were given
+    pub fn sweeps(&self) -> &[Sweep] {
+        &self.sweeps
+    }
+}
+
+/// Slides a window of `size` readings over `depths`, which fails if `size` is 0
+pub fn sweep(depths: impl IntoIterator<Item = i32>, size: usize) -> Result<Sweep, String> {
+    let mut windows = Windows::new(&[size])?;
+    depths.into_iter().for_each(|depth| windows.push(depth));
+    Ok(windows.sweeps[0].clone())
+}
+
+/// Lines up each window's counts under a header
+pub fn table(sweeps: &[Sweep]) -> String {
+    let mut out = String::from("window  increases  decreases  unchanged  longest run\n");
+    for sweep in sweeps {
+        out += &format!(
+            "{:>6}  {:>9}  {:>9}  {:>9}  {:>11}\n",
+            sweep.size, sweep.increases, sweep.decreases, sweep.unchanged, sweep.longest_run
+        );
+    }
+    out
+}
+
+/// What stands out about a reading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    /// The same depth as the reading before
+    Plateau,
+    /// The floor fell away by more than the threshold since the reading before
+    Drop,
+}
+
+impl Feature {
+    /// How the feature is written in CSV
+    pub fn name(self) -> &'static str {
+        match self {
+            Feature::Plateau => "plateau",
+            Feature::Drop => "drop",
+        }
+    }
+}
+
+/// A reading, and what the depths looked like up to and including it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    /// The reading itself
+    pub depth: i32,
+    /// How much deeper this is than the reading before, if there was one
+    pub change: Option<i64>,
+    /// The shallowest reading so far
+    pub min: i32,
+    /// The deepest reading so far
+    pub max: i32,
+    /// The average of the readings so far
+    pub mean: f64,
+    /// Whether this reading stands out, and how
+    pub feature: Option<Feature>,
+}
+
+/// Annotates each reading as it comes in, marking those deeper than the one
+/// before by more than `threshold` as drops
+pub fn profile(depths: impl Iterator<Item = i32>, threshold: i64) -> impl Iterator<Item = Sample> {
+    let (mut min, mut max) = (i32::MAX, i32::MIN);
+    let (mut sum, mut count) = (0i64, 0);
+    let mut prev = None;
+    depths.map(move |depth| {
+        min = min.min(depth);
+        max = max.max(depth);
+        sum += depth as i64;
+        count += 1;
+        let change = prev.map(|prev| i64::from(depth) - i64::from(prev));
+        prev = Some(depth);
+
+        let feature = match change {
+            Some(0) => Some(Feature::Plateau),
+            Some(change) if change > threshold => Some(Feature::Drop),
+            _ => None,
+        };
+        Sample {
+            depth,
+            change,
+            min,
+            max,
+            mean: sum as f64 / count as f64,
+            feature,
+        }
+    })
+}
+
+/// Writes one CSV row per sample, numbering the readings from 1
+pub fn csv(samples: impl Iterator<Item = Sample>, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "reading,depth,change,min,max,mean,feature")?;
+    for (i, sample) in samples.enumerate() {
+        writeln!(
+            out,
+            "{},{},{},{},{},{:.2},{}",
+            i + 1,
+            sample.depth,
+            sample.change.map(|c| c.to_string()).unwrap_or_default(),
+            sample.min,
+            sample.max,
+            sample.mean,
+            sample.feature.map(Feature::name).unwrap_or_default()
+        )?;
+    }
+    Ok(())
+}
+
+#[test]
+fn part1_test() {
+    let v = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(Ok(7), sweep(v, 1).map(|s| s.increases));
+}
+
+#[test]
+fn part2_test() {
+    let v = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+    assert_eq!(Ok(5), sweep(v, 3).map(|s| s.increases));
+}
+
+#[test]
+fn windows_test() {
+    let mut windows = Windows::new(&[1, 3, 10, 11]).unwrap();
+    for depth in [199, 200, 208, 210, 200, 207, 240, 269, 260, 263] {
+        windows.push(depth);
+    }
+    let counts = |size, increases, decreases, unchanged, longest_run| Sweep {
+        size,
+        increases,
+        decreases,
+        unchanged,
+        longest_run,
+    };
+    assert_eq!(
+        &[
+            counts(1, 7, 2, 0, 3),
+            // 607 618 618 617 647 716 769 792
+            counts(3, 5, 1, 1, 4),
+            counts(10, 0, 0, 0, 0),
+            counts(11, 0, 0, 0, 0),
+        ],
+        windows.sweeps()
+    );
+
+    assert_eq!(Ok(counts(2, 0, 0, 2, 0)), sweep([5, 5, 5, 5], 2));
+    assert!(sweep([5], 0).is_err());
+    assert!(Windows::new(&[]).is_err());
+    assert!(Windows::new(&[3, 0]).is_err());
+
+    // Only as many readings as have come in are kept
+    let mut windows = Windows::new(&[2, usize::MAX]).unwrap();
+    for depth in [3, 1, 4, 1, 5] {
+        windows.push(depth);
+    }
+    assert_eq!(5, windows.recent.len());
+    assert_eq!(
+        &[counts(2, 2, 0, 1, 1), counts(usize::MAX, 0, 0, 0, 0)],
+        windows.sweeps()
+    );
+}
+
+#[test]
+fn table_test() {
+    let sweeps = [
+        sweep([1, 2, 3, 2], 1).unwrap(),
+        sweep([1, 2, 3, 2], 2).unwrap(),
+    ];
+    assert_eq!(
+        concat!(
+            "window  increases  decreases  unchanged  longest run\n",
+            "     1          2          1          0            2\n",
+            "     2          1          0          1            1\n",
+        ),
+        table(&sweeps)
+    );
+}
+
+#[test]
+fn profile_test() {
+    let samples = profile([200, 190, 190, 240, 250].into_iter(), 20).collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            None,
+            None,
+            Some(Feature::Plateau),
+            Some(Feature::Drop),
+            None
+        ],
+        samples.iter().map(|s| s.feature).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Sample {
+            depth: 240,
+            change: Some(50),
+            min: 190,
+            max: 240,
+            mean: 205.0,
+            feature: Some(Feature::Drop),
+        },
+        samples[3]
+    );
+    assert_eq!(0, profile(std::iter::empty(), 20).count());
+
+    // Changes between the extremes don't fit an i32
+    let samples = profile([i32::MAX, i32::MIN].into_iter(), 20).collect::<Vec<_>>();
+    assert_eq!(Some(-(1 << 32) + 1), samples[1].change);
+    assert_eq!(None, samples[1].feature);
+    let samples = profile([i32::MIN, i32::MAX].into_iter(), 20).collect::<Vec<_>>();
+    assert_eq!(Some((1 << 32) - 1), samples[1].change);
+    assert_eq!(Some(Feature::Drop), samples[1].feature);
+}
+
+#[test]
+fn csv_test() {
+    let mut out = vec![];
+    csv(profile([3, 3, 10].into_iter(), 5), &mut out).unwrap();
+    assert_eq!(
+        concat!(
+            "reading,depth,change,min,max,mean,feature\n",
+            "1,3,,3,3,3.00,\n",
+            "2,3,0,3,3,3.00,plateau\n",
+            "3,10,7,3,10,5.33,drop\n",
+        ),
+        String::from_utf8(out).unwrap()
+    );
+}
+
+/// Depths that mostly go down, with the odd step back up
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let depths = params.get("depths", 2000);
+    let mut depth = rng.range(100, 200);
+    let mut out = String::new();
+    for _ in 0..depths {
+        out += &format!("{}\n", depth);
+        depth = (depth + rng.range(-10, 30)).max(0);
+    }
+    out
+}
+
+#[test]
+fn day1_generate_test() {
+    let params = Params::parse(&[String::from("depths=50")]).unwrap();
+    let input = generate(&mut Rng::new(1), &params);
+    assert_eq!(input, generate(&mut Rng::new(1), &params));
+    assert_eq!(50, input.lines().count());
+    Day01::parse(input.lines().map(String::from)).unwrap();
+}
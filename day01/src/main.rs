@@ -1,72 +1,70 @@
-use std::{env, io, io::prelude::*};
+use std::{
+    env,
+    io::{self, prelude::*},
+    process,
+};
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
-    let part = args.get(1).cloned().unwrap_or(String::from("1"));
-
-    let reader = io::stdin();
-    let lines = reader.lock().lines();
-    let values = lines.map(|x| x.unwrap().parse::<i32>().unwrap());
-    match part.as_str() {
-        "1" => println!("{}", larger_than_previous(values)),
-        "2" => println!("{}", windowed_larger_than_previous(values)),
-        _ => println!("Invalid part {}", part),
-    }
-}
+use day01::Windows;
 
-fn larger_than_previous(mut v: impl Iterator<Item = i32>) -> i32 {
-    let mut prev = v.next();
-    if prev.is_none() {
-        return 0;
-    }
-    let mut num_larger = 0;
-    for elem in v {
-        if elem > prev.unwrap() {
-            num_larger += 1;
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.as_slice() {
+        [flag, sizes] if flag == "--windows" => windows(sizes),
+        [flag] if flag == "--profile" => profile("20"),
+        [flag, option, threshold] if flag == "--profile" && option == "--threshold" => {
+            profile(threshold)
         }
-        prev = Some(elem);
+        _ => return aoc_core::main::<day01::Day01>(),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
     }
-    num_larger
 }
 
-#[test]
-fn part1_test() {
-    let v = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
-    let answer = larger_than_previous(v.into_iter());
+/// Slides a window of each of the comma separated `sizes` over stdin, reading
+/// it once
+fn windows(sizes: &str) -> Result<(), String> {
+    let sizes = sizes
+        .split(',')
+        .map(|size| {
+            size.parse()
+                .map_err(|_| format!("Invalid window size {}", size))
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+    let mut windows = Windows::new(&sizes)?;
 
-    assert_eq!(7, answer);
+    let mut error = None;
+    depths(&mut error).for_each(|depth| windows.push(depth));
+    error.map_or(Ok(()), Err)?;
+    print!("{}", day01::table(windows.sweeps()));
+    Ok(())
 }
 
-fn windowed_larger_than_previous(v: impl Iterator<Item = i32>) -> i32 {
-    let mut num_larger = 0;
-    let mut running_sum = 0;
-    let mut window: Vec<i32> = vec![];
-    for elem in v {
-        if window.len() < 3 {
-            window.push(elem);
-            running_sum += elem;
-            continue;
-        }
-
-        let prev_sum = running_sum;
+/// Writes each reading on stdin as a CSV row as soon as it is read
+fn profile(threshold: &str) -> Result<(), String> {
+    let threshold = threshold
+        .parse()
+        .map_err(|_| format!("Invalid threshold {}", threshold))?;
 
-        running_sum -= window[0];
-        window.remove(0);
-        window.push(elem);
-        running_sum += elem;
-
-        if running_sum > prev_sum {
-            num_larger += 1;
-        }
-    }
-
-    num_larger
+    let mut error = None;
+    let samples = day01::profile(depths(&mut error), threshold);
+    day01::csv(samples, &mut io::stdout().lock()).map_err(|e| format!("stdout: {}", e))?;
+    error.map_or(Ok(()), Err)
 }
 
-#[test]
-fn part2_test() {
-    let v = vec![199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
-    let answer = windowed_larger_than_previous(v.into_iter());
-
-    assert_eq!(5, answer);
+/// The readings on stdin, stopping at the first one that can't be read and
+/// leaving its error in `error`
+fn depths(error: &mut Option<String>) -> impl Iterator<Item = i32> + '_ {
+    io::stdin()
+        .lock()
+        .lines()
+        .enumerate()
+        .map_while(|(i, line)| {
+            let depth = line
+                .map_err(|e| format!("stdin: {}", e))
+                .and_then(|line| day01::depth(i + 1, &line).map_err(|e| format!("stdin: {}", e)));
+            depth.map_err(|e| *error = Some(e)).ok()
+        })
 }
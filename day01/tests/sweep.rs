@@ -0,0 +1,57 @@
+use aoc_core::ParseError;
+use day01::{csv, depth, profile, sweep, table, Feature, Windows};
+
+const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+#[test]
+fn windows_test() {
+    let mut windows = Windows::new(&[3, 1]).unwrap();
+    EXAMPLE.into_iter().for_each(|depth| windows.push(depth));
+    let increases = windows
+        .sweeps()
+        .iter()
+        .map(|sweep| (sweep.size, sweep.increases))
+        .collect::<Vec<_>>();
+    assert_eq!(vec![(3, 5), (1, 7)], increases);
+    assert_eq!(Ok(windows.sweeps()[1].clone()), sweep(EXAMPLE, 1));
+
+    // A window longer than the readings never moves
+    assert_eq!(Ok(0), sweep(EXAMPLE, 11).map(|s| s.increases));
+    assert_eq!(
+        Err(String::from("Invalid window size 0")),
+        Windows::new(&[0]).map(|_| ())
+    );
+}
+
+#[test]
+fn table_test() {
+    let out = table(&[sweep(EXAMPLE, 3).unwrap()]);
+    assert_eq!(
+        Some("     3          5          1          1            4"),
+        out.lines().nth(1)
+    );
+}
+
+#[test]
+fn profile_test() {
+    let features = profile([10, 10, 50, 40].into_iter(), 30)
+        .map(|sample| sample.feature)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![None, Some(Feature::Plateau), Some(Feature::Drop), None],
+        features
+    );
+
+    let mut out = vec![];
+    csv(profile([7].into_iter(), 30), &mut out).unwrap();
+    assert_eq!(
+        "reading,depth,change,min,max,mean,feature\n1,7,,7,7,7.00,\n",
+        String::from_utf8(out).unwrap()
+    );
+}
+
+#[test]
+fn depth_test() {
+    assert_eq!(Ok(-12), depth(1, "-12"));
+    assert_eq!(Err(ParseError::new(4, 3, "end of line")), depth(4, "12m"));
+}
@@ -0,0 +1,681 @@
+//! Day 2: Dive! Steers the submarine through its course, first by moving
+//! directly and then by adjusting its aim. Both parts run the same
+//! [`Command`]s through [`run`], and differ only in their [`Model`]. A
+//! [`Trajectory`] records every position along the way.
+
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// Follows the course with each [`Model`] and multiplies where it ends up
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    const DAY: u8 = 2;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<Command>, ParseError> {
+        parse_commands(v)
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<Answer, String> {
+        position_product(commands, Direct::default()).map(Answer::from)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<Answer, String> {
+        position_product(commands, Aim::default()).map(Answer::from)
+    }
+}
+
+/// One line of the course, or a block of them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// `forward N`
+    Forward(i64),
+    /// `down N`
+    Down(i64),
+    /// `up N`
+    Up(i64),
+    /// `back N`, which moves the opposite way to `forward`
+    Back(i64),
+    /// `surface`
+    Surface,
+    /// `repeat N {`, the commands on the lines after it, then `}`
+    Repeat(u32, Vec<Command>),
+}
+
+impl fmt::Display for Command {
+    /// The line the command starts on
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(amount) => write!(f, "forward {}", amount),
+            Command::Down(amount) => write!(f, "down {}", amount),
+            Command::Up(amount) => write!(f, "up {}", amount),
+            Command::Back(amount) => write!(f, "back {}", amount),
+            Command::Surface => write!(f, "surface"),
+            Command::Repeat(times, _) => write!(f, "repeat {} {{", times),
+        }
+    }
+}
+
+/// The deepest `repeat` blocks can be nested
+pub const MAX_NESTING: usize = 100;
+
+/// The most steps a course can take, see [`steps`]
+pub const MAX_STEPS: u64 = 100_000_000;
+
+/// How many steps `commands` take with their `repeat` blocks unrolled, up to
+/// `u64::MAX`. Each pass through an empty block counts as a step, so the
+/// count bounds the work of running them as well.
+pub fn steps(commands: &[Command]) -> u64 {
+    commands
+        .iter()
+        .map(Command::steps)
+        .fold(0, u64::saturating_add)
+}
+
+impl Command {
+    fn steps(&self) -> u64 {
+        match self {
+            Command::Repeat(times, block) => (*times as u64).saturating_mul(steps(block).max(1)),
+            _ => 1,
+        }
+    }
+}
+
+/// Parses one command per line, where a `repeat` block runs until its `}`.
+/// Lines may be indented. A course can take at most [`MAX_STEPS`] steps.
+pub fn parse_commands(v: impl Iterator<Item = String>) -> Result<Vec<Command>, ParseError> {
+    let mut commands = vec![];
+    let mut total = 0u64;
+    // Each open block, outermost first, with the error if it is never closed
+    let mut blocks: Vec<(u32, ParseError, Vec<Command>)> = vec![];
+    for (i, x) in v.enumerate() {
+        let mut c = Cursor::new(i + 1, &x);
+        c.skip_spaces();
+        let start = c.clone();
+
+        let verb = c.one_of(&["forward", "down", "up", "back", "surface", "repeat", "}"])?;
+        let command = match verb {
+            "surface" => Command::Surface,
+            "repeat" => {
+                c.literal(" ")?;
+                let times = c.number()?;
+                c.literal(" {")?;
+                c.end()?;
+                if blocks.len() == MAX_NESTING {
+                    let expected = format!("blocks nested at most {} deep", MAX_NESTING);
+                    return Err(start.error(expected));
+                }
+                blocks.push((times, start.error("a `}` closing this block"), vec![]));
+                continue;
+            }
+            "}" => {
+                let (times, _, block) = blocks.pop().ok_or_else(|| start.error("a command"))?;
+                Command::Repeat(times, block)
+            }
+            _ => {
+                c.literal(" ")?;
+                let amount = c.number()?;
+                match verb {
+                    "forward" => Command::Forward(amount),
+                    "down" => Command::Down(amount),
+                    "up" => Command::Up(amount),
+                    _ => Command::Back(amount),
+                }
+            }
+        };
+        c.end()?;
+        match blocks.last_mut() {
+            Some((_, _, block)) => block.push(command),
+            None => {
+                total = total.saturating_add(command.steps());
+                if total > MAX_STEPS {
+                    let expected = format!("a course of at most {} steps", MAX_STEPS);
+                    return Err(start.error(expected));
+                }
+                commands.push(command);
+            }
+        }
+    }
+
+    match blocks.pop() {
+        Some((_, unclosed, _)) => Err(unclosed),
+        None => Ok(commands),
+    }
+}
+
+/// How commands move the submarine. Verbs a model gives no meaning of its own
+/// fall back on the basic ones, e.g. `back` is `forward` in reverse. A move
+/// fails if it would overflow.
+pub trait Model {
+    /// Moves ahead by `amount`
+    fn forward(&mut self, amount: i64) -> Result<(), String>;
+
+    /// Goes deeper by `amount`, or steers that much further down
+    fn down(&mut self, amount: i64) -> Result<(), String>;
+
+    /// The opposite of `down`
+    fn up(&mut self, amount: i64) -> Result<(), String> {
+        self.down(checked(amount.checked_neg(), "up")?)
+    }
+
+    /// The opposite of `forward`
+    fn back(&mut self, amount: i64) -> Result<(), String> {
+        self.forward(checked(amount.checked_neg(), "back")?)
+    }
+
+    /// Rises straight up to the surface
+    fn surface(&mut self);
+
+    /// How far along and how deep the submarine is
+    fn position(&self) -> (i64, i64);
+
+    /// Which way the submarine is pointing, for models that steer
+    fn aim(&self) -> i64 {
+        0
+    }
+}
+
+/// The result of checked arithmetic, or an error naming what overflowed
+pub fn checked(n: Option<i64>, what: &str) -> Result<i64, String> {
+    n.ok_or(format!("{} overflowed", what))
+}
+
+/// Part 1: `down` and `up` change the depth directly
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Direct {
+    /// How far along the submarine is
+    pub horizontal: i64,
+    /// How deep the submarine is
+    pub depth: i64,
+}
+
+impl Model for Direct {
+    fn forward(&mut self, amount: i64) -> Result<(), String> {
+        self.horizontal = checked(self.horizontal.checked_add(amount), "horizontal")?;
+        Ok(())
+    }
+
+    fn down(&mut self, amount: i64) -> Result<(), String> {
+        self.depth = checked(self.depth.checked_add(amount), "depth")?;
+        Ok(())
+    }
+
+    fn surface(&mut self) {
+        self.depth = 0;
+    }
+
+    fn position(&self) -> (i64, i64) {
+        (self.horizontal, self.depth)
+    }
+}
+
+/// Part 2: `down` and `up` tilt the submarine, and moving forward follows
+/// its aim
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Aim {
+    /// How far along the submarine is
+    pub horizontal: i64,
+    /// How deep the submarine is
+    pub depth: i64,
+    /// How much deeper each step forward takes it
+    pub aim: i64,
+}
+
+impl Model for Aim {
+    fn forward(&mut self, amount: i64) -> Result<(), String> {
+        let dive = checked(self.aim.checked_mul(amount), "depth")?;
+        let depth = checked(self.depth.checked_add(dive), "depth")?;
+        self.horizontal = checked(self.horizontal.checked_add(amount), "horizontal")?;
+        self.depth = depth;
+        Ok(())
+    }
+
+    fn down(&mut self, amount: i64) -> Result<(), String> {
+        self.aim = checked(self.aim.checked_add(amount), "aim")?;
+        Ok(())
+    }
+
+    /// Levels off as well
+    fn surface(&mut self) {
+        self.depth = 0;
+        self.aim = 0;
+    }
+
+    fn position(&self) -> (i64, i64) {
+        (self.horizontal, self.depth)
+    }
+
+    fn aim(&self) -> i64 {
+        self.aim
+    }
+}
+
+/// Moves `model` through each of `commands` in turn
+pub fn run(commands: &[Command], model: &mut impl Model) -> Result<(), String> {
+    run_each(commands, model, &mut |_, _| {})
+}
+
+/// Like [`run`], but shows `each` every command other than a `repeat` along
+/// with the model it just moved. Fails on courses of more than [`MAX_STEPS`]
+/// steps, or at the first move that overflows.
+pub fn run_each<M: Model>(
+    commands: &[Command],
+    model: &mut M,
+    each: &mut impl FnMut(&Command, &M),
+) -> Result<(), String> {
+    if steps(commands) > MAX_STEPS {
+        return Err(format!("The course takes more than {} steps", MAX_STEPS));
+    }
+    unroll(commands, model, &mut 0, each)
+}
+
+fn unroll<M: Model>(
+    commands: &[Command],
+    model: &mut M,
+    step: &mut usize,
+    each: &mut impl FnMut(&Command, &M),
+) -> Result<(), String> {
+    for command in commands {
+        let moved = match command {
+            Command::Forward(amount) => model.forward(*amount),
+            Command::Down(amount) => model.down(*amount),
+            Command::Up(amount) => model.up(*amount),
+            Command::Back(amount) => model.back(*amount),
+            Command::Surface => {
+                model.surface();
+                Ok(())
+            }
+            Command::Repeat(times, block) => {
+                for _ in 0..*times {
+                    unroll(block, model, step, each)?;
+                }
+                continue;
+            }
+        };
+        *step += 1;
+        moved.map_err(|e| format!("Step {} ({}): {}", step, command, e))?;
+        each(command, model);
+    }
+    Ok(())
+}
+
+fn position_product(commands: &[Command], mut model: impl Model) -> Result<i128, String> {
+    run(commands, &mut model)?;
+    let (horizontal, depth) = model.position();
+    Ok(horizontal as i128 * depth as i128)
+}
+
+/// Where the submarine was after a step of the course
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Point {
+    /// Counts the commands run so far, with `repeat` blocks unrolled
+    pub step: usize,
+    /// The command that moved it here, or `None` at the start
+    pub command: Option<Command>,
+    /// How far along the submarine was
+    pub horizontal: i64,
+    /// How deep the submarine was
+    pub depth: i64,
+    /// Where it was pointing, always 0 for [`Direct`]
+    pub aim: i64,
+}
+
+/// The most steps a [`Trajectory`] records, as it keeps a point for each
+pub const MAX_RECORDED_STEPS: u64 = 1_000_000;
+
+/// Every position a course passes through, starting from the surface
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    /// The start, then one point per step
+    pub points: Vec<Point>,
+}
+
+impl Trajectory {
+    /// Runs `commands` through `model`, keeping each point along the way. Fails
+    /// on courses of more than [`MAX_RECORDED_STEPS`] steps.
+    pub fn record<M: Model>(commands: &[Command], mut model: M) -> Result<Trajectory, String> {
+        let point = |step, command: Option<&Command>, model: &M| {
+            let (horizontal, depth) = model.position();
+            Point {
+                step,
+                command: command.cloned(),
+                horizontal,
+                depth,
+                aim: model.aim(),
+            }
+        };
+
+        if steps(commands) > MAX_RECORDED_STEPS {
+            return Err(format!(
+                "The course takes more than the {} steps a trajectory can record",
+                MAX_RECORDED_STEPS
+            ));
+        }
+
+        let mut points = vec![point(0, None, &model)];
+        run_each(commands, &mut model, &mut |command, model| {
+            points.push(point(points.len(), Some(command), model));
+        })?;
+        Ok(Trajectory { points })
+    }
+
+    /// The first point at the greatest depth
+    pub fn deepest(&self) -> &Point {
+        let deepest = self.points.iter().map(|p| p.depth).max().unwrap_or(0);
+        self.points.iter().find(|p| p.depth == deepest).unwrap()
+    }
+
+    /// The first step that goes down to `depth` or below from above it
+    pub fn crossing(&self, depth: i64) -> Option<&Point> {
+        self.points
+            .windows(2)
+            .find(|pair| pair[0].depth < depth && pair[1].depth >= depth)
+            .map(|pair| &pair[1])
+    }
+
+    /// Where the submarine was after `step` commands
+    pub fn at(&self, step: usize) -> Option<&Point> {
+        self.points.get(step)
+    }
+
+    /// Writes one row per point
+    pub fn csv(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "step,command,horizontal,depth,aim")?;
+        for point in &self.points {
+            writeln!(
+                out,
+                "{},{},{},{},{}",
+                point.step,
+                point
+                    .command
+                    .as_ref()
+                    .map(Command::to_string)
+                    .unwrap_or_default(),
+                point.horizontal,
+                point.depth,
+                point.aim
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Draws the course as a line seen from the side, deeper further down. The
+    /// picture is stretched to fit, as depths run far larger than distances.
+    pub fn svg(&self, out: &mut impl Write) -> io::Result<()> {
+        let xs = self.points.iter().map(|p| p.horizontal);
+        let ys = self.points.iter().map(|p| p.depth);
+        let (left, top) = (xs.clone().min().unwrap_or(0), ys.clone().min().unwrap_or(0));
+        let width = (xs.max().unwrap_or(0) - left).max(1);
+        let height = (ys.max().unwrap_or(0) - top).max(1);
+
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="400" viewBox="{} {} {} {}" preserveAspectRatio="none">"#,
+            left, top, width, height
+        )?;
+        let points = self
+            .points
+            .iter()
+            .map(|p| format!("{},{}", p.horizontal, p.depth))
+            .collect::<Vec<_>>();
+        writeln!(
+            out,
+            r##"<polyline points="{}" fill="none" stroke="#457b9d" stroke-width="2" vector-effect="non-scaling-stroke"/>"##,
+            points.join(" ")
+        )?;
+        writeln!(out, "</svg>")
+    }
+}
+
+#[cfg(test)]
+fn example() -> Vec<Command> {
+    let lines = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+    parse_commands(lines.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn day2_part1_test() {
+    assert_eq!(Ok(150), position_product(&example(), Direct::default()));
+}
+
+#[test]
+fn day2_part2_test() {
+    assert_eq!(Ok(900), position_product(&example(), Aim::default()));
+}
+
+#[test]
+fn parse_commands_test() {
+    let parse = |s: &str| parse_commands(s.lines().map(String::from));
+
+    let course = "down 2\nrepeat 3 {\n  forward 1\n  repeat 2 {\n    back 4\n  }\n}\nsurface";
+    assert_eq!(
+        Ok(vec![
+            Command::Down(2),
+            Command::Repeat(
+                3,
+                vec![
+                    Command::Forward(1),
+                    Command::Repeat(2, vec![Command::Back(4)])
+                ]
+            ),
+            Command::Surface,
+        ]),
+        parse(course)
+    );
+
+    assert_eq!(
+        Err(ParseError::new(1, 1, "a `}` closing this block")),
+        parse("repeat 2 {\nforward 1")
+    );
+    assert_eq!(Err(ParseError::new(2, 1, "a command")), parse("up 1\n}"));
+    assert!(parse("surface 3").is_err());
+    assert!(parse("repeat -1 {\n}").is_err());
+    assert!(parse("sideways 3").is_err());
+    assert!(parse(&"repeat 1 {\n".repeat(MAX_NESTING)).is_err());
+    assert!(parse(&"repeat 1 {\n".repeat(MAX_NESTING + 1)).is_err());
+    let nested = "repeat 1 {\n".repeat(MAX_NESTING) + &"}\n".repeat(MAX_NESTING);
+    assert!(parse(&nested).is_ok());
+
+    // Too many steps once unrolled, even with nothing to repeat
+    let expected = format!("a course of at most {} steps", MAX_STEPS);
+    assert_eq!(
+        Err(ParseError::new(5, 1, &expected)),
+        parse("repeat 4294967295 {\nrepeat 4294967295 {\nforward 1\n}\n}")
+    );
+    assert_eq!(
+        Err(ParseError::new(4, 1, &expected)),
+        parse("repeat 4294967295 {\nrepeat 4294967295 {\n}\n}")
+    );
+    assert_eq!(
+        Err(ParseError::new(3, 1, &expected)),
+        parse("repeat 100000000 {\n}\nsurface")
+    );
+}
+
+#[test]
+fn steps_test() {
+    let block = |times, block| Command::Repeat(times, block);
+    assert_eq!(0, steps(&[]));
+    assert_eq!(
+        7,
+        steps(&[
+            Command::Surface,
+            block(3, vec![Command::Up(1), Command::Down(1)])
+        ])
+    );
+    assert_eq!(4, steps(&[block(4, vec![])]));
+    assert_eq!(
+        u64::MAX,
+        steps(&[block(
+            u32::MAX,
+            vec![block(u32::MAX, vec![block(u32::MAX, vec![])])]
+        )])
+    );
+    // Courses built without the parser are bounded too
+    let endless = [block(u32::MAX, vec![block(u32::MAX, vec![])])];
+    assert!(run(&endless, &mut Direct::default()).is_err());
+}
+
+#[test]
+fn run_test() {
+    let course = parse_commands(
+        "forward 3\ndown 2\nrepeat 2 {\nforward 5\nback 1\n}\nsurface\nforward 1\ndown 1"
+            .lines()
+            .map(String::from),
+    )
+    .unwrap();
+
+    let mut direct = Direct::default();
+    run(&course, &mut direct).unwrap();
+    assert_eq!((12, 1), direct.position());
+
+    // Surfacing levels off, so the last forward stays at the surface
+    let mut aim = Aim::default();
+    run(&course, &mut aim).unwrap();
+    assert_eq!(
+        Aim {
+            horizontal: 12,
+            depth: 0,
+            aim: 1
+        },
+        aim
+    );
+}
+
+#[test]
+fn overflow_test() {
+    let course = |s: &str| parse_commands(s.lines().map(String::from)).unwrap();
+
+    let mut direct = Direct::default();
+    assert_eq!(
+        Err(String::from(
+            "Step 1 (up -9223372036854775808): up overflowed"
+        )),
+        run(&course("up -9223372036854775808"), &mut direct)
+    );
+    assert_eq!(
+        Err(String::from(
+            "Step 2 (forward 9223372036854775807): horizontal overflowed"
+        )),
+        run(
+            &course("repeat 2 {\nforward 9223372036854775807\n}"),
+            &mut direct
+        )
+    );
+    assert_eq!((9223372036854775807, 0), direct.position());
+
+    // The aim model is left as it was before the step that failed
+    let mut aim = Aim::default();
+    let failed = run(&course("down 3074457345618258603\nforward 3"), &mut aim);
+    assert_eq!(
+        Err(String::from("Step 2 (forward 3): depth overflowed")),
+        failed
+    );
+    assert_eq!((0, 0), aim.position());
+
+    assert_eq!(
+        Err(String::from("Step 2 (down 1): aim overflowed")),
+        Day02::part2(&course("down 9223372036854775807\ndown 1"))
+    );
+    let answer = Day02::part1(&course(
+        "down 9223372036854775807\nforward 9223372036854775807",
+    ))
+    .unwrap();
+    assert_eq!("85070591730234615847396907784232501249", answer.to_string());
+}
+
+#[test]
+fn trajectory_test() {
+    let course = parse_commands(
+        "down 5\nrepeat 2 {\nforward 2\n}\nup 7\nforward 1\nsurface"
+            .lines()
+            .map(String::from),
+    )
+    .unwrap();
+
+    let direct = Trajectory::record(&course, Direct::default()).unwrap();
+    assert_eq!(
+        vec![0, 5, 5, 5, -2, -2, 0],
+        direct.points.iter().map(|p| p.depth).collect::<Vec<_>>()
+    );
+    assert_eq!(1, direct.deepest().step);
+    assert_eq!(Some(1), direct.crossing(3).map(|p| p.step));
+    assert_eq!(Some(6), direct.crossing(-1).map(|p| p.step));
+    assert_eq!(None, direct.crossing(6));
+    assert_eq!(
+        Some(&Point {
+            step: 3,
+            command: Some(Command::Forward(2)),
+            horizontal: 4,
+            depth: 5,
+            aim: 0,
+        }),
+        direct.at(3)
+    );
+    assert_eq!(None, direct.at(7));
+
+    let aim = Trajectory::record(&course, Aim::default()).unwrap();
+    assert_eq!(
+        vec![0, 0, 10, 20, 20, 18, 0],
+        aim.points.iter().map(|p| p.depth).collect::<Vec<_>>()
+    );
+    assert_eq!(3, aim.deepest().step);
+    assert_eq!(-2, aim.at(4).unwrap().aim);
+}
+
+#[test]
+fn trajectory_limit_test() {
+    let course = |times: u64| {
+        let course = format!("repeat {} {{\nforward 1\n}}", times);
+        parse_commands(course.lines().map(String::from)).unwrap()
+    };
+    let trajectory = Trajectory::record(&course(MAX_RECORDED_STEPS), Direct::default()).unwrap();
+    assert_eq!(MAX_RECORDED_STEPS as usize + 1, trajectory.points.len());
+    assert!(Trajectory::record(&course(MAX_RECORDED_STEPS + 1), Direct::default()).is_err());
+}
+
+#[test]
+fn trajectory_export_test() {
+    let course = parse_commands(["down 2", "forward 3"].into_iter().map(String::from)).unwrap();
+    let trajectory = Trajectory::record(&course, Aim::default()).unwrap();
+
+    let mut csv = vec![];
+    trajectory.csv(&mut csv).unwrap();
+    assert_eq!(
+        "step,command,horizontal,depth,aim\n0,,0,0,0\n1,down 2,0,0,2\n2,forward 3,3,6,2\n",
+        String::from_utf8(csv).unwrap()
+    );
+
+    let mut svg = vec![];
+    trajectory.svg(&mut svg).unwrap();
+    let svg = String::from_utf8(svg).unwrap();
+    assert!(svg.contains(r#"viewBox="0 0 3 6""#));
+    assert!(svg.contains(r#"points="0,0 0,0 3,6""#));
+}
+
+/// `commands` random moves, mostly forward and down
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let commands = params.get("commands", 1000);
+    let mut out = String::new();
+    for _ in 0..commands {
+        let direction = rng.pick(&["forward", "forward", "down", "down", "up"]);
+        out += &format!("{} {}\n", direction, rng.range(1, 9));
+    }
+    out
+}
+
+#[test]
+fn day2_generate_test() {
+    let params = Params::parse(&[String::from("commands=50")]).unwrap();
+    let input = generate(&mut Rng::new(2), &params);
+    let commands = Day02::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(50, commands.len());
+}
@@ -1,83 +1,130 @@
-use std::{env, io, io::prelude::*};
+use std::{
+    env, fs,
+    io::{self, prelude::*},
+    process,
+};
+
+use aoc_core::{input, Solution};
+use day02::{Aim, Day02, Direct, Point, Trajectory};
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let part = args.get(1).cloned().unwrap_or(String::from("1"));
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("--trajectory") => parse_args(&args[1..]).and_then(|options| trajectory(&options)),
+        _ => return aoc_core::main::<Day02>(),
+    };
 
-    let reader = io::stdin();
-    let lines = reader.lock().lines();
-    let values = lines.map(|x| x.unwrap());
-    match part.as_str() {
-        "1" => println!("{}", part1_depth_by_horizontal(values)),
-        "2" => println!("{}", part2_depth_by_horizontal(values)),
-        _ => println!("Invalid part {}", part),
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
     }
 }
 
-fn part1_depth_by_horizontal(v: impl Iterator<Item = String>) -> i32 {
-    let mut depth = 0;
-    let mut horiz = 0;
-    for elem in v {
-        let cap = elem.split(" ").collect::<Vec<_>>();
-        let dir = cap.get(0).unwrap();
-        let amt = cap.get(1).unwrap().parse::<i32>().unwrap();
-        match *dir {
-            "forward" => horiz += amt,
-            "down" => depth += amt,
-            "up" => depth -= amt,
-            _ => println!("Invalid direction {}", dir),
+const USAGE: &str = "\
+Usage: day02 --trajectory [part] [--output <path>] [--crossing <depth>] [--at <step>]
+Records the course on stdin as the part steers it, and writes each step to a
+.csv or .svg file";
+
+#[derive(Debug, Default)]
+struct Options {
+    part: u8,
+    output: Option<String>,
+    crossing: Option<i64>,
+    at: Option<usize>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        part: 1,
+        ..Options::default()
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--output" => options.output = Some(value()?.clone()),
+            "--crossing" => {
+                let depth = value()?;
+                options.crossing = Some(
+                    depth
+                        .parse()
+                        .map_err(|_| format!("Invalid depth {}", depth))?,
+                );
+            }
+            "--at" => {
+                let step = value()?;
+                options.at = Some(step.parse().map_err(|_| format!("Invalid step {}", step))?);
+            }
+            "1" | "2" => options.part = arg.parse().unwrap(),
+            _ => return Err(String::from(USAGE)),
         }
     }
-    depth * horiz
+    Ok(options)
 }
 
-#[test]
-fn day2_part1_test() {
-    let v = vec![
-        String::from("forward 5"),
-        String::from("down 5"),
-        String::from("forward 8"),
-        String::from("up 3"),
-        String::from("down 8"),
-        String::from("forward 2"),
-    ];
-    let answer = part1_depth_by_horizontal(v.into_iter());
+fn trajectory(options: &Options) -> Result<(), String> {
+    let text = input::read_stdin()?;
+    let commands = Day02::parse(text.lines().map(String::from))
+        .map_err(|e| format!("stdin: {}", e.report(&text)))?;
+    let trajectory = match options.part {
+        1 => Trajectory::record(&commands, Direct::default()),
+        _ => Trajectory::record(&commands, Aim::default()),
+    }?;
 
-    assert_eq!(150, answer);
-}
+    if let Some(path) = &options.output {
+        let svg = match path.rsplit_once('.').map(|(_, extension)| extension) {
+            Some("csv") => false,
+            Some("svg") => true,
+            _ => return Err(format!("{}: expected a .csv or .svg file", path)),
+        };
+        let error = |e: io::Error| format!("{}: {}", path, e);
+        let mut out = io::BufWriter::new(fs::File::create(path).map_err(error)?);
+        let written = match svg {
+            true => trajectory.svg(&mut out),
+            false => trajectory.csv(&mut out),
+        };
+        written.and_then(|_| out.flush()).map_err(error)?;
+    }
 
-fn part2_depth_by_horizontal(v: impl Iterator<Item = String>) -> i32 {
-    let mut aim = 0;
-    let mut depth = 0;
-    let mut horiz = 0;
-    for elem in v {
-        let cap = elem.split(" ").collect::<Vec<_>>();
-        let dir = cap.get(0).unwrap();
-        let amt = cap.get(1).unwrap().parse::<i32>().unwrap();
-        match *dir {
-            "forward" => {
-                horiz += amt;
-                depth += aim * amt;
-            }
-            "down" => aim += amt,
-            "up" => aim -= amt,
-            _ => println!("Invalid direction {}", dir),
+    let describe = |point: &Point| {
+        format!(
+            "step {}: horizontal {}, depth {}, aim {}",
+            point.step, point.horizontal, point.depth, point.aim
+        )
+    };
+    println!("Steps: {}", trajectory.points.len() - 1);
+    println!("Deepest at {}", describe(trajectory.deepest()));
+    if let Some(depth) = options.crossing {
+        match trajectory.crossing(depth) {
+            Some(point) => println!("Crosses depth {} at {}", depth, describe(point)),
+            None => println!("Never goes down to depth {}", depth),
         }
     }
-    depth * horiz
+    if let Some(step) = options.at {
+        match trajectory.at(step) {
+            Some(point) => println!("At {}", describe(point)),
+            None => println!("The course ends before step {}", step),
+        }
+    }
+    Ok(())
 }
 
 #[test]
-fn day2_part2_test() {
-    let v = vec![
-        String::from("forward 5"),
-        String::from("down 5"),
-        String::from("forward 8"),
-        String::from("up 3"),
-        String::from("down 8"),
-        String::from("forward 2"),
-    ];
-    let answer = part2_depth_by_horizontal(v.into_iter());
+fn parse_args_test() {
+    let args = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+
+    let options = parse_args(&[]).unwrap();
+    assert_eq!(1, options.part);
+    assert_eq!(None, options.output);
+
+    let options = parse_args(&args("2 --output a.svg --crossing 40 --at 7")).unwrap();
+    assert_eq!(2, options.part);
+    assert_eq!(Some(String::from("a.svg")), options.output);
+    assert_eq!(Some(40), options.crossing);
+    assert_eq!(Some(7), options.at);
 
-    assert_eq!(900, answer);
+    assert!(parse_args(&args("3")).is_err());
+    assert!(parse_args(&args("--at -1")).is_err());
+    assert!(parse_args(&args("--crossing")).is_err());
 }
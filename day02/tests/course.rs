@@ -0,0 +1,63 @@
+use day02::{parse_commands, run, steps, Aim, Command, Direct, Trajectory, MAX_STEPS};
+
+fn commands(input: &str) -> Vec<Command> {
+    parse_commands(input.lines().map(String::from)).unwrap()
+}
+
+const EXAMPLE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2";
+
+#[test]
+fn run_test() {
+    let course = commands(EXAMPLE);
+    let mut direct = Direct::default();
+    run(&course, &mut direct).unwrap();
+    assert_eq!((15, 10), (direct.horizontal, direct.depth));
+
+    let mut aim = Aim::default();
+    run(&course, &mut aim).unwrap();
+    assert_eq!((15, 60, 10), (aim.horizontal, aim.depth, aim.aim));
+}
+
+#[test]
+fn repeat_test() {
+    let course = commands("repeat 3 {\n  forward 2\n  repeat 2 {\n    down 1\n  }\n}\nback 1");
+    assert_eq!(10, steps(&course));
+    assert_eq!("repeat 3 {", course[0].to_string());
+
+    let mut direct = Direct::default();
+    run(&course, &mut direct).unwrap();
+    assert_eq!((5, 6), (direct.horizontal, direct.depth));
+
+    // Too long a course is refused before it can run
+    let endless = "repeat 4294967295 {\nrepeat 4294967295 {\nforward 1\n}\n}";
+    let error = parse_commands(endless.lines().map(String::from)).unwrap_err();
+    assert!(
+        error.to_string().contains(&MAX_STEPS.to_string()),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn trajectory_test() {
+    let trajectory = Trajectory::record(&commands(EXAMPLE), Aim::default()).unwrap();
+    assert_eq!(7, trajectory.points.len());
+    assert_eq!(60, trajectory.deepest().depth);
+    assert_eq!(
+        Some(&Command::Down(8)),
+        trajectory.at(5).unwrap().command.as_ref()
+    );
+
+    let crossing = trajectory.crossing(40).unwrap();
+    assert_eq!((3, 13), (crossing.step, crossing.horizontal));
+    assert_eq!(None, trajectory.crossing(61));
+}
+
+#[test]
+fn parse_commands_test() {
+    let error = parse_commands(["forward 1", "sideways 2"].into_iter().map(String::from));
+    assert_eq!(
+        "line 2, column 1: expected one of `forward`, `down`, `up`, `back`, `surface`, `repeat`, `}`",
+        error.unwrap_err().to_string()
+    );
+}
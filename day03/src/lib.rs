@@ -0,0 +1,494 @@
+//! Day 3: Binary Diagnostic. Reads the power consumption and life support
+//! ratings out of a report of binary numbers.
+
+use std::{fmt, ops::Mul};
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// Multiplies the gamma and epsilon rates, then the oxygen and CO2 ratings
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    const DAY: u8 = 3;
+
+    /// Every line must be as wide as the first
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<String>, ParseError> {
+        let mut width = None;
+        v.enumerate()
+            .map(|(i, x)| {
+                let mut c = Cursor::new(i + 1, &x);
+                let mut bits = 0;
+                loop {
+                    c.one_of(&["0", "1"])?;
+                    bits += 1;
+                    if c.is_empty() || Some(bits) == width {
+                        break;
+                    }
+                }
+
+                match width {
+                    None => width = Some(bits),
+                    Some(width) if bits < width => {
+                        return Err(c.error(format!(
+                            "{} more bits, as line 1 is {} bits wide",
+                            width - bits,
+                            width
+                        )))
+                    }
+                    Some(width) if !c.is_empty() => {
+                        return Err(c.error(format!(
+                            "the end of the line, as line 1 is {} bits wide",
+                            width
+                        )))
+                    }
+                    Some(_) => {}
+                }
+                Ok(x)
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, String> {
+        Ok(part1_gamma_epsilon(lines.iter().cloned()).into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, String> {
+        Ok(part2_o2_co2(lines.iter().cloned()).into())
+    }
+
+    /// Part 2 also filters the candidates list by list, without the trie
+    fn alternatives(part: u8) -> Vec<(&'static str, fn(&Vec<String>) -> Result<Answer, String>)> {
+        match part {
+            2 => vec![("naive", |lines| {
+                Ok(part2_o2_co2_naive(lines.iter().cloned()).into())
+            })],
+            _ => vec![],
+        }
+    }
+}
+
+/// An unsigned number as wide as it needs to be, in 64-bit limbs with the
+/// least significant first
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Bits(Vec<u64>);
+
+impl Bits {
+    /// Reads a string of `0`s and `1`s, most significant bit first
+    pub fn parse(binary: &str) -> Result<Bits, String> {
+        let mut bits = Bits::default();
+        for (i, c) in binary.chars().rev().enumerate() {
+            match c {
+                '0' => {}
+                '1' => bits.set(i),
+                _ => return Err(format!("Invalid bit {:?} in {}", c, binary)),
+            }
+        }
+        Ok(bits)
+    }
+
+    /// Sets bit `i`, counting from the least significant
+    pub fn set(&mut self, i: usize) {
+        if self.0.len() <= i / 64 {
+            self.0.resize(i / 64 + 1, 0);
+        }
+        self.0[i / 64] |= 1 << (i % 64);
+    }
+
+    /// Whether no bit is set
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|&limb| limb == 0)
+    }
+
+    /// The number the bits make, or `None` if it's too big for a `u128`
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.trimmed() {
+            [] => Some(0),
+            [low] => Some(*low as u128),
+            [low, high] => Some((*high as u128) << 64 | *low as u128),
+            _ => None,
+        }
+    }
+
+    fn trimmed(&self) -> &[u64] {
+        let len = self
+            .0
+            .iter()
+            .rposition(|&limb| limb != 0)
+            .map_or(0, |i| i + 1);
+        &self.0[..len]
+    }
+
+    /// Divides in place, returning the remainder
+    fn div_rem(&mut self, divisor: u64) -> u64 {
+        let mut rem = 0u128;
+        for limb in self.0.iter_mut().rev() {
+            let n = rem << 64 | *limb as u128;
+            *limb = (n / divisor as u128) as u64;
+            rem = n % divisor as u128;
+        }
+        rem as u64
+    }
+}
+
+impl Mul for &Bits {
+    type Output = Bits;
+
+    fn mul(self, other: &Bits) -> Bits {
+        let (a, b) = (self.trimmed(), other.trimmed());
+        let mut limbs = vec![0u64; a.len() + b.len()];
+        for (i, &x) in a.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &y) in b.iter().enumerate() {
+                let n = x as u128 * y as u128 + limbs[i + j] as u128 + carry;
+                limbs[i + j] = n as u64;
+                carry = n >> 64;
+            }
+            limbs[i + b.len()] = carry as u64;
+        }
+        Bits(limbs)
+    }
+}
+
+impl fmt::Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Nineteen decimal digits at a time, least significant first
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut n = self.clone();
+        let mut chunks = vec![n.div_rem(CHUNK)];
+        while !n.is_zero() {
+            chunks.push(n.div_rem(CHUNK));
+        }
+
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap())?;
+        chunks.try_for_each(|chunk| write!(f, "{:019}", chunk))
+    }
+}
+
+/// Numbers too wide for a `u128` become strings of digits
+impl From<Bits> for Answer {
+    fn from(bits: Bits) -> Answer {
+        match bits.to_u128() {
+            Some(n) => n.into(),
+            None => bits.to_string().into(),
+        }
+    }
+}
+
+fn part1_gamma_epsilon(v: impl Iterator<Item = String>) -> Bits {
+    let mut zeros: Vec<usize> = vec![];
+    let mut ones: Vec<usize> = vec![];
+
+    for elem in v {
+        zeros.resize(elem.len(), 0);
+        ones.resize(elem.len(), 0);
+        for (i, c) in elem.chars().enumerate() {
+            match c {
+                '0' => zeros[i] += 1,
+                '1' => ones[i] += 1,
+                _ => panic!("Invalid character {}", c),
+            }
+        }
+    }
+
+    let len = zeros.len();
+    let mut gamma = Bits::default();
+    let mut epsilon = Bits::default();
+    for i in 0..len {
+        let x = len - i - 1;
+        if ones[i] > zeros[i] {
+            gamma.set(x);
+        } else {
+            epsilon.set(x);
+        }
+    }
+
+    &gamma * &epsilon
+}
+
+#[test]
+fn day3_part1_test() {
+    let v = vec![
+        String::from("00100"),
+        String::from("11110"),
+        String::from("10110"),
+        String::from("10111"),
+        String::from("10101"),
+        String::from("01111"),
+        String::from("00111"),
+        String::from("11100"),
+        String::from("10000"),
+        String::from("11001"),
+        String::from("00010"),
+        String::from("01010"),
+    ];
+    let answer = part1_gamma_epsilon(v.into_iter());
+
+    assert_eq!(Some(198), answer.to_u128());
+}
+
+struct Node {
+    children: Option<[Box<Node>; 2]>,
+    weight: usize,
+}
+
+impl Node {
+    fn new() -> Box<Node> {
+        Box::new(Node {
+            children: None,
+            weight: 0,
+        })
+    }
+}
+
+fn part2_o2_co2(v: impl Iterator<Item = String>) -> Bits {
+    let mut root = Node::new();
+    let mut width = 0;
+
+    // Pass through the input and build a weighted tree of nodes
+    for elem in v {
+        let mut current = &mut root;
+        width = elem.len();
+
+        for c in elem.chars() {
+            let children = current
+                .children
+                .get_or_insert_with(|| [Node::new(), Node::new()]);
+            current = match c {
+                '0' => &mut children[0],
+                '1' => &mut children[1],
+                _ => panic!("Invalid character {}", c),
+            };
+
+            current.weight += 1;
+        }
+    }
+
+    let o2 = rating(&root, width, |zeros, ones| ones >= zeros);
+    let co2 = rating(&root, width, |zeros, ones| zeros > ones);
+    &o2 * &co2
+}
+
+/// Follows the tree down from the root one bit at a time, taking the ones
+/// branch if `ones` picks it given how many numbers are down each branch. A
+/// branch with no numbers down it is never taken.
+fn rating(root: &Node, width: usize, ones: impl Fn(usize, usize) -> bool) -> Bits {
+    let mut rating = Bits::default();
+    let mut current = root;
+    for i in 0..width {
+        let children = current.children.as_ref().unwrap();
+        let mut bit = ones(children[0].weight, children[1].weight) as usize;
+        if children[bit].weight == 0 {
+            bit = 1 - bit;
+        }
+
+        if bit == 1 {
+            rating.set(width - i - 1);
+        }
+        current = &children[bit];
+    }
+    rating
+}
+
+fn part2_o2_co2_naive(v: impl Iterator<Item = String>) -> Bits {
+    let mut o2_candidates: Vec<String> = v.collect();
+    let len = o2_candidates[0].len();
+
+    let mut co2_candidates: Vec<String> = o2_candidates.clone();
+
+    for i in 0..len {
+        if o2_candidates.len() > 1 {
+            let (o2_zeros, o2_ones): (Vec<String>, Vec<String>) = o2_candidates
+                .clone()
+                .into_iter()
+                .partition(|s| s.as_bytes()[i] == b'0');
+
+            o2_candidates = if o2_ones.len() >= o2_zeros.len() {
+                o2_ones
+            } else {
+                o2_zeros
+            };
+        }
+
+        if co2_candidates.len() > 1 {
+            let (co2_zeros, co2_ones): (Vec<String>, Vec<String>) = co2_candidates
+                .clone()
+                .into_iter()
+                .partition(|s| s.as_bytes()[i] == b'0');
+
+            // The least common bit, unless none of the candidates have it
+            co2_candidates = if co2_ones.is_empty()
+                || (!co2_zeros.is_empty() && co2_zeros.len() <= co2_ones.len())
+            {
+                co2_zeros
+            } else {
+                co2_ones
+            };
+        }
+    }
+
+    let rating = |candidates: &[String]| {
+        Bits::parse(&candidates[0]).expect("parsed lines should only hold bits")
+    };
+    &rating(&o2_candidates) * &rating(&co2_candidates)
+}
+
+#[test]
+fn day3_part2_test() {
+    let v = vec![
+        String::from("00100"),
+        String::from("11110"),
+        String::from("10110"),
+        String::from("10111"),
+        String::from("10101"),
+        String::from("01111"),
+        String::from("00111"),
+        String::from("11100"),
+        String::from("10000"),
+        String::from("11001"),
+        String::from("00010"),
+        String::from("01010"),
+    ];
+    let answer = part2_o2_co2(v.into_iter());
+
+    assert_eq!(Some(230), answer.to_u128());
+}
+
+#[test]
+fn day3_part2_naive_test() {
+    let v = vec![
+        String::from("00100"),
+        String::from("11110"),
+        String::from("10110"),
+        String::from("10111"),
+        String::from("10101"),
+        String::from("01111"),
+        String::from("00111"),
+        String::from("11100"),
+        String::from("10000"),
+        String::from("11001"),
+        String::from("00010"),
+        String::from("01010"),
+    ];
+    let answer = part2_o2_co2_naive(v.into_iter());
+
+    assert_eq!(Some(230), answer.to_u128());
+}
+
+#[test]
+fn day3_wide_test() {
+    // 100 bits: ones then zeros, and the other way round
+    let ones = "1".repeat(50) + &"0".repeat(50);
+    let zeros = "0".repeat(50) + &"1".repeat(50);
+    let lines = vec![ones.clone(), ones.clone(), zeros.clone()];
+
+    let (gamma, epsilon) = (Bits::parse(&ones).unwrap(), Bits::parse(&zeros).unwrap());
+    assert_eq!(
+        &gamma * &epsilon,
+        part1_gamma_epsilon(lines.iter().cloned())
+    );
+    assert_eq!(
+        "1427247692705957345757085512991818042883178496",
+        (&gamma * &epsilon).to_string()
+    );
+    assert_eq!(
+        part2_o2_co2_naive(lines.iter().cloned()),
+        part2_o2_co2(lines.iter().cloned())
+    );
+    assert_eq!(
+        None,
+        Day03::part2(&lines)
+            .unwrap()
+            .to_string()
+            .parse::<u128>()
+            .ok()
+    );
+}
+
+#[test]
+fn bits_test() {
+    assert_eq!(Some(0), Bits::default().to_u128());
+    assert_eq!("0", Bits::default().to_string());
+    assert_eq!(Ok(Some(0b1011)), Bits::parse("001011").map(|b| b.to_u128()));
+    assert_eq!(Ok(Bits::default()), Bits::parse(""));
+    assert!(Bits::parse("0120").is_err());
+
+    let max = Bits::parse(&"1".repeat(128)).unwrap();
+    assert_eq!(Some(u128::MAX), max.to_u128());
+    assert_eq!(u128::MAX.to_string(), max.to_string());
+    let square = &max * &max;
+    assert_eq!(None, square.to_u128());
+    assert_eq!(
+        "115792089237316195423570985008687907852589419931798687112530834793049593217025",
+        square.to_string()
+    );
+    assert_eq!(Some(0), (&max * &Bits::default()).to_u128());
+
+    let mut bits = Bits::default();
+    bits.set(64);
+    assert_eq!("18446744073709551616", bits.to_string());
+}
+
+#[test]
+fn day3_parse_test() {
+    let parse = |s: &str| Day03::parse(s.lines().map(String::from));
+
+    assert_eq!(Ok(2), parse("0101\n1100").map(|lines| lines.len()));
+    assert_eq!(
+        Err(ParseError::new(
+            2,
+            3,
+            "2 more bits, as line 1 is 4 bits wide"
+        )),
+        parse("0101\n11")
+    );
+    assert_eq!(
+        Err(ParseError::new(
+            3,
+            5,
+            "the end of the line, as line 1 is 4 bits wide"
+        )),
+        parse("0101\n1100\n11001")
+    );
+    assert!(parse("0101\n\n1100").is_err());
+    assert!(parse("0121").is_err());
+}
+
+/// Random numbers of `width` bits
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let lines = params.at_least("lines", 1000, 1);
+    let width = params.at_least("width", 12, 1);
+    let mut out = String::new();
+    for _ in 0..lines {
+        // 64 bits at a time, so numbers up to 64 bits take one draw each
+        let mut left = width;
+        while left > 0 {
+            let bits = rng.next_u64();
+            for i in (0..left.min(64)).rev() {
+                out.push(if bits >> i & 1 == 1 { '1' } else { '0' });
+            }
+            left -= left.min(64);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn day3_generate_test() {
+    let params = Params::parse(&[String::from("lines=100"), String::from("width=20")]).unwrap();
+    let input = generate(&mut Rng::new(3), &params);
+    let lines = Day03::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(20, lines[0].len());
+    Day03::part1(&lines).unwrap();
+    Day03::part2(&lines).unwrap();
+
+    let params = Params::parse(&[String::from("lines=10"), String::from("width=150")]).unwrap();
+    let input = generate(&mut Rng::new(3), &params);
+    let lines = Day03::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(150, lines[0].len());
+}
@@ -0,0 +1,36 @@
+use aoc_core::{Answer, Solution};
+use day03::{Bits, Day03};
+
+const EXAMPLE: &str =
+    "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+#[test]
+fn bits_test() {
+    let bits = Bits::parse("10110").unwrap();
+    assert_eq!("22", bits.to_string());
+    assert_eq!(Some(22), bits.to_u128());
+    assert_eq!(Some(0), Bits::parse("000").unwrap().to_u128());
+    assert!(Bits::parse("000").unwrap().is_zero());
+    assert!(Bits::parse("102").is_err());
+
+    let mut wide = Bits::parse("1").unwrap();
+    wide.set(127);
+    assert_eq!(Some((1 << 127) | 1), wide.to_u128());
+    wide.set(128);
+    assert_eq!(None, wide.to_u128());
+    // Written out in decimal all the same
+    assert_eq!("510423550381407695195061911147652317185", wide.to_string());
+
+    let product = &Bits::parse("101").unwrap() * &Bits::parse("11").unwrap();
+    assert_eq!(Answer::from(15), Answer::from(product));
+}
+
+#[test]
+fn parts_test() {
+    let report = Day03::parse(EXAMPLE.lines().map(String::from)).unwrap();
+    assert_eq!(198, Day03::part1(&report).unwrap());
+    assert_eq!(230, Day03::part2(&report).unwrap());
+    for (name, part) in Day03::alternatives(2) {
+        assert_eq!(Ok(Answer::from(230)), part(&report), "{}", name);
+    }
+}
@@ -0,0 +1,333 @@
+//! Day 4: Giant Squid. [`Bingo`] plays the drawn numbers against every board
+//! and scores them in the order they win.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// Scores the first board to win, then the last
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Bingo;
+    const DAY: u8 = 4;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Bingo, ParseError> {
+        Bingo::from(v)
+    }
+
+    fn part1(bingo: &Bingo) -> Result<Answer, String> {
+        let mut boards = bingo.boards.clone();
+
+        for &num in bingo.numbers.iter() {
+            for board in boards.iter_mut() {
+                board.stamp(num);
+                if board.check() {
+                    let unstamped = board.unstamped();
+                    return Ok((unstamped * num as i128).into());
+                }
+            }
+        }
+
+        Ok(0.into())
+    }
+
+    fn part2(bingo: &Bingo) -> Result<Answer, String> {
+        let mut boards = bingo.boards.clone();
+        let mut matches = 0;
+        let total = boards.len();
+
+        for &num in bingo.numbers.iter() {
+            for board in boards.iter_mut() {
+                board.stamp(num);
+                if !board.has_match && board.check() {
+                    board.mark();
+                    matches += 1;
+                    if matches == total {
+                        let unstamped = board.unstamped();
+                        return Ok((unstamped * num as i128).into());
+                    }
+                }
+            }
+        }
+
+        Ok(0.into())
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Space {
+    num: i32,
+    marked: bool,
+}
+
+impl Space {
+    fn new(num: i32) -> Space {
+        Space { num, marked: false }
+    }
+    fn mark(&mut self) {
+        self.marked = true;
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Board {
+    rows: [[Space; 5]; 5],
+    cols: [[Space; 5]; 5],
+    has_match: bool,
+}
+
+impl Board {
+    fn new(nums: Vec<i32>) -> Board {
+        let rows = (0..5)
+            .map(|r| {
+                [
+                    Space::new(nums[r * 5]),
+                    Space::new(nums[r * 5 + 1]),
+                    Space::new(nums[r * 5 + 2]),
+                    Space::new(nums[r * 5 + 3]),
+                    Space::new(nums[r * 5 + 4]),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let cols = (0..5)
+            .map(|c| {
+                [
+                    Space::new(nums[c]),
+                    Space::new(nums[5 + c]),
+                    Space::new(nums[10 + c]),
+                    Space::new(nums[15 + c]),
+                    Space::new(nums[20 + c]),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        Board {
+            rows: [rows[0], rows[1], rows[2], rows[3], rows[4]],
+            cols: [cols[0], cols[1], cols[2], cols[3], cols[4]],
+            has_match: false,
+        }
+    }
+
+    fn stamp(&mut self, num: i32) {
+        for row in self.rows.iter_mut() {
+            for space in row {
+                if space.num == num {
+                    space.mark();
+                }
+            }
+        }
+        for col in self.cols.iter_mut() {
+            for space in col {
+                if space.num == num {
+                    space.mark();
+                }
+            }
+        }
+    }
+
+    fn check(self) -> bool {
+        for row in self.rows.iter() {
+            if row.iter().all(|x| x.marked) {
+                return true;
+            }
+        }
+        for col in self.cols.iter() {
+            if col.iter().all(|x| x.marked) {
+                return true;
+            }
+        }
+        false
+    }
+
+    // Wide enough that multiplying it by any number drawn can't overflow
+    fn unstamped(self) -> i128 {
+        self.rows
+            .iter()
+            .flat_map(|r| {
+                r.iter()
+                    .filter_map(|x| if !x.marked { Some(x.num as i128) } else { None })
+            })
+            .sum()
+    }
+
+    fn mark(&mut self) {
+        self.has_match = true;
+    }
+}
+
+/// The numbers to draw, in order, and the boards they're called against
+pub struct Bingo {
+    numbers: Vec<i32>,
+    boards: Vec<Board>,
+}
+
+impl Bingo {
+    fn from(v: impl Iterator<Item = String>) -> Result<Bingo, ParseError> {
+        let mut boards: Vec<Board> = vec![];
+        let mut lines = v.enumerate().map(|(i, line)| (i + 1, line));
+
+        let first_line = lines.next().map(|(_, line)| line).unwrap_or_default();
+        let mut c = Cursor::new(1, &first_line);
+        let numbers = c.separated(",", |c| c.number())?;
+        c.end()?;
+
+        let mut board_nums: Vec<i32> = vec![];
+        let mut last_line = 1;
+
+        for (i, line) in lines {
+            last_line = i;
+            if line.is_empty() {
+                if !board_nums.is_empty() {
+                    return Err(ParseError::new(i, 1, "5 rows per board"));
+                }
+                continue;
+            }
+
+            let mut c = Cursor::new(i, &line);
+            for _ in 0..5 {
+                c.skip_spaces();
+                board_nums.push(c.number()?);
+            }
+            c.end()?;
+
+            if board_nums.len() == 25 {
+                boards.push(Board::new(board_nums));
+                board_nums = vec![];
+            }
+        }
+
+        if !board_nums.is_empty() {
+            return Err(ParseError::new(last_line + 1, 1, "5 rows per board"));
+        }
+
+        Ok(Bingo { numbers, boards })
+    }
+}
+
+#[test]
+fn day4_part1_test() {
+    let v = vec![
+        String::from("7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1"),
+        String::from(""),
+        String::from("22 13 17 11  0"),
+        String::from(" 8  2 23  4 24"),
+        String::from("21  9 14 16  7"),
+        String::from(" 6 10  3 18  5"),
+        String::from(" 1 12 20 15 19"),
+        String::from(""),
+        String::from(" 3 15  0  2 22"),
+        String::from(" 9 18 13 17  5"),
+        String::from("19  8  7 25 23"),
+        String::from("20 11 10 24  4"),
+        String::from("14 21 16 12  6"),
+        String::from(""),
+        String::from("14 21 17 24  4"),
+        String::from("10 16 15  9 19"),
+        String::from("18  8 23 26 20"),
+        String::from("22 11 13  6  5"),
+        String::from(" 2  0 12  3  7"),
+    ];
+    let answer = Day04::part1(&Day04::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(4512, answer);
+}
+
+#[test]
+fn day4_part2_test() {
+    let v = vec![
+        String::from("7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1"),
+        String::from(""),
+        String::from("22 13 17 11  0"),
+        String::from(" 8  2 23  4 24"),
+        String::from("21  9 14 16  7"),
+        String::from(" 6 10  3 18  5"),
+        String::from(" 1 12 20 15 19"),
+        String::from(""),
+        String::from(" 3 15  0  2 22"),
+        String::from(" 9 18 13 17  5"),
+        String::from("19  8  7 25 23"),
+        String::from("20 11 10 24  4"),
+        String::from("14 21 16 12  6"),
+        String::from(""),
+        String::from("14 21 17 24  4"),
+        String::from("10 16 15  9 19"),
+        String::from("18  8 23 26 20"),
+        String::from("22 11 13  6  5"),
+        String::from(" 2  0 12  3  7"),
+    ];
+    let answer = Day04::part2(&Day04::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(1924, answer);
+}
+
+#[test]
+fn day4_wide_test() {
+    // Scores that no longer fit in an i32, or even an i64
+    let board = |rows: [&str; 5]| rows.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+    let v = [
+        vec![String::from("1,2,3,4,7"), String::new()],
+        board([
+            "1 2 3 4 7",
+            "400000000 0 0 0 0",
+            "0 0 0 0 0",
+            "0 0 0 0 0",
+            "0 0 0 0 0",
+        ]),
+    ]
+    .concat();
+    let bingo = Day04::parse(v.into_iter()).unwrap();
+    assert_eq!(2800000000_i64, Day04::part1(&bingo).unwrap());
+    assert_eq!(2800000000_i64, Day04::part2(&bingo).unwrap());
+
+    let min = "-2147483648 ".repeat(5);
+    let max = "2147483647 ".repeat(5);
+    let v = [
+        vec![String::from("-2147483648"), String::new()],
+        board([min.trim(), max.trim(), max.trim(), max.trim(), max.trim()]),
+    ]
+    .concat();
+    let bingo = Day04::parse(v.into_iter()).unwrap();
+    assert_eq!(-92233720325598085120_i128, Day04::part1(&bingo).unwrap());
+}
+
+/// Every number from 0 to `numbers - 1` is drawn, so every board wins
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let boards = params.at_least("boards", 100, 1);
+    let mut numbers = (0..params.at_least("numbers", 100, 25)).collect::<Vec<_>>();
+    let width = (numbers.len() - 1).to_string().len();
+
+    rng.shuffle(&mut numbers);
+    let mut out = numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    out.push('\n');
+
+    for _ in 0..boards {
+        rng.shuffle(&mut numbers);
+        out.push('\n');
+        for row in numbers[..25].chunks(5) {
+            let row = row
+                .iter()
+                .map(|n| format!("{:>1$}", n, width))
+                .collect::<Vec<_>>();
+            out += &row.join(" ");
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[test]
+fn day4_generate_test() {
+    let params = Params::parse(&[String::from("boards=20"), String::from("numbers=150")]).unwrap();
+    let input = generate(&mut Rng::new(4), &params);
+    let bingo = Day04::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(20, bingo.boards.len());
+    assert_eq!(150, bingo.numbers.len());
+    assert_ne!(0, Day04::part2(&bingo).unwrap());
+}
@@ -0,0 +1,50 @@
+use aoc_core::{ParseError, Solution};
+use day04::{Bingo, Day04};
+
+const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+fn bingo(input: &str) -> Result<Bingo, ParseError> {
+    Day04::parse(input.lines().map(String::from))
+}
+
+#[test]
+fn score_test() {
+    let bingo = bingo(EXAMPLE).unwrap();
+    assert_eq!(4512, Day04::part1(&bingo).unwrap());
+    assert_eq!(1924, Day04::part2(&bingo).unwrap());
+}
+
+#[test]
+fn column_test() {
+    // Only the first column is ever complete, and 2 is marked on the way
+    let board = "1 2 3 4 5\n6 0 0 0 0\n7 0 0 0 0\n8 0 0 0 0\n9 0 0 0 0";
+    let bingo = bingo(&format!("1,6,7,8,2,9\n\n{}", board)).unwrap();
+    assert_eq!((3 + 4 + 5) * 9, Day04::part1(&bingo).unwrap());
+}
+
+#[test]
+fn parse_test() {
+    let short = EXAMPLE.lines().take(6).collect::<Vec<_>>().join("\n");
+    assert_eq!(
+        Some(ParseError::new(7, 1, "5 rows per board")),
+        bingo(&(short + "\n\n")).err()
+    );
+}
@@ -0,0 +1,256 @@
+//! Day 5: Hydrothermal Venture. Each vent [`Line`] iterates over the points
+//! it covers, and the solver counts where lines overlap.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, Grid, ParseError, Solution,
+};
+use std::collections::HashMap;
+
+/// Counts the points covered by two or more vents, first without diagonals
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Line>;
+    const DAY: u8 = 5;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<Line>, ParseError> {
+        v.enumerate()
+            .map(|(i, line)| Line::from(&mut Cursor::new(i + 1, &line)))
+            .collect()
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<Answer, String> {
+        Ok(count_overlaps(straight(lines)).into())
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<Answer, String> {
+        Ok(count_overlaps(lines.iter()).into())
+    }
+
+    /// How many vents cover each point
+    fn picture(lines: &Vec<Line>, part: u8) -> Option<Grid<usize>> {
+        let map = match part {
+            1 => vent_map(straight(lines)),
+            _ => vent_map(lines.iter()),
+        };
+        let width = map.keys().map(|&(x, _)| x + 1).max().unwrap_or(0);
+        let height = map.keys().map(|&(_, y)| y + 1).max().unwrap_or(0);
+        let mut grid = Grid::new(width.max(0) as usize, height.max(0) as usize, 0);
+        for (&(x, y), &count) in map.iter().filter(|(&(x, y), _)| x >= 0 && y >= 0) {
+            grid[(x as usize, y as usize)] = count as usize;
+        }
+        Some(grid)
+    }
+}
+
+// Part 1 only considers horizontal and vertical lines
+fn straight(lines: &[Line]) -> impl Iterator<Item = &Line> {
+    lines
+        .iter()
+        .filter(|line| line.x1 == line.x2 || line.y1 == line.y2)
+}
+
+/// A line of vents from one end to the other, inclusive. Lines are
+/// horizontal, vertical or at 45 degrees.
+#[derive(Clone, Copy, Debug)]
+pub struct Line {
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+}
+
+impl IntoIterator for Line {
+    type Item = (i32, i32);
+    type IntoIter = LineIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let x_step = (self.x2 - self.x1).signum();
+        let y_step = (self.y2 - self.y1).signum();
+
+        LineIntoIter {
+            x: self.x1,
+            y: self.y1,
+            x_max: self.x2,
+            y_max: self.y2,
+            x_step,
+            y_step,
+            started: false,
+        }
+    }
+}
+
+/// Each point on a [`Line`], starting from its first end
+pub struct LineIntoIter {
+    x: i32,
+    y: i32,
+    x_max: i32,
+    y_max: i32,
+    x_step: i32,
+    y_step: i32,
+    /// Whether the first end has been given, which is also the last for a
+    /// line only one point long
+    started: bool,
+}
+
+impl Iterator for LineIntoIter {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some((self.x, self.y));
+        }
+        let x_finished = self.x == self.x_max;
+        let y_finished = self.y == self.y_max;
+
+        if x_finished && y_finished {
+            return None;
+        }
+        if x_finished {
+            self.x_step = 0;
+        }
+        if y_finished {
+            self.y_step = 0;
+        }
+
+        self.x += self.x_step;
+        self.y += self.y_step;
+        Some((self.x, self.y))
+    }
+}
+
+impl Line {
+    fn from(c: &mut Cursor) -> Result<Line, ParseError> {
+        let x1 = c.number()?;
+        c.literal(",")?;
+        let y1 = c.number()?;
+        c.literal(" -> ")?;
+        let x2 = c.number()?;
+        c.literal(",")?;
+        let y2 = c.number()?;
+        c.end()?;
+
+        Ok(Line { x1, y1, x2, y2 })
+    }
+}
+
+fn vent_map<'a>(lines: impl Iterator<Item = &'a Line>) -> HashMap<(i32, i32), u32> {
+    let mut map = HashMap::new();
+
+    for &line in lines {
+        for coord in line {
+            *map.entry(coord).or_insert(0) += 1;
+        }
+    }
+
+    map
+}
+
+fn count_overlaps<'a>(lines: impl Iterator<Item = &'a Line>) -> usize {
+    vent_map(lines)
+        .values()
+        .filter(|&&count| count >= 2)
+        .count()
+}
+
+#[test]
+fn day5_part1_test() {
+    let v = vec![
+        String::from("0,9 -> 5,9"),
+        String::from("8,0 -> 0,8"),
+        String::from("9,4 -> 3,4"),
+        String::from("2,2 -> 2,1"),
+        String::from("7,0 -> 7,4"),
+        String::from("6,4 -> 2,0"),
+        String::from("0,9 -> 2,9"),
+        String::from("3,4 -> 1,4"),
+        String::from("0,0 -> 8,8"),
+        String::from("5,5 -> 8,2"),
+    ];
+    let answer = Day05::part1(&Day05::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(5, answer);
+}
+
+#[test]
+fn day5_part2_test() {
+    let v = vec![
+        String::from("0,9 -> 5,9"),
+        String::from("8,0 -> 0,8"),
+        String::from("9,4 -> 3,4"),
+        String::from("2,2 -> 2,1"),
+        String::from("7,0 -> 7,4"),
+        String::from("6,4 -> 2,0"),
+        String::from("0,9 -> 2,9"),
+        String::from("3,4 -> 1,4"),
+        String::from("0,0 -> 8,8"),
+        String::from("5,5 -> 8,2"),
+    ];
+    let answer = Day05::part2(&Day05::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(12, answer);
+}
+
+#[test]
+fn day5_parse_error_test() {
+    let parse = |s: &str| Day05::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(2, 9, "`,`")),
+        parse("0,9 -> 5,9\n0,9 -> 5")
+    );
+    assert_eq!(Some(ParseError::new(1, 4, "` -> `")), parse("0,9 - 5,9"));
+}
+
+/// Horizontal, vertical and diagonal lines inside a `size` by `size` square
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let lines = params.get("lines", 500);
+    let size = params.at_least("size", 1000, 3) as i64;
+    let directions = [
+        (1, 0),
+        (0, 1),
+        (1, 1),
+        (1, -1),
+        (-1, 0),
+        (0, -1),
+        (-1, -1),
+        (-1, 1),
+    ];
+    // How far a line can go from `at` in direction `d` without leaving the square
+    let room = |at: i64, d: i64| match d {
+        1 => size - 1 - at,
+        -1 => at,
+        _ => size,
+    };
+    let mut out = String::new();
+    for _ in 0..lines {
+        // Try again when starting against the edge the line is heading for
+        let (x1, y1, dx, dy, longest) = loop {
+            let (x1, y1) = (rng.range(0, size - 1), rng.range(0, size - 1));
+            let &(dx, dy) = rng.pick(&directions);
+            let longest = room(x1, dx).min(room(y1, dy)).min(size / 3);
+            if longest > 0 {
+                break (x1, y1, dx, dy, longest);
+            }
+        };
+        let len = rng.range(1, longest);
+        out += &format!("{},{} -> {},{}\n", x1, y1, x1 + dx * len, y1 + dy * len);
+    }
+    out
+}
+
+#[test]
+fn day5_generate_test() {
+    let params = Params::parse(&[String::from("lines=200"), String::from("size=50")]).unwrap();
+    let input = generate(&mut Rng::new(5), &params);
+    let lines = Day05::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(200, lines.len());
+    for line in &lines {
+        for (x, y) in [(line.x1, line.y1), (line.x2, line.y2)] {
+            assert!((0..50).contains(&x) && (0..50).contains(&y));
+        }
+    }
+    assert_ne!(0, Day05::part2(&lines).unwrap());
+}
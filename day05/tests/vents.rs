@@ -0,0 +1,28 @@
+use aoc_core::Solution;
+use day05::{Day05, Line};
+
+fn lines(input: &str) -> Vec<Line> {
+    Day05::parse(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn points_test() {
+    let points = |input| lines(input)[0].into_iter().collect::<Vec<_>>();
+    assert_eq!(vec![(1, 1), (1, 2), (1, 3)], points("1,1 -> 1,3"));
+    assert_eq!(vec![(9, 7), (8, 7), (7, 7)], points("9,7 -> 7,7"));
+    assert_eq!(vec![(5, 5), (6, 4), (7, 3)], points("5,5 -> 7,3"));
+    assert_eq!(vec![(2, 2)], points("2,2 -> 2,2"));
+}
+
+#[test]
+fn overlap_test() {
+    let example = "0,9 -> 5,9\n8,0 -> 0,8\n9,4 -> 3,4\n2,2 -> 2,1\n7,0 -> 7,4\n6,4 -> 2,0\n0,9 -> 2,9\n3,4 -> 1,4\n0,0 -> 8,8\n5,5 -> 8,2";
+    let vents = lines(example);
+    assert_eq!(5, Day05::part1(&vents).unwrap());
+    assert_eq!(12, Day05::part2(&vents).unwrap());
+
+    // Diagonals only count in part 2
+    let crossing = lines("0,0 -> 2,2\n0,2 -> 2,0");
+    assert_eq!(0, Day05::part1(&crossing).unwrap());
+    assert_eq!(1, Day05::part2(&crossing).unwrap());
+}
@@ -0,0 +1,96 @@
+//! Day 6: Lanternfish. Counts fish by their timer rather than one by one, so
+//! the school can grow for hundreds of days.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// Counts the lanternfish after 80 days, then after 256
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+    const DAY: u8 = 6;
+
+    fn parse(mut v: impl Iterator<Item = String>) -> Result<Vec<usize>, ParseError> {
+        let line = v.next().unwrap_or_default();
+        let mut c = Cursor::new(1, &line);
+        let values = c.separated(",", |c| {
+            c.char_map("a timer from 0 to 8", |t| t.to_digit(9).map(|t| t as usize))
+        })?;
+        c.end()?;
+        Ok(values)
+    }
+
+    fn part1(initial: &Vec<usize>) -> Result<Answer, String> {
+        Ok(day6(initial, 80).into())
+    }
+
+    fn part2(initial: &Vec<usize>) -> Result<Answer, String> {
+        Ok(day6(initial, 256).into())
+    }
+}
+
+fn day6(initial: &[usize], days: i64) -> i64 {
+    let mut fish = [0; 9];
+    for &f in initial {
+        fish[f] += 1;
+    }
+
+    for _ in 0..days {
+        let fish_0 = fish[0];
+        fish.rotate_left(1);
+        fish[6] += fish_0;
+    }
+
+    fish.into_iter().sum()
+}
+
+#[test]
+fn day6_part1_test() {
+    let v = vec![String::from("3,4,3,1,2")];
+    let answer = Day06::part1(&Day06::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(5934, answer);
+}
+
+#[test]
+fn day6_part2_test() {
+    let v = vec![String::from("3,4,3,1,2")];
+    let answer = Day06::part2(&Day06::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(26984457539_i64, answer);
+}
+
+#[test]
+fn day6_parse_error_test() {
+    let parse = |s: &str| Day06::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(1, 3, "a timer from 0 to 8")),
+        parse("3,9")
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 3, "a timer from 0 to 8")),
+        parse("3,,4")
+    );
+    assert_eq!(Some(ParseError::new(1, 2, "end of line")), parse("3 4"));
+}
+
+/// `fish` lanternfish with timers from 1 to 5, like the puzzle's
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let fish = params.at_least("fish", 300, 1);
+    let timers = (0..fish)
+        .map(|_| rng.range(1, 5).to_string())
+        .collect::<Vec<_>>();
+    timers.join(",") + "\n"
+}
+
+#[test]
+fn day6_generate_test() {
+    let params = Params::parse(&[String::from("fish=10")]).unwrap();
+    let input = generate(&mut Rng::new(6), &params);
+    let fish = Day06::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(10, fish.len());
+}
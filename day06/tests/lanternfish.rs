@@ -0,0 +1,27 @@
+use aoc_core::Solution;
+use day06::Day06;
+
+fn school(timers: &str) -> Vec<usize> {
+    Day06::parse([timers].into_iter().map(String::from)).unwrap()
+}
+
+#[test]
+fn count_test() {
+    let example = school("3,4,3,1,2");
+    assert_eq!(5934, Day06::part1(&example).unwrap());
+    assert_eq!(26984457539_i64, Day06::part2(&example).unwrap());
+}
+
+#[test]
+fn school_test() {
+    // Each fish's family grows on its own, so a school is the sum of them
+    let count = |timers| Day06::part2(&school(timers)).unwrap().to_string();
+    let apart = ["3", "4", "3", "1", "2"]
+        .into_iter()
+        .map(|timer| count(timer).parse::<i64>().unwrap())
+        .sum::<i64>();
+    assert_eq!(apart, Day06::part2(&school("3,4,3,1,2")).unwrap());
+
+    assert_eq!(vec![0, 8], school("0,8"));
+    assert!(Day06::parse(["9"].into_iter().map(String::from)).is_err());
+}
@@ -0,0 +1,102 @@
+//! Day 7: The Treachery of Whales. Finds where the crabs can line up for the
+//! least fuel, with constant and growing costs per step.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// The least fuel to line the crabs up, at a constant then a growing cost
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<i32>;
+    const DAY: u8 = 7;
+
+    fn parse(mut v: impl Iterator<Item = String>) -> Result<Vec<i32>, ParseError> {
+        let line = v.next().unwrap_or_default();
+        let mut c = Cursor::new(1, &line);
+        let values = c.separated(",", |c| c.number())?;
+        c.end()?;
+        Ok(values)
+    }
+
+    fn part1(positions: &Vec<i32>) -> Result<Answer, String> {
+        Ok(min_fuel(positions, |distance| distance).into())
+    }
+
+    fn part2(positions: &Vec<i32>) -> Result<Answer, String> {
+        Ok(min_fuel(positions, |distance| distance * (distance + 1) / 2).into())
+    }
+}
+
+fn min_fuel(positions: &[i32], cost: impl Fn(i64) -> i64) -> i64 {
+    let min_pos = *positions.iter().min().unwrap();
+    let max_pos = *positions.iter().max().unwrap();
+
+    let mut min = i64::MAX;
+
+    for i in min_pos..=max_pos {
+        let fuel = positions
+            .iter()
+            .fold(0, |sum, &p| sum + cost((p as i64 - i as i64).abs()));
+
+        if fuel < min {
+            min = fuel;
+        }
+    }
+
+    min
+}
+
+#[test]
+fn day7_part1_test() {
+    let v = vec![String::from("16,1,2,0,4,2,7,1,2,14")];
+    let answer = Day07::part1(&Day07::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(37, answer);
+}
+
+#[test]
+fn day7_part2_test() {
+    let v = vec![String::from("16,1,2,0,4,2,7,1,2,14")];
+    let answer = Day07::part2(&Day07::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(168, answer);
+}
+
+#[test]
+fn day7_part2_wide_test() {
+    // Fuel for this one no longer fits in an i32
+    let v = vec![String::from("0,100000")];
+    let answer = Day07::part2(&Day07::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(2500050000_i64, answer);
+}
+
+#[test]
+fn day7_parse_error_test() {
+    let parse = |s: &str| Day07::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(Some(ParseError::new(1, 3, "a number")), parse("1,x"));
+    assert_eq!(Some(ParseError::new(1, 6, "a number")), parse("16,1,"));
+}
+
+/// Crab positions from 0 to `max`
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let crabs = params.at_least("crabs", 1000, 1);
+    let max = params.get("max", 2000) as i64;
+    let positions = (0..crabs)
+        .map(|_| rng.range(0, max).to_string())
+        .collect::<Vec<_>>();
+    positions.join(",") + "\n"
+}
+
+#[test]
+fn day7_generate_test() {
+    let params = Params::parse(&[String::from("crabs=20"), String::from("max=100")]).unwrap();
+    let input = generate(&mut Rng::new(7), &params);
+    let positions = Day07::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(20, positions.len());
+    assert!(positions.iter().all(|p| (0..=100).contains(p)));
+}
@@ -0,0 +1,26 @@
+use aoc_core::Solution;
+use day07::Day07;
+
+fn crabs(positions: &str) -> Vec<i32> {
+    Day07::parse([positions].into_iter().map(String::from)).unwrap()
+}
+
+#[test]
+fn fuel_test() {
+    let example = crabs("16,1,2,0,4,2,7,1,2,14");
+    assert_eq!(37, Day07::part1(&example).unwrap());
+    assert_eq!(168, Day07::part2(&example).unwrap());
+}
+
+#[test]
+fn single_crab_test() {
+    // Nobody has to move
+    let alone = crabs("42");
+    assert_eq!(0, Day07::part1(&alone).unwrap());
+    assert_eq!(0, Day07::part2(&alone).unwrap());
+
+    // Meeting in the middle costs 1 + 2 each at the growing rate
+    let pair = crabs("0,4");
+    assert_eq!(4, Day07::part1(&pair).unwrap());
+    assert_eq!(6, Day07::part2(&pair).unwrap());
+}
@@ -0,0 +1,347 @@
+//! Day 8: Seven Segment Search. Works out which wires drive which segments
+//! of each display from its ten unique patterns.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Counts the easy digits in the outputs, then decodes every display
+pub struct Day08;
+
+/// The ten unique signal patterns and the four output digits for one display
+pub type Entry = (Vec<String>, Vec<String>);
+
+impl Solution for Day08 {
+    type Input = Vec<Entry>;
+    const DAY: u8 = 8;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<Entry>, ParseError> {
+        v.enumerate()
+            .map(|(i, line)| parse_line(&mut Cursor::new(i + 1, &line)))
+            .collect()
+    }
+
+    fn part1(entries: &Vec<Entry>) -> Result<Answer, String> {
+        Ok(day8_part1(entries).into())
+    }
+
+    fn part2(entries: &Vec<Entry>) -> Result<Answer, String> {
+        Ok(day8_part2(entries).into())
+    }
+}
+
+fn parse_line(c: &mut Cursor) -> Result<Entry, ParseError> {
+    let patterns = |c: &mut Cursor, count| {
+        let mut patterns = vec![];
+        for i in 0..count {
+            if i > 0 {
+                c.literal(" ")?;
+            }
+            let start = c.error("each segment at most once");
+            match c.take_while(|s| ('a'..='g').contains(&s)) {
+                "" => return Err(c.error("segments a to g")),
+                pattern if sorted(pattern).windows(2).any(|w| w[0] == w[1]) => return Err(start),
+                pattern => patterns.push((start.column, pattern.to_string())),
+            }
+        }
+        Ok(patterns)
+    };
+
+    let line = c.line();
+    let input = patterns(c, 10)?;
+    c.literal(" | ")?;
+    let output = patterns(c, 4)?;
+    c.end()?;
+
+    // Part 2 works out the wiring, which it can only do for the ten digits
+    let input = input.into_iter().map(|(_, p)| p).collect::<Vec<_>>();
+    if !is_wiring(&input) {
+        return Err(ParseError::new(
+            line,
+            1,
+            "the patterns of the digits 0 to 9",
+        ));
+    }
+    for (column, pattern) in &output {
+        if !input.iter().any(|p| sorted(p) == sorted(pattern)) {
+            return Err(ParseError::new(line, *column, "one of the ten patterns"));
+        }
+    }
+
+    Ok((input, output.into_iter().map(|(_, p)| p).collect()))
+}
+
+fn sorted(pattern: &str) -> Vec<char> {
+    let mut segments = pattern.chars().collect::<Vec<_>>();
+    segments.sort();
+    segments
+}
+
+// Each segment is in a different set of digits, which the lengths of those
+// digits are enough to tell apart. The patterns are the ten digits exactly
+// when rewiring them by that gives back `DIGITS`.
+fn is_wiring(patterns: &[String]) -> bool {
+    let lengths = |patterns: &[&str], segment: char| {
+        let mut lengths = patterns
+            .iter()
+            .filter(|p| p.contains(segment))
+            .map(|p| p.len())
+            .collect::<Vec<_>>();
+        lengths.sort();
+        lengths
+    };
+    let patterns = patterns.iter().map(String::as_str).collect::<Vec<_>>();
+    let intended = ('a'..='g')
+        .map(|segment| (lengths(&DIGITS, segment), segment))
+        .collect::<HashMap<_, _>>();
+
+    let rewired = patterns
+        .iter()
+        .map(|p| {
+            let segments = p
+                .chars()
+                .map(|wire| intended.get(&lengths(&patterns, wire)).copied())
+                .collect::<Option<String>>()?;
+            Some(sorted(&segments))
+        })
+        .collect::<Option<HashSet<_>>>();
+    rewired == Some(DIGITS.iter().map(|d| sorted(d)).collect())
+}
+
+fn day8_part1(entries: &[Entry]) -> i32 {
+    let length_to_segments: HashMap<usize, usize> = HashMap::from([(2, 1), (4, 4), (3, 7), (7, 8)]);
+
+    let mut sum = 0;
+
+    for (_, output) in entries {
+        for val in output {
+            if length_to_segments.contains_key(&val.len()) {
+                sum += 1;
+            }
+        }
+    }
+
+    sum
+}
+
+#[test]
+fn day8_part1_test() {
+    let v = vec![
+        String::from("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe"),
+        String::from("edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc"),
+        String::from("fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg"),
+        String::from("fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb"),
+        String::from("aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea"),
+        String::from("fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb"),
+        String::from("dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe"),
+        String::from("bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef"),
+        String::from("egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb"),
+        String::from("gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"),
+    ];
+    let answer = Day08::part1(&Day08::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(26, answer);
+}
+
+fn day8_part2(entries: &[Entry]) -> i32 {
+    let length_to_segments: HashMap<usize, usize> = HashMap::from([(2, 1), (4, 4), (3, 7), (7, 8)]);
+
+    let mut sum = 0;
+
+    for (input, output) in entries {
+        let (knowns, unknowns): (Vec<String>, Vec<String>) = input
+            .iter()
+            .cloned()
+            .partition(|x| length_to_segments.contains_key(&x.len()));
+
+        let mut decoded: [String; 10] = [
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+        ];
+
+        decoded[1] = knowns.iter().find(|x| x.len() == 2).unwrap().to_string();
+        let one = HashSet::from_iter(decoded[1].chars());
+
+        decoded[4] = knowns.iter().find(|x| x.len() == 4).unwrap().to_string();
+        decoded[7] = knowns.iter().find(|x| x.len() == 3).unwrap().to_string();
+        decoded[8] = knowns.iter().find(|x| x.len() == 7).unwrap().to_string();
+
+        decoded[3] = unknowns
+            .iter()
+            .filter(|x| x.len() == 5)
+            .find(|x| decoded[1].chars().all(|c| x.contains(c)))
+            .unwrap()
+            .to_string();
+
+        let four = HashSet::from_iter(decoded[4].chars());
+
+        let (nines, zero_sixes): (Vec<&String>, Vec<&String>) =
+            unknowns.iter().filter(|x| x.len() == 6).partition(|x| {
+                let candidate = HashSet::from_iter(x.chars());
+                let union: HashSet<_> = candidate.union(&four).cloned().collect();
+                union.is_subset(&candidate)
+            });
+
+        assert!(nines.len() == 1);
+        assert!(zero_sixes.len() == 2);
+
+        decoded[9] = nines.first().unwrap().to_string();
+
+        let (threes, two_fives): (Vec<&String>, Vec<&String>) =
+            unknowns.iter().filter(|x| x.len() == 5).partition(|x| {
+                let candidate = HashSet::from_iter(x.chars());
+                let union: HashSet<_> = candidate.union(&one).cloned().collect();
+                union.is_subset(&candidate)
+            });
+
+        assert!(threes.len() == 1);
+        assert!(two_fives.len() == 2);
+
+        decoded[3] = threes.first().unwrap().to_string();
+
+        let nine: HashSet<char> = HashSet::from_iter(decoded[9].chars());
+        let eight: HashSet<char> = HashSet::from_iter(decoded[8].chars());
+        let ee = eight.difference(&nine).cloned().collect();
+
+        let (twos, fives): (Vec<&String>, Vec<&String>) = two_fives.iter().partition(|x| {
+            let candidate = HashSet::from_iter(x.chars());
+            let union: HashSet<_> = candidate.union(&ee).cloned().collect();
+            union.is_subset(&candidate)
+        });
+
+        assert!(twos.len() == 1);
+        assert!(fives.len() == 1);
+
+        decoded[2] = twos.first().unwrap().to_string();
+        decoded[5] = fives.first().unwrap().to_string();
+
+        let five: HashSet<char> = HashSet::from_iter(decoded[5].chars());
+        let cc = nine.difference(&five).cloned().collect();
+
+        let (zeros, sixes): (Vec<&String>, Vec<&String>) = zero_sixes.iter().partition(|x| {
+            let candidate = HashSet::from_iter(x.chars());
+            let union: HashSet<_> = candidate.union(&cc).cloned().collect();
+            union.is_subset(&candidate)
+        });
+
+        assert!(zeros.len() == 1);
+        assert!(sixes.len() == 1);
+
+        decoded[0] = zeros.first().unwrap().to_string();
+        decoded[6] = sixes.first().unwrap().to_string();
+
+        let mut out_str = String::new();
+        for digit in output {
+            let digit_hash: HashSet<char> = HashSet::from_iter(digit.chars());
+
+            let i = decoded
+                .iter()
+                .position(|x| {
+                    let x_hash: HashSet<char> = HashSet::from_iter(x.chars());
+                    x_hash.is_subset(&digit_hash) && x_hash.is_superset(&digit_hash)
+                })
+                .unwrap();
+
+            out_str.push_str(&i.to_string());
+        }
+
+        sum += out_str.parse::<i32>().unwrap();
+    }
+
+    sum
+}
+
+#[test]
+fn day8_part2_test() {
+    let v = vec![
+        String::from("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe"),
+        String::from("edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc"),
+        String::from("fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg"),
+        String::from("fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb"),
+        String::from("aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea"),
+        String::from("fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb"),
+        String::from("dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe"),
+        String::from("bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef"),
+        String::from("egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb"),
+        String::from("gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"),
+    ];
+    let answer = Day08::part2(&Day08::parse(v.into_iter()).unwrap()).unwrap();
+
+    //assert_eq!(8394, answer);
+    assert_eq!(61229, answer);
+}
+
+#[test]
+fn day8_parse_error_test() {
+    let parse = |s: &str| Day08::parse(vec![String::from(s)].into_iter()).err();
+    let line = "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd";
+
+    assert_eq!(
+        Some(ParseError::new(1, 83, "one of the ten patterns")),
+        parse(&format!("{} gcb", line))
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 1, "the patterns of the digits 0 to 9")),
+        parse(&format!("bg{} gcbe", &line[2..]))
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 4, "each segment at most once")),
+        parse(&format!("be cfbegaa{} gcbe", &line[10..]))
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 83, "segments a to g")),
+        parse(&format!("{} ", line))
+    );
+}
+
+// Segments lit for each digit on a correctly wired display
+const DIGITS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+/// Displays with randomly crossed wires, listing the patterns and segments in
+/// random order
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let displays = params.get("displays", 200);
+    let mut out = String::new();
+    for _ in 0..displays {
+        let mut wires = "abcdefg".chars().collect::<Vec<_>>();
+        rng.shuffle(&mut wires);
+        let mut digits = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut digits);
+        let outputs = (0..4).map(|_| rng.below(10)).collect::<Vec<_>>();
+
+        let mut pattern = |digit: &usize| {
+            let mut segments = DIGITS[*digit]
+                .chars()
+                .map(|s| wires[s as usize - 'a' as usize])
+                .collect::<Vec<_>>();
+            rng.shuffle(&mut segments);
+            segments.into_iter().collect::<String>()
+        };
+        let patterns = digits.iter().map(&mut pattern).collect::<Vec<_>>();
+        let outputs = outputs.iter().map(&mut pattern).collect::<Vec<_>>();
+        out += &format!("{} | {}\n", patterns.join(" "), outputs.join(" "));
+    }
+    out
+}
+
+#[test]
+fn day8_generate_test() {
+    let params = Params::parse(&[String::from("displays=20")]).unwrap();
+    let input = generate(&mut Rng::new(8), &params);
+    let entries = Day08::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(20, entries.len());
+    Day08::part2(&entries).unwrap();
+}
@@ -0,0 +1,31 @@
+use aoc_core::{ParseError, Solution};
+use day08::{Day08, Entry};
+
+fn entries(input: &str) -> Result<Vec<Entry>, ParseError> {
+    Day08::parse(input.lines().map(String::from))
+}
+
+// The wires all in their usual places
+const PATTERNS: &str = "abcefg cf acdeg acdfg bcdf abdfg abdefg acf abcdefg abcdfg";
+
+#[test]
+fn decode_test() {
+    let input = format!("{} | cf acdfg bcdf abcdefg", PATTERNS);
+    let displays = entries(&input).unwrap();
+    assert_eq!(3, Day08::part1(&displays).unwrap());
+    assert_eq!(1348, Day08::part2(&displays).unwrap());
+
+    // The same display with the wires swapped round and the segments of each
+    // pattern in any order
+    let swap = |text: &str| text.replace('a', "A").replace('g', "a").replace('A', "g");
+    let input = format!("{} | fc gfdca fcdb gfedcba", swap(PATTERNS));
+    assert_eq!(1348, Day08::part2(&entries(&input).unwrap()).unwrap());
+}
+
+#[test]
+fn parse_test() {
+    let input = format!("{} | cf acdfg bcdf", PATTERNS);
+    assert!(entries(&input).is_err());
+    let input = format!("{} | cf acdfg bcdf abcdefh", PATTERNS);
+    assert!(entries(&input).is_err());
+}
@@ -0,0 +1,146 @@
+//! Day 9: Smoke Basin. Finds the low points of a [`HeightMap`] and the basins
+//! that drain into them.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Grid, ParseError, Pos, Solution,
+};
+use std::collections::HashSet;
+
+/// Sums the low points' risk levels, then multiplies the three largest basins
+pub struct Day09;
+
+/// The height of each location, from 0 to 9
+pub type HeightMap = Grid<u32>;
+
+impl Solution for Day09 {
+    type Input = HeightMap;
+    const DAY: u8 = 9;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<HeightMap, ParseError> {
+        Grid::parse_digits(v, "a height from 0 to 9")
+    }
+
+    fn part1(map: &HeightMap) -> Result<Answer, String> {
+        let low_points = find_low_points(map);
+
+        Ok(low_points
+            .iter()
+            .fold(0i64, |acc, &pos| acc + map[pos] as i64 + 1)
+            .into())
+    }
+
+    fn part2(map: &HeightMap) -> Result<Answer, String> {
+        let low_points = find_low_points(map);
+
+        let mut basins = low_points
+            .iter()
+            .map(|&pos| {
+                let mut basin = HashSet::new();
+                walk_basin(&mut basin, map, pos);
+                basin.len() as u64
+            })
+            .collect::<Vec<_>>();
+
+        basins.sort();
+        Ok(basins.iter().rev().take(3).product::<u64>().into())
+    }
+
+    /// The low points for part 1, or every basin in its own colour for part 2
+    fn picture(map: &HeightMap, part: u8) -> Option<Grid<usize>> {
+        let mut picture = map.map(|_| 0);
+        for (i, &low) in find_low_points(map).iter().enumerate() {
+            if part == 1 {
+                picture[low] = 1;
+                continue;
+            }
+            let mut basin = HashSet::new();
+            walk_basin(&mut basin, map, low);
+            for pos in basin {
+                picture[pos] = i + 1;
+            }
+        }
+        Some(picture)
+    }
+}
+
+fn find_low_points(map: &HeightMap) -> Vec<Pos> {
+    map.cells()
+        .filter(|&(pos, height)| map.neighbours4(pos).all(|n| map[n] >= *height))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+#[test]
+fn day9_part1_test() {
+    let v = vec![
+        String::from("2199943210"),
+        String::from("3987894921"),
+        String::from("9856789892"),
+        String::from("8767896789"),
+        String::from("9899965678"),
+    ];
+    let answer = Day09::part1(&Day09::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(15, answer);
+}
+
+fn walk_basin(basin: &mut HashSet<Pos>, map: &HeightMap, pos: Pos) {
+    basin.insert(pos);
+
+    for next in map.neighbours4(pos) {
+        if map[next] < 9 && !basin.contains(&next) {
+            walk_basin(basin, map, next);
+        }
+    }
+}
+
+#[test]
+fn day9_part2_test() {
+    let v = vec![
+        String::from("2199943210"),
+        String::from("3987894921"),
+        String::from("9856789892"),
+        String::from("8767896789"),
+        String::from("9899965678"),
+    ];
+    let answer = Day09::part2(&Day09::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(1134, answer);
+}
+
+#[test]
+fn day9_parse_error_test() {
+    let parse = |s: &str| Day09::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(2, 2, "a height from 0 to 9")),
+        parse("12\n1a")
+    );
+    assert_eq!(Some(ParseError::new(2, 3, "end of line")), parse("12\n123"));
+}
+
+/// Random heights, with `walls` percent of them 9 so basins stay apart
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let width = params.at_least("width", 100, 1);
+    let height = params.at_least("height", 100, 1);
+    let walls = params.get("walls", 45);
+    let mut out = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            let h = if rng.chance(walls) { 9 } else { rng.below(9) };
+            out += &h.to_string();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn day9_generate_test() {
+    let params = Params::parse(&[String::from("width=30"), String::from("height=20")]).unwrap();
+    let input = generate(&mut Rng::new(9), &params);
+    let map = Day09::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!((30, 20), (map.width(), map.height()));
+    assert_ne!(0, Day09::part2(&map).unwrap());
+}
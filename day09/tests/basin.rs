@@ -0,0 +1,23 @@
+use aoc_core::Solution;
+use day09::{Day09, HeightMap};
+
+fn map(input: &str) -> HeightMap {
+    Day09::parse(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn example_test() {
+    let example = map("2199943210\n3987894921\n9856789892\n8767896789\n9899965678");
+    assert_eq!((10, 5), (example.width(), example.height()));
+    assert_eq!(15, Day09::part1(&example).unwrap());
+    assert_eq!(1134, Day09::part2(&example).unwrap());
+}
+
+#[test]
+fn walls_test() {
+    // Five low points split by walls of 9, and only the top left one's basin
+    // reaches past it
+    let map = map("0195\n1949\n9390");
+    assert_eq!(1 + 6 + 5 + 4 + 1, Day09::part1(&map).unwrap());
+    assert_eq!(3, Day09::part2(&map).unwrap());
+}
@@ -0,0 +1,247 @@
+//! Day 10: Syntax Scoring. Scores lines of brackets that are corrupted, and
+//! completes the ones that are only unfinished.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+use std::collections::HashMap;
+
+/// Scores the corrupted lines, then the middle completion score
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    const DAY: u8 = 10;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<String>, ParseError> {
+        v.enumerate()
+            .map(|(i, line)| {
+                // A closing character is only corrupt if it has a chunk to close
+                let mut c = Cursor::new(i + 1, &line);
+                let mut open = 0;
+                while !c.is_empty() {
+                    let error = c.error("an opening character");
+                    match c.one_of(&["(", ")", "[", "]", "{", "}", "<", ">"])? {
+                        "(" | "[" | "{" | "<" => open += 1,
+                        _ if open == 0 => return Err(error),
+                        _ => open -= 1,
+                    }
+                }
+                Ok(line)
+            })
+            .collect()
+    }
+
+    fn part1(lines: &Vec<String>) -> Result<Answer, String> {
+        Ok(day10_part1(lines.iter().cloned()).into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Result<Answer, String> {
+        day10_part2(lines.iter().cloned()).map(Answer::from)
+    }
+}
+
+fn day10_part1(v: impl Iterator<Item = String>) -> i32 {
+    let start_pairs = HashMap::from([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]);
+    let end_pairs = HashMap::from([(')', '('), (']', '['), ('}', '{'), ('>', '<')]);
+    let points = HashMap::from([(')', 3), (']', 57), ('}', 1197), ('>', 25137)]);
+
+    let mut sum = 0;
+
+    'outer: for line in v {
+        let mut stack = vec![];
+
+        for c in line.chars() {
+            if start_pairs.contains_key(&c) {
+                stack.push(c);
+            } else if end_pairs.contains_key(&c)
+                && stack.pop().unwrap() != *end_pairs.get(&c).unwrap()
+            {
+                sum += points.get(&c).unwrap();
+                continue 'outer;
+            }
+        }
+    }
+
+    sum
+}
+
+#[test]
+fn day10_part1_test() {
+    let v = vec![
+        String::from("[({(<(())[]>[[{[]{<()<>>"),
+        String::from("[(()[<>])]({[<{<<[]>>("),
+        String::from("{([(<{}[<>[]}>{[]{[(<()>"),
+        String::from("(((({<>}<{<{<>}{[]{[]{}"),
+        String::from("[[<[([]))<([[{}[[()]]]"),
+        String::from("[{[{({}]{}}([{[{{{}}([]"),
+        String::from("{<[[]]>}<{[{[{[]{()[[[]"),
+        String::from("[<(<(<(<{}))><([]([]()"),
+        String::from("<{([([[(<>()){}]>(<<{{"),
+        String::from("<{([{{}}[<[[[<>{}]]]>[]]"),
+    ];
+    let answer = day10_part1(v.into_iter());
+
+    assert_eq!(26397, answer);
+}
+
+fn day10_part2(v: impl Iterator<Item = String>) -> Result<i64, String> {
+    let start_pairs = HashMap::from([('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')]);
+    let end_pairs = HashMap::from([(')', '('), (']', '['), ('}', '{'), ('>', '<')]);
+    let points = HashMap::from([(')', 1), (']', 2), ('}', 3), ('>', 4)]);
+
+    let mut sums = vec![];
+
+    'outer: for (i, line) in v.enumerate() {
+        let mut stack = vec![];
+
+        for c in line.chars() {
+            if start_pairs.contains_key(&c) {
+                stack.push(c);
+            } else if end_pairs.contains_key(&c) {
+                let last = stack.pop().unwrap();
+                if last != *end_pairs.get(&c).unwrap() {
+                    continue 'outer;
+                }
+            }
+        }
+
+        // Nothing left open, so the line is complete rather than incomplete
+        if stack.is_empty() {
+            continue;
+        }
+
+        let mut sum: i64 = 0;
+        for c in stack.iter().rev() {
+            sum = sum
+                .checked_mul(5)
+                .and_then(|sum| sum.checked_add(points[&start_pairs[c]]))
+                .ok_or(format!("Line {} has too many chunks left open", i + 1))?;
+        }
+        sums.push(sum);
+    }
+
+    sums.sort();
+    sums.get(sums.len() / 2)
+        .copied()
+        .ok_or(String::from("No line is incomplete"))
+}
+
+#[test]
+fn day10_part2_test() {
+    let v = vec![
+        String::from("[({(<(())[]>[[{[]{<()<>>"),
+        String::from("[(()[<>])]({[<{<<[]>>("),
+        String::from("{([(<{}[<>[]}>{[]{[(<()>"),
+        String::from("(((({<>}<{<{<>}{[]{[]{}"),
+        String::from("[[<[([]))<([[{}[[()]]]"),
+        String::from("[{[{({}]{}}([{[{{{}}([]"),
+        String::from("{<[[]]>}<{[{[{[]{()[[[]"),
+        String::from("[<(<(<(<{}))><([]([]()"),
+        String::from("<{([([[(<>()){}]>(<<{{"),
+        String::from("<{([{{}}[<[[[<>{}]]]>[]]"),
+    ];
+    let answer = day10_part2(v.into_iter());
+
+    assert_eq!(Ok(288957), answer);
+
+    let corrupt = vec![String::from("(]")];
+    assert_eq!(
+        Err(String::from("No line is incomplete")),
+        day10_part2(corrupt.into_iter())
+    );
+    let deep = vec!["(".repeat(28)];
+    assert_eq!(
+        Err(String::from("Line 1 has too many chunks left open")),
+        day10_part2(deep.into_iter())
+    );
+}
+
+#[test]
+fn day10_parse_error_test() {
+    let parse = |s: &str| Day10::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(2, 3, "an opening character")),
+        parse("()\n()]")
+    );
+    assert_eq!(
+        Some(ParseError::new(
+            1,
+            2,
+            "one of `(`, `)`, `[`, `]`, `{`, `}`, `<`, `>`"
+        )),
+        parse("(x)")
+    );
+}
+
+// Incomplete lines never leave more than this many chunks open, which keeps
+// their part 2 scores inside an i64
+const MAX_OPEN: usize = 20;
+
+/// Lines of about `length` characters, `corrupted` percent of which end on a
+/// mismatched closing character. The rest are left incomplete, and there is
+/// always an odd number of those so part 2 has a middle score.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+
+    let lines = params.at_least("lines", 100, 1);
+    let length = params.at_least("length", 100, 1);
+    let corrupted = params.get("corrupted", 50).min(100);
+
+    let mut incomplete = (lines * (100 - corrupted) / 100).max(1);
+    if incomplete.is_multiple_of(2) {
+        incomplete = if incomplete < lines {
+            incomplete + 1
+        } else {
+            incomplete - 1
+        };
+    }
+    let mut kinds = (0..lines).map(|i| i < incomplete).collect::<Vec<_>>();
+    rng.shuffle(&mut kinds);
+
+    let mut out = String::new();
+    for is_incomplete in kinds {
+        let mut open = vec![];
+        let mut line = String::new();
+        for _ in 0..length {
+            if !open.is_empty() && (open.len() >= MAX_OPEN || rng.chance(45)) {
+                let (_, close) = PAIRS[open.pop().unwrap()];
+                line.push(close);
+            } else {
+                let pair = rng.below(PAIRS.len());
+                open.push(pair);
+                line.push(PAIRS[pair].0);
+            }
+        }
+
+        if is_incomplete {
+            if open.is_empty() {
+                line.push(rng.pick(&PAIRS).0);
+            }
+        } else {
+            if open.is_empty() {
+                let pair = rng.below(PAIRS.len());
+                open.push(pair);
+                line.push(PAIRS[pair].0);
+            }
+            let expected = *open.last().unwrap();
+            let wrong = (expected + 1 + rng.below(PAIRS.len() - 1)) % PAIRS.len();
+            line.push(PAIRS[wrong].1);
+        }
+        out += &line;
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn day10_generate_test() {
+    let params = Params::parse(&[String::from("lines=40"), String::from("corrupted=25")]).unwrap();
+    let input = generate(&mut Rng::new(10), &params);
+    let lines = Day10::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(40, lines.len());
+    assert_ne!(0, Day10::part1(&lines).unwrap());
+    assert_ne!(0, Day10::part2(&lines).unwrap());
+}
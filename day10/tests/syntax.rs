@@ -0,0 +1,34 @@
+use aoc_core::Solution;
+use day10::Day10;
+
+fn lines(input: &str) -> Vec<String> {
+    Day10::parse(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn score_test() {
+    let example = lines(
+        "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(\n{([(<{}[<>[]}>{[]{[(<()>\n\
+         (((({<>}<{<{<>}{[]{[]{}\n[[<[([]))<([[{}[[()]]]\n[{[{({}]{}}([{[{{{}}([]\n\
+         {<[[]]>}<{[{[{[]{()[[[]\n[<(<(<(<{}))><([]([]()\n<{([([[(<>()){}]>(<<{{\n\
+         <{([{{}}[<[[[<>{}]]]>[]]",
+    );
+    assert_eq!(26397, Day10::part1(&example).unwrap());
+    assert_eq!(288957, Day10::part2(&example).unwrap());
+}
+
+#[test]
+fn complete_test() {
+    // Nothing corrupted; `>` then `]` completes the first, `}` the second and
+    // the last is already complete
+    let lines = lines("[<\n{\n()");
+    assert_eq!(0, Day10::part1(&lines).unwrap());
+    // 4 * 5 + 2 for the first and 3 for the second; with two the later one is
+    // the middle
+    assert_eq!(22, Day10::part2(&lines).unwrap());
+
+    assert_eq!(
+        Err(String::from("No line is incomplete")),
+        Day10::part2(&self::lines("()\n<>"))
+    );
+}
@@ -0,0 +1,248 @@
+//! Day 11: Dumbo Octopus. [`Flashes`] steps a grid of [`Octopuses`] as their
+//! energy builds and they flash.
+
+use aoc_core::{
+    anim::Simulation,
+    gen::{Params, Rng},
+    repl::{self, Explore},
+    Answer, Grid, ParseError, Pos, Solution,
+};
+use std::collections::HashSet;
+
+/// Counts the flashes in 100 steps, then finds when they all flash at once
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Octopuses;
+    const DAY: u8 = 11;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Octopuses, ParseError> {
+        Grid::parse_digits(v, "an energy level from 0 to 9")
+    }
+
+    fn part1(grid: &Octopuses) -> Result<Answer, String> {
+        let mut oct = grid.clone();
+        let mut total_flashed = 0;
+
+        for _ in 0..100 {
+            total_flashed += step(&mut oct);
+        }
+
+        Ok(total_flashed.into())
+    }
+
+    fn part2(grid: &Octopuses) -> Result<Answer, String> {
+        let mut oct = grid.clone();
+        let mut steps = 0;
+
+        loop {
+            steps += 1;
+
+            if step(&mut oct) == oct.len() {
+                break;
+            }
+        }
+
+        Ok(steps.into())
+    }
+}
+
+/// The energy level of each octopus
+pub type Octopuses = Grid<u32>;
+type Flashed = HashSet<Pos>;
+
+/// Runs one step and returns how many octopuses flashed
+fn step(oct: &mut Octopuses) -> usize {
+    let mut flashed = first_pass(oct);
+    flash_until_stable(oct, &mut flashed)
+}
+
+fn first_pass(oct: &mut Octopuses) -> Flashed {
+    let mut flashed = HashSet::new();
+    for energy in oct.iter_mut() {
+        *energy += 1;
+    }
+    for pos in oct.positions().collect::<Vec<_>>() {
+        if oct[pos] > 9 {
+            flash(oct, &mut flashed, pos);
+        }
+    }
+    flashed
+}
+
+fn flash(oct: &mut Octopuses, flashed: &mut Flashed, pos: Pos) {
+    if flashed.contains(&pos) {
+        return;
+    }
+
+    oct[pos] += 1;
+
+    if oct[pos] > 9 {
+        flashed.insert(pos);
+
+        for adjacent in oct.neighbours8(pos).collect::<Vec<_>>() {
+            flash(oct, flashed, adjacent);
+        }
+    }
+}
+
+fn flash_until_stable(oct: &mut Octopuses, flashed: &mut Flashed) -> usize {
+    let mut last_flashed = flashed.len();
+    loop {
+        for pos in flashed.clone() {
+            flash(oct, flashed, pos);
+        }
+        if flashed.len() == last_flashed {
+            break;
+        }
+        last_flashed = flashed.len();
+    }
+    for &pos in flashed.iter() {
+        oct[pos] = 0;
+    }
+    last_flashed
+}
+
+/// The octopuses flashing step by step until they all flash at once
+pub struct Flashes {
+    oct: Octopuses,
+    in_sync: bool,
+}
+
+impl From<Octopuses> for Flashes {
+    fn from(oct: Octopuses) -> Flashes {
+        Flashes {
+            oct,
+            in_sync: false,
+        }
+    }
+}
+
+impl Simulation for Flashes {
+    fn step(&mut self) -> bool {
+        if self.in_sync {
+            return false;
+        }
+        self.in_sync = step(&mut self.oct) == self.oct.len();
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.oct.to_string()
+    }
+}
+
+impl Explore for Flashes {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        repl::SIMULATION_COMMANDS.to_vec()
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>> {
+        repl::simulate(self, command, args)
+    }
+}
+
+#[test]
+fn day11_part1_test() {
+    let v = vec![
+        String::from("5483143223"),
+        String::from("2745854711"),
+        String::from("5264556173"),
+        String::from("6141336146"),
+        String::from("6357385478"),
+        String::from("4167524645"),
+        String::from("2176841721"),
+        String::from("6882881134"),
+        String::from("4846848554"),
+        String::from("5283751526"),
+    ];
+    let answer = Day11::part1(&Day11::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(1656, answer);
+}
+
+#[test]
+fn day11_part2_test() {
+    let v = vec![
+        String::from("5483143223"),
+        String::from("2745854711"),
+        String::from("5264556173"),
+        String::from("6141336146"),
+        String::from("6357385478"),
+        String::from("4167524645"),
+        String::from("2176841721"),
+        String::from("6882881134"),
+        String::from("4846848554"),
+        String::from("5283751526"),
+    ];
+    let answer = Day11::part2(&Day11::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(195, answer);
+}
+
+#[test]
+fn day11_flashes_test() {
+    let v = vec![
+        String::from("11111"),
+        String::from("19991"),
+        String::from("19191"),
+        String::from("19991"),
+        String::from("11111"),
+    ];
+    let mut flashes = Flashes::from(Day11::parse(v.into_iter()).unwrap());
+    let frames = aoc_core::anim::frames(&mut flashes)
+        .take(3)
+        .collect::<Vec<_>>();
+
+    assert_eq!("34543\n40004\n50005\n40004\n34543\n", frames[1]);
+    assert_eq!("45654\n51115\n61116\n51115\n45654\n", frames[2]);
+}
+
+// Steps a generated grid gets to flash in sync
+const SYNC_WITHIN: usize = 1000;
+
+#[test]
+fn day11_parse_error_test() {
+    let parse = |s: &str| Day11::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(1, 2, "an energy level from 0 to 9")),
+        parse("1x")
+    );
+    assert_eq!(
+        Some(ParseError::new(2, 2, "an energy level from 0 to 9")),
+        parse("12\n1")
+    );
+}
+
+/// Random energy levels. Plenty of grids never flash in sync, so up to
+/// `attempts` grids are drawn until one does within a thousand steps. Bigger
+/// grids rarely sync, and part 2 may never finish if none of them did.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let width = params.at_least("width", 10, 1);
+    let height = params.at_least("height", 10, 1);
+    let attempts = params.at_least("attempts", 100, 1);
+
+    let mut grid = Grid::new(width, height, 0);
+    for _ in 0..attempts {
+        grid = Grid::from_fn(width, height, |_| rng.below(10) as u32);
+        let mut oct = grid.clone();
+        if (0..SYNC_WITHIN).any(|_| step(&mut oct) == oct.len()) {
+            break;
+        }
+    }
+    grid.to_string()
+}
+
+#[test]
+fn day11_generate_test() {
+    let params = ["width=20", "height=15", "attempts=1"].map(String::from);
+    let input = generate(&mut Rng::new(11), &Params::parse(&params).unwrap());
+    let grid = Day11::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!((20, 15), (grid.width(), grid.height()));
+    assert_ne!(0, Day11::part1(&grid).unwrap());
+
+    let input = generate(&mut Rng::new(11), &Params::default());
+    let mut oct = Day11::parse(input.lines().map(String::from)).unwrap();
+    assert!((0..SYNC_WITHIN).any(|_| step(&mut oct) == oct.len()));
+}
@@ -0,0 +1,34 @@
+use aoc_core::{anim, Solution};
+use day11::{Day11, Flashes, Octopuses};
+
+const EXAMPLE: &str = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
+
+fn octopuses(input: &str) -> Octopuses {
+    Day11::parse(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn flashes_test() {
+    let octopuses = octopuses(EXAMPLE);
+    assert_eq!(1656, Day11::part1(&octopuses).unwrap());
+    assert_eq!(195, Day11::part2(&octopuses).unwrap());
+}
+
+#[test]
+fn simulation_test() {
+    // The starting grid, then one frame per step until they all flash
+    let mut flashes = Flashes::from(octopuses(EXAMPLE));
+    let frames = anim::frames(&mut flashes).collect::<Vec<_>>();
+    assert_eq!(196, frames.len());
+    assert_eq!(format!("{}\n", EXAMPLE), frames[0]);
+    assert!(frames[195].lines().all(|row| row == "0000000000"));
+}
+
+#[test]
+fn spread_test() {
+    // The middle flashes and pushes every neighbour over the edge in turn
+    let octopuses = octopuses("888\n898\n888");
+    let frames = anim::frames(&mut Flashes::from(octopuses)).collect::<Vec<_>>();
+    assert_eq!("000\n000\n000\n", frames[1]);
+    assert_eq!(2, frames.len());
+}
@@ -0,0 +1,360 @@
+//! Day 12: Passage Pathing. [`Cave`] counts the paths from `start` to `end`
+//! through a system of big and small caves.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    repl::Explore,
+    search, Answer, Cursor, ParseError, Solution,
+};
+use std::collections::{BTreeSet, HashMap};
+
+/// Counts the paths through the cave, then with one small cave visited twice
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Cave;
+    const DAY: u8 = 12;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Cave, ParseError> {
+        Cave::from(v)
+    }
+
+    fn part1(cave: &Cave) -> Result<Answer, String> {
+        Ok(cave.count_paths(false).into())
+    }
+
+    fn part2(cave: &Cave) -> Result<Answer, String> {
+        Ok(cave.count_paths(true).into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct NodeId {
+    name: String,
+}
+
+impl NodeId {
+    fn from(name: &str) -> NodeId {
+        NodeId {
+            name: name.to_string(),
+        }
+    }
+}
+
+/// Where a path is, which small caves it has been through, and whether it may
+/// still go through one of them a second time
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Visit {
+    at: NodeId,
+    smalls: BTreeSet<NodeId>,
+    can_revisit: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum NodeKind {
+    Start,
+    End,
+    Big,
+    Small,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    kind: NodeKind,
+    siblings: Vec<NodeId>,
+}
+
+impl Node {
+    fn new(id: &NodeId, siblings: Vec<NodeId>) -> Node {
+        let kind = match id.name.as_str() {
+            "start" => NodeKind::Start,
+            "end" => NodeKind::End,
+            n => {
+                if id.name.to_uppercase() == n {
+                    NodeKind::Big
+                } else {
+                    NodeKind::Small
+                }
+            }
+        };
+        Node { kind, siblings }
+    }
+
+    fn is_start(&self) -> bool {
+        self.kind == NodeKind::Start
+    }
+    fn is_end(&self) -> bool {
+        self.kind == NodeKind::End
+    }
+    fn is_small(&self) -> bool {
+        self.kind == NodeKind::Small
+    }
+}
+
+/// Each cave and the caves it has tunnels to
+pub struct Cave {
+    nodes: HashMap<NodeId, Node>,
+}
+
+impl Cave {
+    /// Reads the tunnels, one `a-b` pair of caves per line
+    pub fn from(v: impl Iterator<Item = String>) -> Result<Cave, ParseError> {
+        let mut nodes: HashMap<NodeId, Node> = HashMap::new();
+        let mut lines = 0;
+
+        for (i, line) in v.enumerate() {
+            let mut c = Cursor::new(i + 1, &line);
+            let id_one = NodeId::from(c.word()?);
+            c.literal("-")?;
+            let id_two = NodeId::from(c.word()?);
+            c.end()?;
+            lines += 1;
+
+            // Paths could go back and forth between two big caves forever
+            let big = |id: &NodeId| Node::new(id, vec![]).kind == NodeKind::Big;
+            if big(&id_one) && big(&id_two) {
+                return Err(ParseError::new(i + 1, 1, "a small cave at one end"));
+            }
+
+            if !nodes.contains_key(&id_one) {
+                nodes.insert(id_one.clone(), Node::new(&id_one, vec![id_two.clone()]));
+            } else {
+                let node_one = nodes.get_mut(&id_one).unwrap();
+                node_one.siblings.push(id_two.clone());
+            }
+            if !nodes.contains_key(&id_two) {
+                nodes.insert(id_two.clone(), Node::new(&id_two, vec![id_one]));
+            } else {
+                let node_two = nodes.get_mut(&id_two).unwrap();
+                node_two.siblings.push(id_one.clone());
+            }
+        }
+
+        if !nodes.contains_key(&NodeId::from("start")) {
+            return Err(ParseError::new(lines + 1, 1, "a path from `start`"));
+        }
+        Ok(Cave { nodes })
+    }
+
+    /// Paths from `start` to `end` that go through each small cave at most
+    /// once, or through a single one of them twice if `can_revisit`
+    pub fn count_paths(&self, can_revisit: bool) -> usize {
+        let start = Visit {
+            at: NodeId::from("start"),
+            smalls: BTreeSet::new(),
+            can_revisit,
+        };
+
+        let successors = |visit: &Visit| {
+            let mut next = vec![];
+            for sibling_id in &self.nodes[&visit.at].siblings {
+                let sibling = &self.nodes[sibling_id];
+                if sibling.is_start() {
+                    continue;
+                }
+
+                let mut visit = Visit {
+                    at: sibling_id.clone(),
+                    ..visit.clone()
+                };
+                if sibling.is_small() && !visit.smalls.insert(sibling_id.clone()) {
+                    if !visit.can_revisit {
+                        continue;
+                    }
+                    visit.can_revisit = false;
+                }
+                next.push(visit);
+            }
+            next
+        };
+
+        let count = search::count_paths(start, successors, |visit| self.nodes[&visit.at].is_end());
+        count.record();
+        count.paths
+    }
+}
+
+impl Explore for Cave {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("caves", "List each cave and the caves it leads to"),
+            ("paths", "Count paths, with and without a small cave twice"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>> {
+        match command {
+            "caves" | "paths" if !args.is_empty() => {
+                Some(Err(format!("{} takes no arguments", command)))
+            }
+            "caves" => {
+                let mut caves = self.nodes.iter().collect::<Vec<_>>();
+                caves.sort_by_key(|(id, _)| *id);
+                let lines = caves.iter().map(|(id, node)| {
+                    let mut siblings = node
+                        .siblings
+                        .iter()
+                        .map(|s| s.name.as_str())
+                        .collect::<Vec<_>>();
+                    siblings.sort();
+                    format!("{}: {}\n", id.name, siblings.join(" "))
+                });
+                Some(Ok(lines.collect()))
+            }
+            "paths" => Some(Ok(format!(
+                "{} paths, {} revisiting a small cave",
+                self.count_paths(false),
+                self.count_paths(true)
+            ))),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn day12_part1_test_1() {
+    let v = vec![
+        String::from("start-A"),
+        String::from("start-b"),
+        String::from("A-c"),
+        String::from("A-b"),
+        String::from("b-d"),
+        String::from("A-end"),
+        String::from("b-end"),
+    ];
+    let answer = Day12::part1(&Day12::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(10, answer);
+}
+
+#[test]
+fn day12_part1_test_2() {
+    let v = vec![
+        String::from("dc-end"),
+        String::from("HN-start"),
+        String::from("start-kj"),
+        String::from("dc-start"),
+        String::from("dc-HN"),
+        String::from("LN-dc"),
+        String::from("HN-end"),
+        String::from("kj-sa"),
+        String::from("kj-HN"),
+        String::from("kj-dc"),
+    ];
+    let answer = Day12::part1(&Day12::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(19, answer);
+}
+
+#[test]
+fn day12_part2_test_1() {
+    let v = vec![
+        String::from("start-A"),
+        String::from("start-b"),
+        String::from("A-c"),
+        String::from("A-b"),
+        String::from("b-d"),
+        String::from("A-end"),
+        String::from("b-end"),
+    ];
+    let answer = Day12::part2(&Day12::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(36, answer);
+}
+
+#[test]
+fn day12_part2_test_2() {
+    let v = vec![
+        String::from("dc-end"),
+        String::from("HN-start"),
+        String::from("start-kj"),
+        String::from("dc-start"),
+        String::from("dc-HN"),
+        String::from("LN-dc"),
+        String::from("HN-end"),
+        String::from("kj-sa"),
+        String::from("kj-HN"),
+        String::from("kj-dc"),
+    ];
+    let answer = Day12::part2(&Day12::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(103, answer);
+}
+
+#[test]
+fn day12_parse_error_test() {
+    let parse = |s: &str| Day12::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(3, 1, "a small cave at one end")),
+        parse("start-A\nA-end\nA-B")
+    );
+    assert_eq!(
+        Some(ParseError::new(2, 1, "a path from `start`")),
+        parse("a-end")
+    );
+    assert_eq!(Some(ParseError::new(1, 2, "`-`")), parse("a end"));
+}
+
+/// `small` and `big` caves, each reachable from `start`, with `edges` extra
+/// tunnels between random caves. Big caves are never joined to each other,
+/// which would allow endless paths.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    // There are only so many two letter names
+    let small = params.at_least("small", 6, 1).min(26 * 26);
+    let big = params.get("big", 2).min(26 * 26 - small);
+    let edges = params.get("edges", small + big);
+
+    let mut names = (0..26 * 26)
+        .map(|i| [b'a' + (i / 26) as u8, b'a' + (i % 26) as u8])
+        .map(|name| String::from_utf8(name.to_vec()).unwrap())
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    names.truncate(small + big);
+    for name in &mut names[small..] {
+        *name = name.to_uppercase();
+    }
+    let is_big = |i: usize| i >= small;
+
+    let mut tunnels = BTreeSet::new();
+    let mut join = |a: usize, b: usize| {
+        if a != b && !(is_big(a) && is_big(b)) {
+            tunnels.insert((a.min(b), a.max(b)));
+        }
+    };
+    // A tree first, so every cave is connected to the first small one
+    let mut order = (1..names.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut order);
+    for (n, &cave) in order.iter().enumerate() {
+        let mut other = if n == 0 { 0 } else { order[rng.below(n)] };
+        if is_big(cave) && is_big(other) {
+            other = rng.below(small);
+        }
+        join(cave, other);
+    }
+    for _ in 0..edges {
+        join(rng.below(names.len()), rng.below(names.len()));
+    }
+
+    let mut lines = tunnels
+        .into_iter()
+        .map(|(a, b)| format!("{}-{}", names[a], names[b]))
+        .collect::<Vec<_>>();
+    for _ in 0..2 {
+        lines.push(format!("start-{}", rng.pick(&names)));
+        lines.push(format!("{}-end", rng.pick(&names)));
+    }
+    lines.sort();
+    lines.dedup();
+    rng.shuffle(&mut lines);
+    lines.join("\n") + "\n"
+}
+
+#[test]
+fn day12_generate_test() {
+    let params = Params::parse(&[String::from("small=5"), String::from("big=2")]).unwrap();
+    let input = generate(&mut Rng::new(12), &params);
+    let cave = Day12::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(9, cave.nodes.len());
+    assert_ne!(0, Day12::part2(&cave).unwrap());
+}
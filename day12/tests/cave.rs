@@ -0,0 +1,23 @@
+use day12::Cave;
+
+fn cave(input: &str) -> Cave {
+    Cave::from(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn count_paths_test() {
+    let small = cave("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end");
+    assert_eq!(10, small.count_paths(false));
+    assert_eq!(36, small.count_paths(true));
+
+    // Going back through the big cave is only a new path if `a` can be revisited
+    let loop_back = cave("start-a\na-B\nB-end");
+    assert_eq!(1, loop_back.count_paths(false));
+    assert_eq!(2, loop_back.count_paths(true));
+}
+
+#[test]
+fn from_test() {
+    assert!(Cave::from(["a-end"].into_iter().map(String::from)).is_err());
+    assert!(Cave::from(["start end"].into_iter().map(String::from)).is_err());
+}
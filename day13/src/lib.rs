@@ -0,0 +1,367 @@
+//! Day 13: Transparent Origami. [`Paper`] folds a sheet of dots along each of
+//! its instructions to reveal a code.
+
+use aoc_core::{
+    anim::Simulation,
+    export,
+    gen::{Params, Rng},
+    repl::{self, Explore},
+    Answer, Cursor, Grid, ParseError, Solution,
+};
+use std::collections::BTreeSet;
+
+/// Counts the dots after one fold, then shows the code after all of them
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Paper;
+    const DAY: u8 = 13;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Paper, ParseError> {
+        Paper::from(v)
+    }
+
+    fn part1(paper: &Paper) -> Result<Answer, String> {
+        let mut paper = paper.clone();
+
+        paper.fold(1);
+        Ok(paper.dots.len().into())
+    }
+
+    fn part2(paper: &Paper) -> Result<Answer, String> {
+        let mut paper = paper.clone();
+
+        paper.fold(paper.folds.len());
+        Ok(paper.render().into())
+    }
+
+    /// The dots left after the part's folds
+    fn picture(paper: &Paper, part: u8) -> Option<Grid<usize>> {
+        let mut paper = paper.clone();
+
+        paper.fold(if part == 1 { 1 } else { paper.folds.len() });
+        Some(export::points(paper.dots.iter().map(|&Dot(x, y)| (x, y))))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+struct Dot(usize, usize);
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Fold(usize, usize);
+
+/// The dots on the transparent paper and the folds still to make
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paper {
+    dots: Vec<Dot>,
+    folds: Vec<Fold>,
+}
+
+impl Paper {
+    /// Reads the dots, a blank line, then the folds
+    pub fn from(v: impl Iterator<Item = String>) -> Result<Self, ParseError> {
+        let mut lines = v.enumerate().map(|(i, line)| (i + 1, line));
+
+        let mut dots = vec![];
+        loop {
+            let (i, line) = match lines.next() {
+                Some(next) => next,
+                None => return Err(ParseError::new(dots.len() + 1, 1, "a blank line")),
+            };
+            if line.is_empty() {
+                break;
+            }
+
+            let mut c = Cursor::new(i, &line);
+            let x = c.number()?;
+            c.literal(",")?;
+            let y = c.number()?;
+            c.end()?;
+
+            dots.push(Dot(x, y));
+        }
+
+        dots.sort();
+
+        let mut folds = vec![];
+        for (i, line) in lines {
+            let mut c = Cursor::new(i, &line);
+            c.literal("fold along ")?;
+            let axis = c.one_of(&["x", "y"])?;
+            c.literal("=")?;
+            let num = c.number()?;
+            c.end()?;
+
+            match axis {
+                "x" => folds.push(Fold(num, 0)),
+                _ => folds.push(Fold(0, num)),
+            }
+        }
+
+        Ok(Paper { dots, folds })
+    }
+
+    /// Folds along the first `times` instructions, or all of them if there
+    /// are fewer
+    pub fn fold(&mut self, times: usize) {
+        for fold in self.folds.clone().into_iter().take(times) {
+            self.fold_along(fold);
+        }
+    }
+
+    /// How many dots are visible
+    pub fn dots(&self) -> usize {
+        self.dots.len()
+    }
+
+    /// How many fold instructions there are
+    pub fn folds(&self) -> usize {
+        self.folds.len()
+    }
+
+    fn fold_along(&mut self, Fold(x, y): Fold) {
+        for dot in self.dots.iter_mut() {
+            if x > 0 {
+                // fold along x
+                let mut new_x = dot.0;
+                if dot.0 >= x {
+                    let dx = dot.0 - x;
+                    new_x = x - dx;
+                }
+                dot.0 = new_x;
+            } else if y > 0 {
+                // fold along y
+                let mut new_y = dot.1;
+                if dot.1 >= y {
+                    let dy = dot.1 - y;
+                    new_y = y - dy;
+                }
+                dot.1 = new_y;
+            }
+        }
+        self.dots.sort();
+        self.dots.dedup();
+    }
+
+    /// Renders the dots as rows of blocks, one line per row of paper
+    pub fn render(&self) -> String {
+        let max_x = self.dots.iter().map(|d| d.0).max().unwrap_or(0);
+        let max_y = self.dots.iter().map(|d| d.1).max().unwrap_or(0);
+
+        let mut out = String::new();
+        for y in 0..=max_y {
+            for x in 0..=max_x {
+                out.push(if self.dots.contains(&Dot(x, y)) {
+                    '█'
+                } else {
+                    ' '
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// The paper being folded one instruction at a time
+pub struct Folding {
+    paper: Paper,
+    folded: usize,
+}
+
+impl From<Paper> for Folding {
+    fn from(paper: Paper) -> Folding {
+        Folding { paper, folded: 0 }
+    }
+}
+
+impl Simulation for Folding {
+    fn step(&mut self) -> bool {
+        match self.paper.folds.get(self.folded) {
+            Some(&fold) => {
+                self.paper.fold_along(fold);
+                self.folded += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn frame(&self) -> String {
+        self.paper.render()
+    }
+}
+
+impl Explore for Folding {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            (
+                "fold [n]",
+                "Make the next n folds (default 1) and show the paper",
+            ),
+            ("show", "Show the paper"),
+            ("dots", "Count the visible dots"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>> {
+        match command {
+            "fold" => Some(repl::count(args).map(|n| repl::steps(self, n))),
+            "dots" => Some(Ok(format!(
+                "{} dots after {} of {} folds",
+                self.paper.dots(),
+                self.folded,
+                self.paper.folds()
+            ))),
+            _ => repl::simulate(self, command, args),
+        }
+    }
+}
+
+#[test]
+fn day13_part1_test() {
+    let v = vec![
+        String::from("6,10"),
+        String::from("0,14"),
+        String::from("9,10"),
+        String::from("0,3"),
+        String::from("10,4"),
+        String::from("4,11"),
+        String::from("6,0"),
+        String::from("6,12"),
+        String::from("4,1"),
+        String::from("0,13"),
+        String::from("10,12"),
+        String::from("3,4"),
+        String::from("3,0"),
+        String::from("8,4"),
+        String::from("1,10"),
+        String::from("2,14"),
+        String::from("8,10"),
+        String::from("9,0"),
+        String::from(""),
+        String::from("fold along y=7"),
+        String::from("fold along x=5"),
+    ];
+    let answer = Day13::part1(&Day13::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(17, answer);
+}
+
+#[test]
+fn day13_part2_test() {
+    let v = vec![
+        String::from("6,10"),
+        String::from("0,14"),
+        String::from("9,10"),
+        String::from("0,3"),
+        String::from("10,4"),
+        String::from("4,11"),
+        String::from("6,0"),
+        String::from("6,12"),
+        String::from("4,1"),
+        String::from("0,13"),
+        String::from("10,12"),
+        String::from("3,4"),
+        String::from("3,0"),
+        String::from("8,4"),
+        String::from("1,10"),
+        String::from("2,14"),
+        String::from("8,10"),
+        String::from("9,0"),
+        String::from(""),
+        String::from("fold along y=7"),
+        String::from("fold along x=5"),
+    ];
+    let answer = Day13::part2(&Day13::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!("█████\n█   █\n█   █\n█   █\n█████\n", answer);
+}
+
+#[test]
+fn day13_folding_test() {
+    let v = vec![
+        String::from("0,0"),
+        String::from("4,0"),
+        String::from("0,2"),
+        String::from(""),
+        String::from("fold along x=2"),
+        String::from("fold along y=1"),
+    ];
+    let mut folding = Folding::from(Day13::parse(v.into_iter()).unwrap());
+    let frames = aoc_core::anim::frames(&mut folding).collect::<Vec<_>>();
+
+    assert_eq!(vec!["█   █\n     \n█    \n", "█\n \n█\n", "█\n"], frames);
+}
+
+/// A random `width` by `height` picture, unfolded `folds` times with each dot
+/// landing on a random side of every fold
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let dots = params.get("dots", 800);
+    let folds = params.at_least("folds", 12, 1).min(40);
+    let width = params.at_least("width", 40, 1);
+    let height = params.at_least("height", 6, 1);
+
+    let mut picture = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|_| rng.chance(40))
+        .collect::<Vec<_>>();
+    if picture.is_empty() {
+        picture.push((0, 0));
+    }
+
+    // Folds alternate between x and y, starting with x. Unfolding undoes the
+    // last fold first, doubling the paper each time.
+    let mut unfolds = vec![];
+    let (mut w, mut h) = (width, height);
+    for i in (0..folds).rev() {
+        if i % 2 == 0 {
+            unfolds.push(Fold(w, 0));
+            w = 2 * w + 1;
+        } else {
+            unfolds.push(Fold(0, h));
+            h = 2 * h + 1;
+        }
+    }
+
+    let mut unfolded = BTreeSet::new();
+    for _ in 0..dots {
+        let (mut x, mut y) = *rng.pick(&picture);
+        for &Fold(fx, fy) in &unfolds {
+            if fx > 0 && rng.chance(50) {
+                x = 2 * fx - x;
+            }
+            if fy > 0 && rng.chance(50) {
+                y = 2 * fy - y;
+            }
+        }
+        unfolded.insert((x, y));
+    }
+    let mut unfolded = unfolded.into_iter().collect::<Vec<_>>();
+    rng.shuffle(&mut unfolded);
+
+    let mut out = String::new();
+    for (x, y) in unfolded {
+        out += &format!("{},{}\n", x, y);
+    }
+    out.push('\n');
+    for Fold(x, y) in unfolds.into_iter().rev() {
+        out += &match x {
+            0 => format!("fold along y={}\n", y),
+            _ => format!("fold along x={}\n", x),
+        };
+    }
+    out
+}
+
+#[test]
+fn day13_generate_test() {
+    let params = Params::parse(&[String::from("dots=200"), String::from("folds=5")]).unwrap();
+    let input = generate(&mut Rng::new(13), &params);
+    let mut paper = Day13::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(5, paper.folds.len());
+    assert_ne!(0, Day13::part1(&paper).unwrap());
+
+    paper.fold(5);
+    assert!(paper.dots.iter().all(|&Dot(x, y)| x < 40 && y < 6));
+}
@@ -0,0 +1,28 @@
+use day13::Paper;
+
+const EXAMPLE: &str = "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0\n\nfold along y=7\nfold along x=5";
+
+fn paper() -> Paper {
+    Paper::from(EXAMPLE.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn fold_test() {
+    let mut paper = paper();
+    assert_eq!(18, paper.dots());
+    assert_eq!(2, paper.folds());
+
+    paper.fold(1);
+    assert_eq!(17, paper.dots());
+
+    let mut paper = self::paper();
+    paper.fold(10);
+    assert_eq!(16, paper.dots());
+    assert_eq!("█████\n█   █\n█   █\n█   █\n█████\n", paper.render());
+}
+
+#[test]
+fn from_test() {
+    assert!(Paper::from(["1,2"].into_iter().map(String::from)).is_err());
+    assert!(Paper::from(["1,2", "", "fold along z=1"].into_iter().map(String::from)).is_err());
+}
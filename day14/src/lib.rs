@@ -0,0 +1,273 @@
+//! Day 14: Extended Polymerization. [`FormulaContext`] counts the elements
+//! of a polymer after any number of insertion steps without building it.
+
+use aoc_core::{
+    diag,
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// The most common element less the least after 10 steps, then after 40
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = FormulaContext;
+    const DAY: u8 = 14;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<FormulaContext, ParseError> {
+        FormulaContext::from(v)
+    }
+
+    fn part1(context: &FormulaContext) -> Result<Answer, String> {
+        Ok(most_minus_least(context, 10).into())
+    }
+
+    fn part2(context: &FormulaContext) -> Result<Answer, String> {
+        Ok(most_minus_least(context, 40).into())
+    }
+}
+
+type Pair = (char, char);
+
+type Rules = HashMap<Pair, char>;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct CacheKey(usize, char, char);
+
+/// How many of each element there are
+pub type Counts = HashMap<char, usize>;
+type Cache = HashMap<CacheKey, Counts>;
+
+fn merge_counts(counts: &mut Counts, cache: &Cache, key: &CacheKey) {
+    let other = cache.get(key).unwrap();
+
+    for (k, v) in other {
+        *counts.entry(*k).or_insert(0) += v;
+    }
+}
+
+/// The polymer template and the pair insertion rules
+pub struct FormulaContext {
+    rules: Rules,
+    seed: Vec<char>,
+}
+
+impl FormulaContext {
+    /// Reads the template, a blank line, then the insertion rules
+    pub fn from(v: impl Iterator<Item = String>) -> Result<FormulaContext, ParseError> {
+        let mut lines = v.enumerate().map(|(i, line)| (i + 1, line));
+
+        let first_line = lines.next().map(|(_, line)| line).unwrap_or_default();
+        let mut c = Cursor::new(1, &first_line);
+        let seed = c.word()?.chars().collect::<Vec<_>>();
+        c.end()?;
+
+        let blank = lines.next().map(|(_, line)| line).unwrap_or_default();
+        Cursor::new(2, &blank).end()?;
+
+        let element =
+            |c: &mut Cursor| c.char_map("an element", |e| Some(e).filter(char::is_ascii_uppercase));
+
+        let mut rules: Rules = HashMap::new();
+        let mut rule_lines = HashMap::new();
+        for (i, line) in lines {
+            let mut c = Cursor::new(i, &line);
+            let pair = (element(&mut c)?, element(&mut c)?);
+            c.literal(" -> ")?;
+            rules.insert(pair, element(&mut c)?);
+            rule_lines.insert(pair, i);
+            c.end()?;
+        }
+
+        // Every pair the polymer can come to hold needs a rule. A missing one
+        // is reported where the pair first appears: in the template, or at the
+        // element a rule inserts.
+        let mut pending = seed
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| ((pair[0], pair[1]), 1, i + 1))
+            .collect::<VecDeque<_>>();
+        let mut seen = HashSet::new();
+        while let Some((pair, line, column)) = pending.pop_front() {
+            if !seen.insert(pair) {
+                continue;
+            }
+            let Some(&next) = rules.get(&pair) else {
+                let expected = format!("a rule for {}{}", pair.0, pair.1);
+                return Err(ParseError::new(line, column, expected));
+            };
+            pending.push_back(((pair.0, next), rule_lines[&pair], 7));
+            pending.push_back(((next, pair.1), rule_lines[&pair], 7));
+        }
+
+        Ok(FormulaContext { rules, seed })
+    }
+
+    fn visit(&self, cache: &mut Cache, pair: Pair, iterations: usize) -> CacheKey {
+        let key = CacheKey(iterations, pair.0, pair.1);
+        if cache.contains_key(&key) {
+            // Cache hit - avoid work
+            diag::add("cache_hits", 1);
+            return key;
+        }
+        diag::add("cache_misses", 1);
+
+        let mut counts = HashMap::new();
+
+        if iterations > 0 {
+            // Parsing made sure there's a rule for every pair we can reach
+            let next = &self.rules[&pair];
+
+            // Recursively visit the left and right trees for the current pair
+            let key_left = self.visit(cache, (pair.0, *next), iterations - 1);
+            let key_right = self.visit(cache, (*next, pair.1), iterations - 1);
+
+            // Merge the counts returned by these subtrees for caching later on.
+            // This will help us avoid repeating most of the unnecessary work for
+            // the same pairs, which will always produce the same counts.
+            merge_counts(&mut counts, cache, &key_left);
+            merge_counts(&mut counts, cache, &key_right);
+        } else {
+            // Only count the right side of the pair to avoid double-counting.
+            // These will be aggregated by the callers when iterations > 0
+            *counts.entry(pair.1).or_insert(0) += 1;
+        }
+
+        // Cache the counts for this pair+iteration
+        cache.insert(key, counts);
+        key
+    }
+
+    /// Counts the elements in the polymer after `iterations` insertion steps
+    pub fn count(&self, iterations: usize) -> Counts {
+        let mut cache: Cache = HashMap::new();
+        let mut counts: Counts = HashMap::new();
+
+        for (i, c) in self.seed.iter().enumerate() {
+            if i == 0 {
+                counts.insert(*c, 1);
+                continue;
+            }
+            let child_key = self.visit(&mut cache, (self.seed[i - 1], *c), iterations);
+            merge_counts(&mut counts, &cache, &child_key);
+        }
+
+        counts
+    }
+}
+
+fn most_minus_least(context: &FormulaContext, iterations: usize) -> usize {
+    let mut counts = context.count(iterations).into_values().collect::<Vec<_>>();
+
+    counts.sort();
+    counts.last().unwrap() - counts.first().unwrap()
+}
+
+#[test]
+fn day14_part1_test() {
+    let v = vec![
+        String::from("NNCB"),
+        String::from(""),
+        String::from("CH -> B"),
+        String::from("HH -> N"),
+        String::from("CB -> H"),
+        String::from("NH -> C"),
+        String::from("HB -> C"),
+        String::from("HC -> B"),
+        String::from("HN -> C"),
+        String::from("NN -> C"),
+        String::from("BH -> H"),
+        String::from("NC -> B"),
+        String::from("NB -> B"),
+        String::from("BN -> B"),
+        String::from("BB -> N"),
+        String::from("BC -> B"),
+        String::from("CC -> N"),
+        String::from("CN -> C"),
+    ];
+    let answer = Day14::part1(&Day14::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(1588, answer);
+}
+
+#[test]
+fn day14_part2_test() {
+    let v = vec![
+        String::from("NNCB"),
+        String::from(""),
+        String::from("CH -> B"),
+        String::from("HH -> N"),
+        String::from("CB -> H"),
+        String::from("NH -> C"),
+        String::from("HB -> C"),
+        String::from("HC -> B"),
+        String::from("HN -> C"),
+        String::from("NN -> C"),
+        String::from("BH -> H"),
+        String::from("NC -> B"),
+        String::from("NB -> B"),
+        String::from("BN -> B"),
+        String::from("BB -> N"),
+        String::from("BC -> B"),
+        String::from("CC -> N"),
+        String::from("CN -> C"),
+    ];
+    let answer = Day14::part2(&Day14::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(2188189693529_u64, answer);
+}
+
+#[test]
+fn day14_parse_error_test() {
+    let parse = |s: &str| Day14::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(1, 1, "a rule for NN")),
+        parse("NN\n\nNC -> B")
+    );
+    assert_eq!(
+        Some(ParseError::new(3, 7, "a rule for NB")),
+        parse("NC\n\nNC -> B")
+    );
+    assert_eq!(Some(ParseError::new(3, 3, "` -> `")), parse("NN\n\nNN > C"));
+    assert_eq!(
+        Some(ParseError::new(3, 7, "an element")),
+        parse("NN\n\nNN -> c")
+    );
+    assert_eq!(
+        Some(ParseError::new(2, 1, "end of line")),
+        parse("NN\nNN -> C")
+    );
+}
+
+/// A template made from the first `elements` letters, with a rule for every
+/// pair of them
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let elements = params.at_least("elements", 10, 1).min(26);
+    let template = params.at_least("template", 20, 2);
+
+    let elements = ('A'..='Z').take(elements).collect::<Vec<_>>();
+    let mut out = (0..template)
+        .map(|_| *rng.pick(&elements))
+        .collect::<String>();
+    out += "\n\n";
+    for &a in &elements {
+        for &b in &elements {
+            out += &format!("{}{} -> {}\n", a, b, rng.pick(&elements));
+        }
+    }
+    out
+}
+
+#[test]
+fn day14_generate_test() {
+    let params = Params::parse(&[String::from("elements=5")]).unwrap();
+    let input = generate(&mut Rng::new(14), &params);
+    let context = Day14::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(25, context.rules.len());
+    Day14::part2(&context).unwrap();
+}
@@ -0,0 +1,48 @@
+use day14::FormulaContext;
+
+const EXAMPLE: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+fn polymer(input: &str) -> FormulaContext {
+    FormulaContext::from(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn count_test() {
+    let polymer = polymer(EXAMPLE);
+    assert_eq!(4, polymer.count(0).values().sum::<usize>());
+    // NCNBCHB
+    let step = polymer.count(1);
+    assert_eq!(
+        (2, 2, 2, 1),
+        (step[&'N'], step[&'C'], step[&'B'], step[&'H'])
+    );
+
+    let counts = polymer.count(10);
+    assert_eq!(3073, counts.values().sum::<usize>());
+    assert_eq!((1749, 161), (counts[&'B'], counts[&'H']));
+}
+
+#[test]
+fn from_test() {
+    // NNCB needs a rule for every pair the steps can make
+    let missing = EXAMPLE.replace("CB -> H\n", "");
+    assert!(FormulaContext::from(missing.lines().map(String::from)).is_err());
+    assert!(FormulaContext::from(["NN", "", "NN => C"].into_iter().map(String::from)).is_err());
+}
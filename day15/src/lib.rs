@@ -0,0 +1,152 @@
+//! Day 15: Chiton. [`Cavern`] finds the path of least risk through a grid
+//! and through that grid tiled into a larger one.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    search, Answer, Grid, ParseError, Solution,
+};
+
+/// The lowest total risk across the cavern, then across it tiled five times
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Cavern;
+    const DAY: u8 = 15;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Cavern, ParseError> {
+        Cavern::from(v)
+    }
+
+    fn part1(cavern: &Cavern) -> Result<Answer, String> {
+        Ok(cavern.min_path().into())
+    }
+
+    fn part2(cavern: &Cavern) -> Result<Answer, String> {
+        Ok(cavern.tiled(5).min_path().into())
+    }
+}
+
+/// The risk level of each position in the cavern
+pub struct Cavern {
+    map: Grid<u32>,
+}
+
+fn wrap(risk: u32, dx: usize, dy: usize) -> u32 {
+    let next = risk + (dx + dy) as u32;
+    if next > 9 {
+        next - 9
+    } else {
+        next
+    }
+}
+
+impl Cavern {
+    /// Reads the grid of risk levels, one row per line
+    pub fn from(v: impl Iterator<Item = String>) -> Result<Cavern, ParseError> {
+        let expected = "a risk level from 0 to 9";
+        let map = Grid::parse_digits(v, expected)?;
+        if map.width() == 0 {
+            return Err(ParseError::new(1, 1, expected));
+        }
+        Ok(Cavern { map })
+    }
+
+    /// Repeats the cavern `times` times in each direction, increasing the risk
+    /// by one for every tile away from the original
+    pub fn tiled(&self, times: usize) -> Cavern {
+        let width = self.map.width();
+        let height = self.map.height();
+        let map = Grid::from_fn(width * times, height * times, |(x, y)| {
+            wrap(self.map[(x % width, y % height)], x / width, y / height)
+        });
+        Cavern { map }
+    }
+
+    /// The lowest total risk of a path from the top left to the bottom right,
+    /// not counting the position it starts on
+    pub fn min_path(&self) -> u32 {
+        let goal = (self.map.width() - 1, self.map.height() - 1);
+        let search = search::dijkstra(
+            (0, 0),
+            |&pos| self.map.neighbours4(pos).map(|next| (next, self.map[next])),
+            |&pos| pos == goal,
+        );
+        search.record();
+        search.cost().expect("No path through the cavern")
+    }
+}
+
+#[test]
+fn day15_part1_test() {
+    let v = vec![
+        String::from("1163751742"),
+        String::from("1381373672"),
+        String::from("2136511328"),
+        String::from("3694931569"),
+        String::from("7463417111"),
+        String::from("1319128137"),
+        String::from("1359912421"),
+        String::from("3125421639"),
+        String::from("1293138521"),
+        String::from("2311944581"),
+    ];
+    let answer = Day15::part1(&Day15::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(40, answer);
+}
+
+#[test]
+fn day15_part2_test() {
+    let v = vec![
+        String::from("1163751742"),
+        String::from("1381373672"),
+        String::from("2136511328"),
+        String::from("3694931569"),
+        String::from("7463417111"),
+        String::from("1319128137"),
+        String::from("1359912421"),
+        String::from("3125421639"),
+        String::from("1293138521"),
+        String::from("2311944581"),
+    ];
+    let answer = Day15::part2(&Day15::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(315, answer);
+}
+
+#[test]
+fn day15_parse_error_test() {
+    let parse = |s: &str| Day15::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(1, 1, "a risk level from 0 to 9")),
+        parse("")
+    );
+    assert_eq!(
+        Some(ParseError::new(2, 2, "a risk level from 0 to 9")),
+        parse("12\n1a")
+    );
+}
+
+/// A `width` by `height` grid of risk levels from 1 to 9
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let width = params.at_least("width", 100, 1);
+    let height = params.at_least("height", 100, 1);
+    let mut out = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            out += &rng.range(1, 9).to_string();
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn day15_generate_test() {
+    let params = Params::parse(&[String::from("width=20"), String::from("height=10")]).unwrap();
+    let input = generate(&mut Rng::new(15), &params);
+    let cavern = Day15::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!((20, 10), (cavern.map.width(), cavern.map.height()));
+    assert!(cavern.min_path() >= 28);
+}
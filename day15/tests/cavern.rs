@@ -0,0 +1,22 @@
+use day15::Cavern;
+
+const EXAMPLE: &str = "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n1319128137\n1359912421\n3125421639\n1293138521\n2311944581";
+
+fn cavern(input: &str) -> Cavern {
+    Cavern::from(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn min_path_test() {
+    let cavern = cavern(EXAMPLE);
+    assert_eq!(40, cavern.min_path());
+    assert_eq!(315, cavern.tiled(5).min_path());
+    assert_eq!(40, cavern.tiled(1).min_path());
+}
+
+#[test]
+fn tiled_test() {
+    // Each tile away adds one, wrapping from 9 back round to 1
+    let tiled = cavern("8").tiled(3);
+    assert_eq!(9 + 1 + 2 + 3, tiled.min_path());
+}
@@ -0,0 +1,599 @@
+//! Day 16: Packet Decoder. [`Packet::decode`] reads a BITS transmission written
+//! in hex into a tree of packets.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// Sums the packets' versions, then evaluates the transmission
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    const DAY: u8 = 16;
+
+    fn parse(mut v: impl Iterator<Item = String>) -> Result<Packet, ParseError> {
+        Packet::decode(&v.next().unwrap_or_default())
+    }
+
+    fn part1(root: &Packet) -> Result<Answer, String> {
+        Ok(root.version_sum().into())
+    }
+
+    fn part2(root: &Packet) -> Result<Answer, String> {
+        root.value().map(Answer::from)
+    }
+}
+
+/// What a packet holds: a literal value, or an operator over its subpackets
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeId {
+    /// The sum of the subpackets
+    Sum = 0,
+    /// The product of the subpackets
+    Mul = 1,
+    /// The smallest subpacket
+    Min = 2,
+    /// The largest subpacket
+    Max = 3,
+    /// A literal value
+    Lit = 4,
+    /// 1 if the first of two subpackets is greater, else 0
+    Gt = 5,
+    /// 1 if the first of two subpackets is less, else 0
+    Lt = 6,
+    /// 1 if the two subpackets are equal, else 0
+    Eq = 7,
+}
+
+/// A packet holding a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Literal {
+    /// The packet's version
+    pub ver: usize,
+    /// The number, which the parser limits to 128 bits
+    pub val: u128,
+}
+
+/// A packet that works something out from the packets inside it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operator {
+    /// What it works out
+    pub id: TypeId,
+    /// The packet's version
+    pub ver: usize,
+    /// The packets inside it, in order
+    pub subpackets: Vec<Packet>,
+}
+
+/// A packet of either kind, and everything inside it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    /// A number
+    Literal(Literal),
+    /// An operator over subpackets
+    Operator(Operator),
+}
+
+impl Packet {
+    /// Decodes a transmission written in hex. Bits left over after the
+    /// outermost packet are padding and ignored.
+    pub fn decode(hex: &str) -> Result<Packet, ParseError> {
+        let mut c = Cursor::new(1, hex);
+        while !c.is_empty() {
+            c.char_map("a hex digit", |h| h.to_digit(16))?;
+        }
+        let bin_string = to_bin_str(hex);
+
+        let mut chars = bin_string.chars();
+        let mut read = 0;
+
+        parse_packet(&mut chars, &mut read, 0)
+    }
+
+    /// This packet's version, without its subpackets
+    pub fn version(&self) -> usize {
+        match self {
+            Packet::Literal(lit) => lit.ver,
+            Packet::Operator(op) => op.ver,
+        }
+    }
+
+    /// The versions of this packet and every packet inside it, added up
+    pub fn version_sum(&self) -> usize {
+        collect_packets(self.clone())
+            .iter()
+            .map(Packet::version)
+            .sum()
+    }
+
+    /// What the packet evaluates to, or an error if a sum or product doesn't
+    /// fit in a u128
+    pub fn value(&self) -> Result<u128, String> {
+        packet_value(self)
+    }
+
+    fn lit(ver: usize, val: u128) -> Packet {
+        Packet::Literal(Literal { ver, val })
+    }
+
+    fn op(id: TypeId, ver: usize, subpackets: Vec<Packet>) -> Packet {
+        Packet::Operator(Operator {
+            id,
+            ver,
+            subpackets,
+        })
+    }
+}
+
+// Errors point at the hex digit holding the first missing bit
+fn read_bin(
+    chars: &mut impl Iterator<Item = char>,
+    len: usize,
+    read: &mut usize,
+) -> Result<usize, ParseError> {
+    let bin = chars.take(len).collect::<String>();
+    if bin.len() < len {
+        return Err(ParseError::new(
+            1,
+            (*read + bin.len()) / 4 + 1,
+            "more packet bits",
+        ));
+    }
+    *read += len;
+    Ok(usize::from_str_radix(&bin, 2).unwrap())
+}
+
+// Far deeper than any real transmission, but shallow enough that a hostile
+// one can't overflow the stack
+const MAX_DEPTH: usize = 256;
+
+fn parse_packet(
+    chars: &mut impl Iterator<Item = char>,
+    read: &mut usize,
+    depth: usize,
+) -> Result<Packet, ParseError> {
+    let start = *read;
+    if depth > MAX_DEPTH {
+        return Err(ParseError::new(
+            1,
+            *read / 4 + 1,
+            format!("at most {} nested packets", MAX_DEPTH),
+        ));
+    }
+    let ver = read_bin(chars, 3, read)?;
+
+    let id: TypeId = match read_bin(chars, 3, read)? as u8 {
+        0 => TypeId::Sum,
+        1 => TypeId::Mul,
+        2 => TypeId::Min,
+        3 => TypeId::Max,
+        4 => TypeId::Lit,
+        5 => TypeId::Gt,
+        6 => TypeId::Lt,
+        7 => TypeId::Eq,
+        _ => panic!("Invalid packet type"),
+    };
+
+    match id {
+        // Literal value
+        TypeId::Lit => {
+            let mut val: u128 = 0;
+            loop {
+                if val >> (u128::BITS - 4) != 0 {
+                    return Err(ParseError::new(
+                        1,
+                        *read / 4 + 1,
+                        "a literal of at most 128 bits",
+                    ));
+                }
+                let next = read_bin(chars, 5, read)?;
+
+                val = (val << 4) | (next & 0b01111) as u128;
+                if next & 0b10000 == 0 {
+                    break;
+                }
+            }
+            Ok(Packet::lit(ver, val))
+        }
+        // Operator packet
+        id => {
+            let op = read_bin(chars, 1, read)?;
+
+            let subpackets = match op {
+                0 => {
+                    let len = read_bin(chars, 15, read)?;
+
+                    let init = *read;
+                    let mut subpackets = vec![];
+                    while *read - init < len {
+                        subpackets.push(parse_packet(chars, read, depth + 1)?);
+                    }
+                    subpackets
+                }
+                1 => {
+                    let len = read_bin(chars, 11, read)?;
+
+                    let mut subpackets = Vec::new();
+                    for _ in 0..len {
+                        subpackets.push(parse_packet(chars, read, depth + 1)?);
+                    }
+                    subpackets
+                }
+                _ => panic!("Unknown operator type {}", op),
+            };
+
+            // Errors point at the hex digit the operator starts in
+            let expected = match id {
+                TypeId::Gt | TypeId::Lt | TypeId::Eq if subpackets.len() != 2 => {
+                    "a comparison of two subpackets"
+                }
+                _ if subpackets.is_empty() => "an operator with subpackets",
+                _ => return Ok(Packet::op(id, ver, subpackets)),
+            };
+            Err(ParseError::new(1, start / 4 + 1, expected))
+        }
+    }
+}
+
+fn collect_packets(root: Packet) -> Vec<Packet> {
+    match root {
+        Packet::Literal(_) => vec![root],
+        Packet::Operator(op) => {
+            let mut packets: Vec<Packet> = vec![Packet::Operator(op.clone())];
+            for subpacket in op.subpackets {
+                packets.append(&mut collect_packets(subpacket.clone()));
+            }
+            packets
+        }
+    }
+}
+
+fn to_bin_str(hex_str: &str) -> String {
+    hex_str
+        .chars()
+        .map(|c| {
+            let hex = c.to_digit(16).unwrap();
+            format!("{:0>4b}", hex)
+        })
+        .collect::<Vec<_>>()
+        .join("")
+}
+
+#[test]
+fn day16_parse_error_test() {
+    let truncated = Day16::parse(vec![String::from("D2FE")].into_iter());
+    assert_eq!(Err(ParseError::new(1, 5, "more packet bits")), truncated);
+
+    let not_hex = Day16::parse(vec![String::from("D2FG28")].into_iter());
+    assert_eq!(Err(ParseError::new(1, 4, "a hex digit")), not_hex);
+
+    // A less-than comparison of one literal
+    let one_operand = Day16::parse(vec![String::from("16004408")].into_iter());
+    assert_eq!(
+        Err(ParseError::new(1, 1, "a comparison of two subpackets")),
+        one_operand
+    );
+
+    // A sum holding a maximum of nothing
+    let empty = Day16::parse(vec![String::from("020043800")].into_iter());
+    assert_eq!(
+        Err(ParseError::new(1, 5, "an operator with subpackets")),
+        empty
+    );
+}
+
+#[test]
+fn day16_part1_test() {
+    let bin_1 = to_bin_str("D2FE28");
+    assert_eq!("110100101111111000101000", bin_1);
+
+    let mut chars_1 = bin_1.chars();
+    let mut read_1 = 0;
+    assert_eq!(
+        Ok(Packet::lit(6, 2021)),
+        parse_packet(&mut chars_1, &mut read_1, 0)
+    );
+
+    let bin_2 = to_bin_str("38006F45291200");
+    assert_eq!(
+        "00111000000000000110111101000101001010010001001000000000",
+        bin_2
+    );
+
+    assert_eq!(
+        16,
+        Day16::part1(&Day16::parse(vec![String::from("8A004A801A8002F478")].into_iter()).unwrap())
+            .unwrap()
+    );
+    assert_eq!(
+        12,
+        Day16::part1(
+            &Day16::parse(vec![String::from("620080001611562C8802118E34")].into_iter()).unwrap()
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        23,
+        Day16::part1(
+            &Day16::parse(vec![String::from("C0015000016115A2E0802F182340")].into_iter()).unwrap()
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        31,
+        Day16::part1(
+            &Day16::parse(vec![String::from("A0016C880162017C3686B18A3D4780")].into_iter())
+                .unwrap()
+        )
+        .unwrap()
+    );
+}
+
+// Parsing made sure comparisons have two subpackets and other operators at
+// least one
+fn packet_value(packet: &Packet) -> Result<u128, String> {
+    let op = match packet {
+        Packet::Literal(lit) => return Ok(lit.val),
+        Packet::Operator(op) => op,
+    };
+    let values = op
+        .subpackets
+        .iter()
+        .map(packet_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    match op.id {
+        TypeId::Sum => values
+            .iter()
+            .try_fold(0u128, |a, &b| a.checked_add(b))
+            .ok_or(String::from("A sum overflowed a u128")),
+        TypeId::Mul => values
+            .iter()
+            .try_fold(1u128, |a, &b| a.checked_mul(b))
+            .ok_or(String::from("A product overflowed a u128")),
+        TypeId::Min => Ok(*values.iter().min().unwrap()),
+        TypeId::Max => Ok(*values.iter().max().unwrap()),
+        TypeId::Gt => Ok((values[0] > values[1]) as u128),
+        TypeId::Lt => Ok((values[0] < values[1]) as u128),
+        TypeId::Eq => Ok((values[0] == values[1]) as u128),
+        TypeId::Lit => panic!("Unknown operator type"),
+    }
+}
+
+#[test]
+fn day16_part2_test() {
+    assert_eq!(
+        3,
+        Day16::part2(&Day16::parse(vec![String::from("C200B40A82")].into_iter()).unwrap()).unwrap()
+    );
+    assert_eq!(
+        54,
+        Day16::part2(&Day16::parse(vec![String::from("04005AC33890")].into_iter()).unwrap())
+            .unwrap()
+    );
+    assert_eq!(
+        7,
+        Day16::part2(&Day16::parse(vec![String::from("880086C3E88112")].into_iter()).unwrap())
+            .unwrap()
+    );
+    assert_eq!(
+        9,
+        Day16::part2(&Day16::parse(vec![String::from("CE00C43D881120")].into_iter()).unwrap())
+            .unwrap()
+    );
+
+    assert_eq!(
+        1,
+        Day16::part2(&Day16::parse(vec![String::from("D8005AC2A8F0")].into_iter()).unwrap())
+            .unwrap()
+    );
+
+    assert_eq!(
+        0,
+        Day16::part2(&Day16::parse(vec![String::from("F600BC2D8F")].into_iter()).unwrap()).unwrap()
+    );
+
+    assert_eq!(
+        0,
+        Day16::part2(&Day16::parse(vec![String::from("9C005AC2F8F0")].into_iter()).unwrap())
+            .unwrap()
+    );
+
+    assert_eq!(
+        1,
+        Day16::part2(
+            &Day16::parse(vec![String::from("9C0141080250320F1802104A08")].into_iter()).unwrap()
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn day16_wide_test() {
+    // Literals and products past what a u64 holds
+    let mut bits = String::new();
+    write_packet(&mut Rng::new(0), &Packet::lit(0, u128::MAX >> 8), &mut bits);
+    let literal = parse_packet(&mut bits.chars(), &mut 0, 0).unwrap();
+    assert_eq!(Ok(u128::MAX >> 8), literal.value());
+
+    let product = Packet::op(
+        TypeId::Mul,
+        0,
+        vec![Packet::lit(0, 1 << 63), Packet::lit(0, 4)],
+    );
+    assert_eq!(36893488147419103232_u128, Day16::part2(&product).unwrap());
+
+    // But not past a u128
+    let bits = String::from("000100") + &"11111".repeat(32) + "01111";
+    assert_eq!(
+        Err(ParseError::new(1, 42, "a literal of at most 128 bits")),
+        parse_packet(&mut bits.chars(), &mut 0, 0)
+    );
+    let sum = Packet::op(
+        TypeId::Sum,
+        0,
+        vec![Packet::lit(0, u128::MAX), Packet::lit(0, 1)],
+    );
+    assert_eq!(
+        Err(String::from("A sum overflowed a u128")),
+        Day16::part2(&sum)
+    );
+    let product = Packet::op(TypeId::Mul, 0, vec![Packet::lit(0, 1 << 64); 2]);
+    assert_eq!(
+        Err(String::from("A product overflowed a u128")),
+        product.value()
+    );
+}
+
+/// One transmission: a random tree of packets up to `depth` deep, with up to
+/// `children` subpackets per operator. Products of enough literals can
+/// overflow, which part 2 reports as an error.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let depth = params.get("depth", 5).min(MAX_DEPTH);
+    let children = params.at_least("children", 4, 2).min(2047);
+
+    let packet = random_packet(rng, depth, children);
+    let mut bits = String::new();
+    write_packet(rng, &packet, &mut bits);
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+
+    let hex = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|nibble| {
+            let nibble = std::str::from_utf8(nibble).unwrap();
+            format!("{:X}", u8::from_str_radix(nibble, 2).unwrap())
+        })
+        .collect::<String>();
+    hex + "\n"
+}
+
+fn random_packet(rng: &mut Rng, depth: usize, children: usize) -> Packet {
+    let ver = rng.below(8);
+    if depth == 0 || rng.chance(25) {
+        return Packet::lit(ver, rng.below(1 << 16) as u128);
+    }
+
+    let ids = [
+        TypeId::Sum,
+        TypeId::Mul,
+        TypeId::Min,
+        TypeId::Max,
+        TypeId::Gt,
+        TypeId::Lt,
+        TypeId::Eq,
+    ];
+    let id = rng.pick(&ids).clone();
+    let count = match id {
+        TypeId::Gt | TypeId::Lt | TypeId::Eq => 2,
+        _ => 1 + rng.below(children),
+    };
+    let subpackets = (0..count)
+        .map(|_| random_packet(rng, depth - 1, children))
+        .collect();
+    Packet::op(id, ver, subpackets)
+}
+
+// Operators pick either length type at random, unless their subpackets are
+// too long to measure in 15 bits
+fn write_packet(rng: &mut Rng, packet: &Packet, bits: &mut String) {
+    match packet {
+        Packet::Literal(lit) => {
+            *bits += &format!("{:03b}100", lit.ver);
+            let bits_used = u128::BITS - lit.val.leading_zeros();
+            let groups = (bits_used as usize).div_ceil(4).max(1);
+            for i in (0..groups).rev() {
+                let more = if i > 0 { '1' } else { '0' };
+                *bits += &format!("{}{:04b}", more, lit.val >> (4 * i) & 0xf);
+            }
+        }
+        Packet::Operator(op) => {
+            let mut inner = String::new();
+            for subpacket in &op.subpackets {
+                write_packet(rng, subpacket, &mut inner);
+            }
+            *bits += &format!("{:03b}{:03b}", op.ver, op.id.clone() as u8);
+            if inner.len() < 1 << 15 && rng.chance(50) {
+                *bits += &format!("0{:015b}", inner.len());
+            } else {
+                *bits += &format!("1{:011b}", op.subpackets.len());
+            }
+            *bits += &inner;
+        }
+    }
+}
+
+#[test]
+fn day16_generate_test() {
+    let input = generate(&mut Rng::new(16), &Params::default());
+    let packet = Day16::parse(input.lines().map(String::from)).unwrap();
+    Day16::part1(&packet).unwrap();
+    Day16::part2(&packet).unwrap();
+
+    let mut bits = String::new();
+    write_packet(&mut Rng::new(0), &packet, &mut bits);
+    let mut read = 0;
+    assert_eq!(Ok(packet), parse_packet(&mut bits.chars(), &mut read, 0));
+}
+
+#[test]
+fn day16_nesting_test() {
+    // Sum operators holding one subpacket each, around a literal
+    let mut bits = "000000100000000001".repeat(MAX_DEPTH + 1) + "00010000001";
+    while !bits.len().is_multiple_of(4) {
+        bits.push('0');
+    }
+    let mut read = 0;
+    assert_eq!(
+        Err(ParseError::new(
+            1,
+            (MAX_DEPTH + 1) * 18 / 4 + 1,
+            "at most 256 nested packets"
+        )),
+        parse_packet(&mut bits.chars(), &mut read, 0)
+    );
+}
+
+#[cfg(test)]
+fn packet() -> impl proptest::strategy::Strategy<Value = Packet> {
+    use proptest::prelude::*;
+
+    let ids = [
+        TypeId::Sum,
+        TypeId::Mul,
+        TypeId::Min,
+        TypeId::Max,
+        TypeId::Gt,
+        TypeId::Lt,
+        TypeId::Eq,
+    ];
+    let literal = (0..8usize, any::<u128>()).prop_map(|(ver, val)| Packet::lit(ver, val));
+    literal.prop_recursive(6, 64, 4, move |inner| {
+        (
+            proptest::sample::select(ids.to_vec()),
+            0..8usize,
+            proptest::collection::vec(inner, 1..4),
+        )
+            .prop_map(|(id, ver, mut subpackets)| {
+                if matches!(id, TypeId::Gt | TypeId::Lt | TypeId::Eq) {
+                    subpackets.resize(2, subpackets[0].clone());
+                }
+                Packet::op(id, ver, subpackets)
+            })
+    })
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn day16_round_trip_test(packet in packet(), seed: u64) {
+        let mut bits = String::new();
+        write_packet(&mut Rng::new(seed), &packet, &mut bits);
+        let mut read = 0;
+        proptest::prop_assert_eq!(Ok(packet), parse_packet(&mut bits.chars(), &mut read, 0));
+        proptest::prop_assert_eq!(bits.len(), read);
+    }
+}
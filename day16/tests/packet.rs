@@ -0,0 +1,57 @@
+use aoc_core::ParseError;
+use day16::{Literal, Operator, Packet, TypeId};
+
+#[test]
+fn decode_test() {
+    assert_eq!(
+        Ok(Packet::Literal(Literal { ver: 6, val: 2021 })),
+        Packet::decode("D2FE28")
+    );
+
+    let Packet::Operator(op) = Packet::decode("38006F45291200").unwrap() else {
+        panic!("expected an operator");
+    };
+    let Operator {
+        id,
+        ver,
+        subpackets,
+    } = op;
+    assert_eq!((TypeId::Lt, 1), (id, ver));
+    assert_eq!(
+        Ok(vec![10, 20]),
+        subpackets
+            .iter()
+            .map(Packet::value)
+            .collect::<Result<Vec<_>, _>>()
+    );
+
+    assert_eq!(
+        Err(ParseError::new(1, 5, "more packet bits")),
+        Packet::decode("D2FE")
+    );
+}
+
+#[test]
+fn version_sum_test() {
+    assert_eq!(
+        16,
+        Packet::decode("8A004A801A8002F478").unwrap().version_sum()
+    );
+    assert_eq!(
+        31,
+        Packet::decode("A0016C880162017C3686B18A3D4780")
+            .unwrap()
+            .version_sum()
+    );
+}
+
+#[test]
+fn value_test() {
+    assert_eq!(Ok(3), Packet::decode("C200B40A82").unwrap().value());
+    assert_eq!(
+        Ok(1),
+        Packet::decode("9C0141080250320F1802104A08")
+            .unwrap()
+            .value()
+    );
+}
@@ -0,0 +1,235 @@
+//! Day 17: Trick Shot. Fires the probe at every velocity that could reach the
+//! [`Target`] and keeps the ones that land in it.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+
+/// The highest the probe can go and still hit the target, then how many velocities hit
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Target;
+    const DAY: u8 = 17;
+
+    fn parse(mut v: impl Iterator<Item = String>) -> Result<Target, ParseError> {
+        let line = v.next().unwrap_or_default();
+        Target::from(&mut Cursor::new(1, &line))
+    }
+
+    fn part1(target: &Target) -> Result<Answer, String> {
+        let hits = fire_shots(target);
+
+        Ok(hits
+            .iter()
+            .map(|x| x.path.iter().map(|p| p.1).max().unwrap_or(0))
+            .max()
+            .unwrap()
+            .into())
+    }
+
+    fn part2(target: &Target) -> Result<Answer, String> {
+        let hits = fire_shots(target);
+
+        Ok(hits.len().into())
+    }
+}
+
+/// The trench area the probe has to land in
+#[derive(Debug, Clone, Copy)]
+pub struct Target {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
+
+// How far away a target can be. Every velocity that could reach it is tried,
+// so much further would take too long, and the highest shots would climb out
+// of an i32.
+const MAX_DISTANCE: i32 = 10_000;
+
+impl Target {
+    /// Reads a target ahead of the probe and below it
+    fn from(c: &mut Cursor) -> Result<Target, ParseError> {
+        c.literal("target area: x=")?;
+        let x_min = bounded(c, 1, MAX_DISTANCE)?;
+        c.literal("..")?;
+        let x_max = bounded(c, x_min, MAX_DISTANCE)?;
+        c.literal(", y=")?;
+        let y_min = bounded(c, -MAX_DISTANCE, -1)?;
+        c.literal("..")?;
+        let y_max = bounded(c, y_min, -1)?;
+        c.end()?;
+
+        Ok(Target {
+            x_min,
+            x_max,
+            y_min,
+            y_max,
+        })
+    }
+
+    fn check_point(&self, x: i32, y: i32) -> ShotResult {
+        if x >= self.x_min && x <= self.x_max && y >= self.y_min && y <= self.y_max {
+            ShotResult::Hit
+        } else if x > self.x_max || y < self.y_min {
+            if x >= self.x_min {
+                ShotResult::Miss(MissType::Overshot)
+            } else {
+                ShotResult::Miss(MissType::Undershot)
+            }
+        } else {
+            ShotResult::OnTarget
+        }
+    }
+}
+
+// A number from `min` to `max`, with the error pointing at it if it isn't
+fn bounded(c: &mut Cursor, min: i32, max: i32) -> Result<i32, ParseError> {
+    let error = c.error(format!("a number from {} to {}", min, max));
+    match c.number()? {
+        n if (min..=max).contains(&n) => Ok(n),
+        _ => Err(error),
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum MissType {
+    Overshot,
+    Undershot,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+enum ShotResult {
+    Hit,
+    Miss(MissType),
+    OnTarget,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+struct Shot {
+    x: i32,
+    y: i32,
+    path: Vec<(i32, i32)>,
+    result: ShotResult,
+}
+
+fn trajectory(vel: (i32, i32), target: &Target) -> Shot {
+    let mut shot = Shot {
+        x: vel.0,
+        y: vel.1,
+        path: Vec::new(),
+        result: ShotResult::OnTarget,
+    };
+    let mut x = 0;
+    let mut y = 0;
+    let mut vel_x = vel.0;
+    let mut vel_y = vel.1;
+
+    while shot.result == ShotResult::OnTarget {
+        x += vel_x;
+        y += vel_y;
+
+        shot.path.push((x, y));
+
+        if vel_x > 0 {
+            vel_x -= 1;
+        } else if vel_x < 0 {
+            vel_x += 1;
+        }
+
+        vel_y -= 1;
+
+        shot.result = target.check_point(x, y);
+    }
+
+    shot
+}
+
+fn fire_shots(target: &Target) -> Vec<Shot> {
+    let mut hits = Vec::new();
+
+    // Fire shots until the x and y velocities are such that it will
+    // never hit the target
+    for vel_y in target.y_min..=-target.y_min {
+        // Fire shots until the x velocity is such that it will
+        // never hit the target
+        for vel_x in -target.x_max..=target.x_max {
+            let shot = trajectory((vel_x, vel_y), target);
+
+            if shot.result == ShotResult::Hit {
+                hits.push(shot);
+            }
+        }
+    }
+
+    hits
+}
+
+#[test]
+fn day17_part1_test() {
+    let v = vec![String::from("target area: x=20..30, y=-10..-5")];
+    let answer = Day17::part1(&Day17::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(45, answer);
+}
+
+#[test]
+fn day17_part2_test() {
+    let v = vec![String::from("target area: x=20..30, y=-10..-5")];
+    let answer = Day17::part2(&Day17::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(112, answer);
+}
+
+#[test]
+fn day17_parse_error_test() {
+    let parse = |s: &str| Day17::parse(vec![String::from(s)].into_iter()).err();
+
+    assert_eq!(
+        Some(ParseError::new(1, 16, "a number from 1 to 10000")),
+        parse("target area: x=-30..-20, y=-10..-5")
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 20, "a number from 30 to 10000")),
+        parse("target area: x=30..20, y=-10..-5")
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 26, "a number from -10000 to -1")),
+        parse("target area: x=20..30, y=5..10")
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 31, "a number from -10 to -1")),
+        parse("target area: x=20..30, y=-10..5")
+    );
+    assert_eq!(Some(ParseError::new(1, 1, "`target area: x=`")), parse(""));
+}
+
+/// A target ahead of the probe and below it, ending at most `reach` steps
+/// forward and `depth` steps down
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let reach = params.at_least("reach", 200, 2).min(MAX_DISTANCE as usize) as i64;
+    let depth = params.at_least("depth", 150, 2).min(MAX_DISTANCE as usize) as i64;
+
+    let x_max = rng.range(reach / 2, reach);
+    let x_min = rng.range(1, x_max);
+    let y_min = -rng.range(depth / 2, depth);
+    let y_max = rng.range(y_min, -1);
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min, x_max, y_min, y_max
+    )
+}
+
+#[test]
+fn day17_generate_test() {
+    let params = Params::parse(&[String::from("reach=40"), String::from("depth=20")]).unwrap();
+    let input = generate(&mut Rng::new(17), &params);
+    let target = Day17::parse(input.lines().map(String::from)).unwrap();
+    assert!(0 < target.x_min && target.x_max <= 40);
+    assert!(-20 <= target.y_min && target.y_max < 0);
+    assert_ne!(0, Day17::part2(&target).unwrap());
+}
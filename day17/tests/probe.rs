@@ -0,0 +1,27 @@
+use aoc_core::{ParseError, Solution};
+use day17::{Day17, Target};
+
+fn target(input: &str) -> Result<Target, ParseError> {
+    Day17::parse([input].into_iter().map(String::from))
+}
+
+#[test]
+fn shots_test() {
+    let example = target("target area: x=20..30, y=-10..-5").unwrap();
+    assert_eq!(45, Day17::part1(&example).unwrap());
+    assert_eq!(112, Day17::part2(&example).unwrap());
+
+    // The only way in is straight there in one step
+    let point = target("target area: x=1..1, y=-1..-1").unwrap();
+    assert_eq!(0, Day17::part1(&point).unwrap());
+    assert_eq!(2, Day17::part2(&point).unwrap());
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(
+        Some(ParseError::new(1, 26, "a number from -10000 to -1")),
+        target("target area: x=20..30, y=5..10").err()
+    );
+    assert!(target("target area: x=30..20, y=-10..-5").is_err());
+}
@@ -0,0 +1,526 @@
+//! Day 18: Snailfish. Numbers are kept as flat [`Tokens`] while they're added
+//! and reduced, then built into a [`Node`] tree to take their magnitude.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+use std::vec;
+
+/// The magnitude of the whole sum, then the largest from adding any two
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Tokens>;
+    const DAY: u8 = 18;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<Tokens>, ParseError> {
+        let numbers = v
+            .enumerate()
+            .map(|(i, line)| tokenize(i + 1, &line))
+            .collect::<Result<Vec<_>, _>>()?;
+        match numbers.is_empty() {
+            true => Err(ParseError::new(1, 1, "a snailfish number")),
+            false => Ok(numbers),
+        }
+    }
+
+    fn part1(numbers: &Vec<Tokens>) -> Result<Answer, String> {
+        let mut iter = numbers.iter().cloned();
+        let mut left = iter.next().unwrap();
+        for right in iter {
+            left = add(left, right);
+        }
+
+        let node = Node::from(left.into_iter());
+        Ok(node.magnitude().into())
+    }
+
+    fn part2(numbers: &Vec<Tokens>) -> Result<Answer, String> {
+        let mut max = 0;
+
+        for (i, left) in numbers.iter().enumerate() {
+            for (j, right) in numbers.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let sum = add(left.clone(), right.clone());
+                let node = Node::from(sum.into_iter());
+                let magnitude = node.magnitude();
+
+                if magnitude > max {
+                    max = magnitude;
+                }
+            }
+        }
+
+        Ok(max.into())
+    }
+}
+
+/// One piece of a snailfish number as written: `[`, `]` or a regular number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    /// `[`
+    Open,
+    /// `]`
+    Close,
+    /// A regular number
+    Value(i32),
+}
+
+/// A whole snailfish number, token by token
+pub type Tokens = Vec<Token>;
+
+/// Reads a snailfish number written on `line` of the input
+pub fn tokenize(line: usize, text: &str) -> Result<Tokens, ParseError> {
+    let mut c = Cursor::new(line, text);
+    let mut tokens = Vec::new();
+
+    tokenize_pair(&mut c, &mut tokens, 1)?;
+    c.end()?;
+
+    Ok(tokens)
+}
+
+// Much deeper than any number can get before it explodes, but shallow enough
+// that a hostile line can't overflow the stack
+const MAX_DEPTH: usize = 64;
+
+fn tokenize_pair(c: &mut Cursor, tokens: &mut Tokens, depth: usize) -> Result<(), ParseError> {
+    if depth > MAX_DEPTH {
+        return Err(c.error(format!("at most {} nested pairs", MAX_DEPTH)));
+    }
+    c.literal("[")?;
+    tokens.push(Token::Open);
+    tokenize_element(c, tokens, depth)?;
+    c.literal(",")?;
+    tokenize_element(c, tokens, depth)?;
+    c.literal("]")?;
+    tokens.push(Token::Close);
+
+    Ok(())
+}
+
+fn tokenize_element(c: &mut Cursor, tokens: &mut Tokens, depth: usize) -> Result<(), ParseError> {
+    match c.peek() {
+        Some('[') => tokenize_pair(c, tokens, depth + 1),
+        Some('0'..='9') => {
+            tokens.push(Token::Value(c.number()?));
+            Ok(())
+        }
+        _ => Err(c.error("a number or `[`")),
+    }
+}
+
+/// Writes the `,` that goes between `prev` and whatever follows it
+pub fn add_comma(prev: Option<&Token>, result: &mut String) {
+    match prev {
+        Some(&Token::Value(_)) => (*result).push(','),
+        Some(&Token::Close) => (*result).push(','),
+        _ => (),
+    }
+}
+
+/// Writes a number back out the way it's read
+pub fn stringify(tokens: &Tokens) -> String {
+    let mut result = String::new();
+    let mut prev: Option<&Token> = None;
+    for token in tokens {
+        match token {
+            Token::Open => {
+                add_comma(prev, &mut result);
+                result.push('[')
+            }
+            Token::Close => result.push(']'),
+            Token::Value(number) => {
+                add_comma(prev, &mut result);
+                result.push_str(&number.to_string())
+            }
+        }
+        prev = Some(token)
+    }
+    result
+}
+
+fn explode(tokens: &Tokens) -> Option<Tokens> {
+    let mut depth = 0;
+    let mut exploded = vec![];
+    let mut iter = tokens.iter();
+    let mut did_explode = false;
+
+    while let Some(token) = iter.next() {
+        match token {
+            Token::Open => {
+                depth += 1;
+                exploded.push(token.clone());
+            }
+            Token::Close => {
+                depth -= 1;
+                exploded.push(token.clone());
+            }
+            Token::Value(val_left) => {
+                if depth != 5 || did_explode {
+                    exploded.push(token.clone());
+                    continue;
+                }
+
+                // At depth > 4, explode
+                let val_right = match iter.next().unwrap() {
+                    Token::Value(val) => val,
+                    _ => panic!("Expected value"),
+                };
+
+                // Add left value to previous value, if any
+                let mut restore = vec![];
+                while let Some(left) = exploded.pop() {
+                    match left {
+                        Token::Value(v) => {
+                            restore.push(Token::Value(v + val_left));
+                            break;
+                        }
+                        tok => restore.push(tok),
+                    }
+                }
+                for tok in restore.into_iter().rev() {
+                    exploded.push(tok);
+                }
+
+                // Replace empty pair with 0
+                exploded.pop();
+                exploded.push(Token::Value(0));
+                iter.next();
+
+                // Add value to next value, if any
+                for right in iter.by_ref() {
+                    match right {
+                        Token::Value(v) => {
+                            exploded.push(Token::Value(v + val_right));
+                            break;
+                        }
+                        tok => exploded.push(tok.clone()),
+                    }
+                }
+                did_explode = true;
+            }
+        }
+    }
+
+    if did_explode {
+        Some(exploded)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn explode_test() {
+    // null case
+    assert!(explode(&tokenize(1, "[[[0,9],2],3]").unwrap()).is_none());
+    assert_eq!(
+        "[[[[0,9],2],3],4]",
+        stringify(&explode(&tokenize(1, "[[[[[9,8],1],2],3],4]").unwrap()).unwrap())
+    );
+    assert_eq!(
+        "[7,[6,[5,[7,0]]]]",
+        stringify(&explode(&tokenize(1, "[7,[6,[5,[4,[3,2]]]]]").unwrap()).unwrap())
+    );
+    assert_eq!(
+        "[[6,[5,[7,0]]],3]",
+        stringify(&explode(&tokenize(1, "[[6,[5,[4,[3,2]]]],1]").unwrap()).unwrap())
+    );
+    assert_eq!(
+        "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]",
+        stringify(
+            &explode(&tokenize(1, "[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]").unwrap()).unwrap()
+        )
+    );
+    assert_eq!(
+        "[[3,[2,[8,0]]],[9,[5,[7,0]]]]",
+        stringify(&explode(&tokenize(1, "[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]").unwrap()).unwrap())
+    );
+    assert_eq!(
+        "[[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+        stringify(
+            &explode(&tokenize(1, "[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]").unwrap()).unwrap()
+        )
+    );
+}
+
+fn split(tokens: &Tokens) -> Option<Tokens> {
+    let mut split = vec![];
+    let mut did_split = false;
+
+    for token in tokens {
+        match token {
+            Token::Open => {
+                split.push(token.clone());
+            }
+            Token::Close => {
+                split.push(token.clone());
+            }
+            Token::Value(value) => {
+                if *value >= 10 && !did_split {
+                    split.push(Token::Open);
+                    split.push(Token::Value(value / 2));
+                    split.push(Token::Value((value / 2) + (value % 2)));
+                    split.push(Token::Close);
+                    did_split = true;
+                } else {
+                    split.push(token.clone());
+                }
+            }
+        }
+    }
+
+    if did_split {
+        Some(split)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn split_tests() {
+    assert_eq!(
+        "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+        stringify(&split(&tokenize(1, "[[[[0,7],4],[15,[0,13]]],[1,1]]").unwrap()).unwrap())
+    );
+    assert_eq!(
+        "[[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+        stringify(&split(&tokenize(1, "[[[[0,7],4],[[7,8],[0,13]]],[1,1]]").unwrap()).unwrap())
+    )
+}
+
+/// Adds two numbers and reduces the sum
+pub fn add(left: Tokens, right: Tokens) -> Tokens {
+    let mut cur = vec![Token::Open];
+    cur.append(&mut left.clone());
+    cur.append(&mut right.clone());
+    cur.push(Token::Close);
+
+    loop {
+        if let Some(exploded) = explode(&cur) {
+            cur = exploded;
+        } else if let Some(split) = split(&cur) {
+            cur = split;
+        } else {
+            break;
+        }
+    }
+    cur
+}
+
+#[test]
+fn add_tests() {
+    assert_eq!(
+        "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+        stringify(&add(
+            tokenize(1, "[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap(),
+            tokenize(1, "[1,1]").unwrap(),
+        ))
+    )
+}
+
+/// A snailfish number as a tree, see the [`fishy!`] macro for writing one out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// A regular number
+    Val(i32),
+    /// A pair of numbers, left then right
+    Pair(Box<Node>, Box<Node>),
+}
+
+impl Node {
+    /// Builds the tree for a whole number, as returned by [`tokenize`] or [`add`].
+    /// Panics if the tokens don't make up one number.
+    pub fn from(tokens: impl Iterator<Item = Token>) -> Node {
+        let mut stack = vec![];
+
+        for next in tokens {
+            match next {
+                Token::Open => {
+                    stack.push(Node::Pair(Box::new(Node::Val(0)), Box::new(Node::Val(0))));
+                }
+                Token::Value(value) => {
+                    stack.push(Node::Val(value));
+                }
+                Token::Close => {
+                    let right = stack.pop().unwrap();
+                    let left = stack.pop().unwrap();
+                    stack.pop(); // Pair
+
+                    stack.push(Node::Pair(Box::new(left), Box::new(right)));
+                }
+            }
+        }
+
+        stack.pop().unwrap()
+    }
+
+    /// Three times the left's magnitude plus twice the right's, down to the
+    /// regular numbers
+    pub fn magnitude(&self) -> i32 {
+        match self {
+            Node::Val(v) => *v,
+            Node::Pair(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+}
+
+/// Writes a [`Node`] the way the number looks, e.g. `fishy![[1, 2], 3]`
+#[macro_export]
+macro_rules! fishy {
+    // Terminating case
+    () => (());
+
+    // Literal value
+    ($v:literal) => ($crate::Node::Val($v));
+
+    // Initial case (allows us to use [] as start/end tokens for the macro)
+    ($($left:tt)?, $($right:tt)?) => {{
+        $crate::Node::Pair(Box::new($crate::fishy!($($left)?)), Box::new($crate::fishy!($($right)?)))
+    }};
+
+    // Unpack an array into Pair expressions
+    ([ $($left:tt)?, $($right:tt)? ]) => {{
+        $crate::Node::Pair(Box::new($crate::fishy!($($left)?)), Box::new($crate::fishy!($($right)?)))
+    }};
+}
+
+#[test]
+fn node_from_test() {
+    assert_eq!(
+        fishy![[1, 2], 3],
+        Node::from(tokenize(1, "[[1,2],3]").unwrap().into_iter()),
+    );
+
+    assert_eq!(
+        fishy![[1, 2], [[3, 4], 5]],
+        Node::from(tokenize(1, "[[1,2],[[3,4],5]]").unwrap().into_iter()),
+    );
+}
+
+#[test]
+fn tokenize_error_test() {
+    assert_eq!(
+        ParseError::new(1, 9, "`]`"),
+        tokenize(1, "[[1,2],3").unwrap_err()
+    );
+    assert_eq!(
+        ParseError::new(2, 4, "a number or `[`"),
+        tokenize(2, "[1,x]").unwrap_err()
+    );
+
+    let deep = "[".repeat(MAX_DEPTH + 1) + &"1,2]".repeat(MAX_DEPTH + 1);
+    assert_eq!(
+        ParseError::new(3, MAX_DEPTH + 1, "at most 64 nested pairs"),
+        tokenize(3, &deep).unwrap_err()
+    );
+}
+
+// A snailfish number as text: always a pair, with pairs or regular numbers inside
+#[cfg(test)]
+fn number() -> impl proptest::strategy::Strategy<Value = String> {
+    use proptest::prelude::*;
+
+    let pair = |(left, right): (String, String)| format!("[{},{}]", left, right);
+    let element =
+        (0..=i32::MAX)
+            .prop_map(|n| n.to_string())
+            .prop_recursive(8, 128, 2, move |inner| {
+                (inner.clone(), inner).prop_map(pair)
+            });
+    (element.clone(), element).prop_map(pair)
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn day18_round_trip_test(number in number()) {
+        let tokens = tokenize(1, &number).unwrap();
+        proptest::prop_assert_eq!(&number, &stringify(&tokens));
+        proptest::prop_assert_eq!(Ok(tokens.clone()), tokenize(1, &stringify(&tokens)));
+    }
+}
+
+#[test]
+fn day18_part1_test() {
+    let v = vec![
+        String::from("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]"),
+        String::from("[[[5,[2,8]],4],[5,[[9,9],0]]]"),
+        String::from("[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]"),
+        String::from("[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]"),
+        String::from("[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]"),
+        String::from("[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]"),
+        String::from("[[[[5,4],[7,7]],8],[[8,3],8]]"),
+        String::from("[[9,3],[[9,9],[6,[4,9]]]]"),
+        String::from("[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]"),
+        String::from("[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"),
+    ];
+    let answer = Day18::part1(&Day18::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(4140, answer);
+}
+
+#[test]
+fn day18_part2_test() {
+    let v = vec![
+        String::from("[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]"),
+        String::from("[[[5,[2,8]],4],[5,[[9,9],0]]]"),
+        String::from("[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]"),
+        String::from("[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]"),
+        String::from("[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]"),
+        String::from("[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]"),
+        String::from("[[[[5,4],[7,7]],8],[[8,3],8]]"),
+        String::from("[[9,3],[[9,9],[6,[4,9]]]]"),
+        String::from("[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]"),
+        String::from("[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"),
+    ];
+    let answer = Day18::part2(&Day18::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(3993, answer);
+}
+
+#[test]
+fn day18_parse_error_test() {
+    let parse = |s: &str| Day18::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(Some(ParseError::new(1, 1, "a snailfish number")), parse(""));
+    assert_eq!(
+        Some(ParseError::new(2, 4, "a number or `[`")),
+        parse("[1,2]\n[1,x]")
+    );
+}
+
+/// Snailfish numbers nested at most four deep, as they are once reduced
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    fn pair(rng: &mut Rng, depth: usize) -> String {
+        let element = |rng: &mut Rng| {
+            if depth == 1 || rng.chance(40) {
+                rng.below(10).to_string()
+            } else {
+                pair(rng, depth - 1)
+            }
+        };
+        let left = element(rng);
+        format!("[{},{}]", left, element(rng))
+    }
+
+    let numbers = params.at_least("numbers", 100, 1);
+    let mut out = String::new();
+    for _ in 0..numbers {
+        out += &pair(rng, 4);
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn day18_generate_test() {
+    let params = Params::parse(&[String::from("numbers=10")]).unwrap();
+    let input = generate(&mut Rng::new(18), &params);
+    let numbers = Day18::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(10, numbers.len());
+    Day18::part2(&numbers).unwrap();
+}
@@ -0,0 +1,27 @@
+use day18::{add, fishy, stringify, tokenize, Node};
+
+#[test]
+fn add_test() {
+    let sum = add(
+        tokenize(1, "[[[[4,3],4],4],[7,[[8,4],9]]]").unwrap(),
+        tokenize(2, "[1,1]").unwrap(),
+    );
+    assert_eq!("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", stringify(&sum));
+    assert_eq!(
+        fishy![[[[0, 7], 4], [[7, 8], [6, 0]]], [8, 1]],
+        Node::from(sum.into_iter())
+    );
+}
+
+#[test]
+fn magnitude_test() {
+    let number = tokenize(1, "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").unwrap();
+    assert_eq!(3488, Node::from(number.into_iter()).magnitude());
+    assert_eq!(29, fishy![9, 1].magnitude());
+}
+
+#[test]
+fn tokenize_error_test() {
+    let error = tokenize(3, "[1,2").unwrap_err();
+    assert_eq!("line 3, column 5: expected `]`", error.to_string());
+}
@@ -0,0 +1,595 @@
+//! Day 19: Beacon Scanner. [`transpose_all_scanners`] turns and moves every
+//! [`Scanner`]'s report into the frame of the first one.
+
+use aoc_core::{
+    diag,
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashSet};
+
+/// Counts the beacons, then the widest distance between two scanners
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Scanner>;
+    const DAY: u8 = 19;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<Scanner>, ParseError> {
+        let lines = v.enumerate().map(|(i, x)| (i + 1, x)).collect::<Vec<_>>();
+        let scanner_lines = lines.split(|(_, x)| x.is_empty()).collect::<Vec<_>>();
+        scanner_lines
+            .iter()
+            .filter(|lines| !lines.is_empty())
+            .zip(0..)
+            .map(|(lines, id)| Scanner::from(lines, id))
+            .collect()
+    }
+
+    fn part1(scanners: &Vec<Scanner>) -> Result<Answer, String> {
+        Ok(day19(scanners)?.0.into())
+    }
+
+    fn part2(scanners: &Vec<Scanner>) -> Result<Answer, String> {
+        Ok(day19(scanners)?.1.into())
+    }
+}
+
+const X: usize = 0;
+const Y: usize = 1;
+const Z: usize = 2;
+
+static SWAPS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 1, 0],
+    [2, 0, 1],
+];
+
+static FLIPS: [[i32; 3]; 8] = [
+    [1, 1, 1],
+    [-1, 1, 1],
+    [1, -1, 1],
+    [1, 1, -1],
+    [1, -1, -1],
+    [-1, -1, 1],
+    [-1, 1, -1],
+    [-1, -1, -1],
+];
+
+/// A point in space as x, y and z
+pub type Pos = [i32; 3];
+
+/// A scanner and the beacons it can see
+#[derive(Debug, Clone)]
+pub struct Scanner {
+    /// The scanner's number in the report, from 0
+    pub id: i32,
+    /// Where the scanner is in the frame it was transposed into, or the origin
+    /// of its own frame before that
+    pub pos: Pos,
+    /// Beacon positions, in the same frame as `pos`
+    pub beacons: Vec<Pos>,
+}
+
+fn rel_magnitudes(beacons: &[Pos], beacon: &Pos) -> Vec<(usize, Pos)> {
+    beacons
+        .iter()
+        .enumerate()
+        .map(|(i, pos)| {
+            (
+                i,
+                [
+                    (beacon[X] - pos[X]).abs(),
+                    (beacon[Y] - pos[Y]).abs(),
+                    (beacon[Z] - pos[Z]).abs(),
+                ],
+            )
+        })
+        .collect::<Vec<_>>()
+}
+
+impl Scanner {
+    // Scanners are numbered from 0 in the order they appear
+    fn from(lines: &[(usize, String)], id: i32) -> Result<Scanner, ParseError> {
+        let (header, beacon_lines) = lines.split_first().unwrap();
+
+        let mut c = Cursor::new(header.0, &header.1);
+        c.literal("--- scanner ")?;
+        let error = c.error(format!("scanner number {}", id));
+        if c.number::<i32>()? != id {
+            return Err(error);
+        }
+        c.literal(" ---")?;
+        c.end()?;
+
+        let mut beacons = Vec::new();
+        for (i, line) in beacon_lines {
+            let mut c = Cursor::new(*i, line);
+            let x = c.number()?;
+            c.literal(",")?;
+            let y = c.number()?;
+            c.literal(",")?;
+            let z = c.number()?;
+            c.end()?;
+            beacons.push([x, y, z]);
+        }
+
+        Ok(Scanner {
+            id,
+            beacons,
+            // Guesses
+            pos: [0, 0, 0],
+        })
+    }
+
+    /// `other` turned and moved into this scanner's frame, if the two see at
+    /// least 12 beacons in common
+    pub fn transpose_onto(&self, other: &Scanner) -> Option<Scanner> {
+        self.beacons.par_iter().find_map_any(|&beacon| {
+            let relative_magnitudes_self = rel_magnitudes(&self.beacons, &beacon);
+
+            for flip in FLIPS {
+                for swap in SWAPS {
+                    let translated = other.translate(flip, swap);
+
+                    let scanner = translated.par_iter().find_map_any(|&other_beacon| {
+                        let mut overlaps = Vec::new();
+
+                        let relative_magnitudes_other = rel_magnitudes(&translated, &other_beacon);
+
+                        for (j, mag) in relative_magnitudes_other.iter() {
+                            if let Some((i, _)) = relative_magnitudes_self
+                                .iter()
+                                .find(|(_, other)| *mag == *other)
+                            {
+                                overlaps.push((*i, *j));
+                            }
+                        }
+
+                        if overlaps.len() >= 12 {
+                            let mut offsets = overlaps.iter().map(|(i, j)| {
+                                [
+                                    (self.beacons[*i][0] - translated[*j][0]),
+                                    (self.beacons[*i][1] - translated[*j][1]),
+                                    (self.beacons[*i][2] - translated[*j][2]),
+                                ]
+                            });
+
+                            let offset_0 = offsets.next().unwrap();
+                            if offsets.skip(1).all(|o| o == offset_0) {
+                                let beacons = translated
+                                    .iter()
+                                    .map(|b| {
+                                        [b[0] + offset_0[0], b[1] + offset_0[1], b[2] + offset_0[2]]
+                                    })
+                                    .collect();
+
+                                return Some(Scanner {
+                                    id: other.id,
+                                    pos: offset_0,
+                                    beacons,
+                                });
+                            }
+                        }
+
+                        None
+                    });
+
+                    if scanner.is_some() {
+                        return scanner;
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    fn translate(&self, flip: [i32; 3], swap: [usize; 3]) -> Vec<Pos> {
+        self.beacons
+            .iter()
+            .map(|pos| {
+                [
+                    flip[X] * pos[swap[X]],
+                    flip[Y] * pos[swap[Y]],
+                    flip[Z] * pos[swap[Z]],
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Every scanner in the frame of the first one, sorted by id. Scanners that
+/// don't overlap any of the others are left out.
+pub fn transpose_all_scanners(scanners: &[Scanner]) -> Vec<Scanner> {
+    let Some(first) = scanners.first() else {
+        return vec![];
+    };
+    let mut rotated = vec![first.clone()];
+
+    let mut tried = HashSet::new();
+    let (mut passes, mut skipped) = (0, 0);
+
+    while rotated.len() < scanners.len() {
+        let cur_rot = rotated.clone();
+        passes += 1;
+
+        'found: for scanner in cur_rot.iter() {
+            for other in scanners.iter() {
+                if tried.contains(&(scanner.id, other.id))
+                    || cur_rot.iter().any(|s| s.id == other.id)
+                {
+                    skipped += 1;
+                    continue;
+                }
+
+                let next = scanner.transpose_onto(other);
+                tried.insert((scanner.id, other.id));
+                if let Some(next) = next {
+                    rotated.push(next);
+                    continue 'found;
+                }
+            }
+        }
+        if rotated.len() == cur_rot.len() {
+            break;
+        }
+    }
+
+    diag::record("scanner_matches", rotated.len() as u64 - 1);
+    diag::record("overlap_checks", tried.len() as u64);
+    diag::record("pairs_skipped", skipped);
+    diag::record("passes", passes);
+    rotated.sort_by_key(|s| s.id);
+    rotated
+}
+
+fn day19(scanners: &[Scanner]) -> Result<(i32, i32), String> {
+    let rotated = transpose_all_scanners(scanners);
+    if let Some(lost) = scanners
+        .iter()
+        .find(|s| !rotated.iter().any(|r| r.id == s.id))
+    {
+        return Err(format!(
+            "Scanner {} can't be placed: it doesn't see 12 beacons in common with any other",
+            lost.id
+        ));
+    }
+    let mut beacons: HashSet<Pos> = HashSet::new();
+
+    let mut max_dist = 0;
+
+    for a in rotated.iter() {
+        beacons.extend(a.beacons.iter().cloned());
+        for b in rotated.iter() {
+            let dist = (a.pos[X] - b.pos[X]).abs()
+                + (a.pos[Y] - b.pos[Y]).abs()
+                + (a.pos[Z] - b.pos[Z]).abs();
+            if dist > max_dist {
+                max_dist = dist;
+            }
+        }
+    }
+
+    Ok((beacons.len() as i32, max_dist))
+}
+
+#[test]
+fn day19_test() {
+    let v = get_test_input();
+    let answer = day19(&Day19::parse(v.into_iter()).unwrap());
+
+    assert_eq!(Ok((79, 3621)), answer);
+
+    let apart = "--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6";
+    let apart = Day19::parse(apart.lines().map(String::from)).unwrap();
+    assert_eq!(
+        Err(String::from(
+            "Scanner 1 can't be placed: it doesn't see 12 beacons in common with any other"
+        )),
+        day19(&apart)
+    );
+}
+
+#[test]
+fn day19_parse_error_test() {
+    let parse = |s: &str| Day19::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(4, 13, "scanner number 1")),
+        parse("--- scanner 0 ---\n1,2,3\n\n--- scanner 2 ---\n4,5,6")
+    );
+    assert_eq!(
+        Some(ParseError::new(2, 4, "`,`")),
+        parse("--- scanner 0 ---\n1,2\n")
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 1, "`--- scanner `")),
+        parse("1,2,3")
+    );
+}
+
+#[test]
+fn overlaps_test() {
+    let scanners = Day19::parse(get_test_input().into_iter()).unwrap();
+    let [scanner_0, scanner_1, scanner_2, _, scanner_4] = &scanners[..] else {
+        panic!("Expected 5 scanners");
+    };
+
+    let rotated = scanner_0.transpose_onto(scanner_1).unwrap();
+
+    assert_eq!(rotated.pos, [68, -1246, -43]);
+
+    let rotated_2 = rotated.transpose_onto(scanner_4).unwrap();
+
+    assert_eq!(rotated_2.pos, [-20, -1133, 1061]);
+
+    let rotated_3 = rotated_2.transpose_onto(scanner_2).unwrap();
+    assert_eq!(rotated_3.pos, [1105, -1205, 1229]);
+}
+
+#[allow(unused)]
+fn get_test_input() -> Vec<String> {
+    vec![
+        String::from("--- scanner 0 ---"),
+        String::from("404,-588,-901"),
+        String::from("528,-643,409"),
+        String::from("-838,591,734"),
+        String::from("390,-675,-793"),
+        String::from("-537,-823,-458"),
+        String::from("-485,-357,347"),
+        String::from("-345,-311,381"),
+        String::from("-661,-816,-575"),
+        String::from("-876,649,763"),
+        String::from("-618,-824,-621"),
+        String::from("553,345,-567"),
+        String::from("474,580,667"),
+        String::from("-447,-329,318"),
+        String::from("-584,868,-557"),
+        String::from("544,-627,-890"),
+        String::from("564,392,-477"),
+        String::from("455,729,728"),
+        String::from("-892,524,684"),
+        String::from("-689,845,-530"),
+        String::from("423,-701,434"),
+        String::from("7,-33,-71"),
+        String::from("630,319,-379"),
+        String::from("443,580,662"),
+        String::from("-789,900,-551"),
+        String::from("459,-707,401"),
+        String::from(""),
+        String::from("--- scanner 1 ---"),
+        String::from("686,422,578"),
+        String::from("605,423,415"),
+        String::from("515,917,-361"),
+        String::from("-336,658,858"),
+        String::from("95,138,22"),
+        String::from("-476,619,847"),
+        String::from("-340,-569,-846"),
+        String::from("567,-361,727"),
+        String::from("-460,603,-452"),
+        String::from("669,-402,600"),
+        String::from("729,430,532"),
+        String::from("-500,-761,534"),
+        String::from("-322,571,750"),
+        String::from("-466,-666,-811"),
+        String::from("-429,-592,574"),
+        String::from("-355,545,-477"),
+        String::from("703,-491,-529"),
+        String::from("-328,-685,520"),
+        String::from("413,935,-424"),
+        String::from("-391,539,-444"),
+        String::from("586,-435,557"),
+        String::from("-364,-763,-893"),
+        String::from("807,-499,-711"),
+        String::from("755,-354,-619"),
+        String::from("553,889,-390"),
+        String::from(""),
+        String::from("--- scanner 2 ---"),
+        String::from("649,640,665"),
+        String::from("682,-795,504"),
+        String::from("-784,533,-524"),
+        String::from("-644,584,-595"),
+        String::from("-588,-843,648"),
+        String::from("-30,6,44"),
+        String::from("-674,560,763"),
+        String::from("500,723,-460"),
+        String::from("609,671,-379"),
+        String::from("-555,-800,653"),
+        String::from("-675,-892,-343"),
+        String::from("697,-426,-610"),
+        String::from("578,704,681"),
+        String::from("493,664,-388"),
+        String::from("-671,-858,530"),
+        String::from("-667,343,800"),
+        String::from("571,-461,-707"),
+        String::from("-138,-166,112"),
+        String::from("-889,563,-600"),
+        String::from("646,-828,498"),
+        String::from("640,759,510"),
+        String::from("-630,509,768"),
+        String::from("-681,-892,-333"),
+        String::from("673,-379,-804"),
+        String::from("-742,-814,-386"),
+        String::from("577,-820,562"),
+        String::from(""),
+        String::from("--- scanner 3 ---"),
+        String::from("-589,542,597"),
+        String::from("605,-692,669"),
+        String::from("-500,565,-823"),
+        String::from("-660,373,557"),
+        String::from("-458,-679,-417"),
+        String::from("-488,449,543"),
+        String::from("-626,468,-788"),
+        String::from("338,-750,-386"),
+        String::from("528,-832,-391"),
+        String::from("562,-778,733"),
+        String::from("-938,-730,414"),
+        String::from("543,643,-506"),
+        String::from("-524,371,-870"),
+        String::from("407,773,750"),
+        String::from("-104,29,83"),
+        String::from("378,-903,-323"),
+        String::from("-778,-728,485"),
+        String::from("426,699,580"),
+        String::from("-438,-605,-362"),
+        String::from("-469,-447,-387"),
+        String::from("509,732,623"),
+        String::from("647,635,-688"),
+        String::from("-868,-804,481"),
+        String::from("614,-800,639"),
+        String::from("595,780,-596"),
+        String::from(""),
+        String::from("--- scanner 4 ---"),
+        String::from("727,592,562"),
+        String::from("-293,-554,779"),
+        String::from("441,611,-461"),
+        String::from("-714,465,-776"),
+        String::from("-743,427,-804"),
+        String::from("-660,-479,-426"),
+        String::from("832,-632,460"),
+        String::from("927,-485,-438"),
+        String::from("408,393,-506"),
+        String::from("466,436,-512"),
+        String::from("110,16,151"),
+        String::from("-258,-428,682"),
+        String::from("-393,719,612"),
+        String::from("-211,-452,876"),
+        String::from("808,-476,-593"),
+        String::from("-575,615,604"),
+        String::from("-485,667,467"),
+        String::from("-680,325,-822"),
+        String::from("-627,-443,-432"),
+        String::from("872,-547,-609"),
+        String::from("833,512,582"),
+        String::from("807,604,487"),
+        String::from("839,-516,451"),
+        String::from("891,-625,532"),
+        String::from("-652,-548,-490"),
+        String::from("30,-46,-14"),
+    ]
+}
+
+// How far a scanner can see along each axis
+const RANGE: i32 = 1000;
+
+/// A chain of scanners, each sharing `overlap` beacons with the one before it
+/// and seeing up to `beacons` more of its own. Every scanner reports all the beacons
+/// in range, relative to itself and turned a random way.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let (scanners, beacons) = survey(rng, params);
+
+    // Turns that keep a right-handed coordinate system: even swaps with an
+    // even number of flips, and odd swaps with an odd number
+    let mut turns = vec![];
+    for (i, swap) in SWAPS.iter().enumerate() {
+        for flip in FLIPS {
+            let parity = if [0, 3, 5].contains(&i) { 1 } else { -1 };
+            if flip.iter().product::<i32>() == parity {
+                turns.push((*swap, flip));
+            }
+        }
+    }
+
+    let mut reports = vec![];
+    for (id, scanner) in scanners.iter().enumerate() {
+        let &(swap, flip) = rng.pick(&turns);
+        let mut seen = beacons
+            .iter()
+            .map(|b| [b[X] - scanner[X], b[Y] - scanner[Y], b[Z] - scanner[Z]])
+            .filter(|b| b.iter().all(|d| d.abs() <= RANGE))
+            .map(|b| [0, 1, 2].map(|axis| flip[axis] * b[swap[axis]]))
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut seen);
+
+        let mut report = format!("--- scanner {} ---\n", id);
+        for [x, y, z] in seen {
+            report += &format!("{},{},{}\n", x, y, z);
+        }
+        reports.push(report);
+    }
+    reports.join("\n")
+}
+
+// Where each scanner is, and every beacon, in scanner 0's coordinates
+fn survey(rng: &mut Rng, params: &Params) -> (Vec<Pos>, Vec<Pos>) {
+    let scanners = params.at_least("scanners", 30, 1);
+    let overlap = params.at_least("overlap", 12, 12);
+    let extra = params.get("beacons", 2);
+
+    let within = |rng: &mut Rng, lo: Pos, hi: Pos| {
+        [0, 1, 2].map(|axis| rng.range(lo[axis] as i64, hi[axis] as i64) as i32)
+    };
+    let sees =
+        |scanner: &Pos, at: &Pos| (0..3).all(|axis| (at[axis] - scanner[axis]).abs() <= RANGE);
+
+    let mut positions: Vec<Pos> = vec![[0, 0, 0]];
+    let mut beacons = BTreeSet::new();
+    let mut misses = 0;
+    while positions.len() < scanners {
+        // Near the last scanner, so their cubes overlap by at least 800 on
+        // each axis, but not too near any of them, or every report gets
+        // crowded. A chain that has boxed itself in branches off instead.
+        let parent = match misses {
+            0..=99 => *positions.last().unwrap(),
+            _ => *rng.pick(&positions),
+        };
+        let next = parent.map(|a| a + rng.range(-1200, 1200) as i32);
+        if positions
+            .iter()
+            .any(|p| (0..3).all(|axis| (p[axis] - next[axis]).abs() < RANGE))
+        {
+            misses += 1;
+            continue;
+        }
+        misses = 0;
+
+        let shared_lo = [0, 1, 2].map(|axis| parent[axis].max(next[axis]) - RANGE);
+        let shared_hi = [0, 1, 2].map(|axis| parent[axis].min(next[axis]) + RANGE);
+        for _ in 0..overlap {
+            beacons.insert(within(rng, shared_lo, shared_hi));
+        }
+        positions.push(next);
+    }
+
+    // Beacons only one scanner can see, giving up on scanners that are
+    // surrounded by others
+    for at in &positions {
+        let (lo, hi) = (at.map(|a| a - RANGE), at.map(|a| a + RANGE));
+        let mut placed = 0;
+        for _ in 0..extra * 10 {
+            if placed == extra {
+                break;
+            }
+            let beacon = within(rng, lo, hi);
+            if positions.iter().filter(|p| sees(p, &beacon)).count() == 1 {
+                beacons.insert(beacon);
+                placed += 1;
+            }
+        }
+    }
+    (positions, beacons.into_iter().collect())
+}
+
+#[test]
+fn day19_generate_test() {
+    let params = Params::parse(&[String::from("scanners=3"), String::from("beacons=4")]).unwrap();
+    let input = generate(&mut Rng::new(19), &params);
+    let scanners = Day19::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(3, scanners.len());
+
+    let (positions, beacons) = survey(&mut Rng::new(19), &params);
+    let farthest = positions
+        .iter()
+        .flat_map(|a| {
+            positions
+                .iter()
+                .map(move |b| (0..3).map(|i| (a[i] - b[i]).abs()).sum())
+        })
+        .max()
+        .unwrap();
+    assert_eq!(Ok((beacons.len() as i32, farthest)), day19(&scanners));
+}
@@ -0,0 +1,63 @@
+use aoc_core::Solution;
+use day19::{transpose_all_scanners, Day19, Pos, Scanner};
+
+// Twelve beacons as scanner 0 sees them
+fn beacons() -> Vec<Pos> {
+    (0..12)
+        .map(|i| [i * 37 - 200, i * i * 5 - 300, 150 - i * 23])
+        .collect()
+}
+
+// The same beacons seen from `pos`, turned a quarter round the z axis
+fn seen_from(pos: Pos) -> Vec<Pos> {
+    beacons()
+        .iter()
+        .map(|b| [b[1] - pos[1], pos[0] - b[0], b[2] - pos[2]])
+        .collect()
+}
+
+#[test]
+fn transpose_onto_test() {
+    let origin = Scanner {
+        id: 0,
+        pos: [0, 0, 0],
+        beacons: beacons(),
+    };
+    let other = Scanner {
+        id: 1,
+        pos: [0, 0, 0],
+        beacons: seen_from([500, -40, 1000]),
+    };
+
+    let placed = origin.transpose_onto(&other).unwrap();
+    assert_eq!(1, placed.id);
+    assert_eq!([500, -40, 1000], placed.pos);
+    assert_eq!(beacons(), placed.beacons);
+}
+
+#[test]
+fn transpose_all_scanners_test() {
+    let input = [
+        "--- scanner 0 ---",
+        "0,0,0",
+        "",
+        "--- scanner 1 ---",
+        "1,2,3",
+    ];
+    let mut scanners = Day19::parse(input.iter().map(|s| s.to_string())).unwrap();
+    assert_eq!(vec![[1, 2, 3]], scanners[1].beacons);
+
+    // Scanner 1 overlaps nothing, so only the new one is placed
+    scanners[0].beacons = beacons();
+    scanners.push(Scanner {
+        id: 2,
+        pos: [0, 0, 0],
+        beacons: seen_from([-700, 20, 3]),
+    });
+    let placed = transpose_all_scanners(&scanners);
+    assert_eq!(
+        vec![(0, [0, 0, 0]), (2, [-700, 20, 3])],
+        placed.iter().map(|s| (s.id, s.pos)).collect::<Vec<_>>()
+    );
+    assert!(transpose_all_scanners(&[]).is_empty());
+}
@@ -0,0 +1,264 @@
+//! Day 20: Trench Map. [`Image`] enhances an infinite image with its
+//! algorithm, keeping track of the pixels beyond its edges.
+
+use aoc_core::{
+    anim::Simulation,
+    gen::{Params, Rng},
+    repl::{self, Explore},
+    Answer, Cursor, Grid, ParseError, Solution,
+};
+
+/// Counts the lit pixels after two enhancements, then after fifty
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Image;
+    const DAY: u8 = 20;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Image, ParseError> {
+        Image::from(v)
+    }
+
+    fn part1(image0: &Image) -> Result<Answer, String> {
+        let image1 = image0.enhance();
+        let image2 = image1.enhance();
+
+        Ok(image2.lit().into())
+    }
+
+    fn part2(image: &Image) -> Result<Answer, String> {
+        let mut image = image.enhance();
+
+        for _ in 1..ENHANCEMENTS {
+            image = image.enhance();
+        }
+
+        Ok(image.lit().into())
+    }
+
+    /// The lit pixels after the part's enhancements
+    fn picture(image: &Image, part: u8) -> Option<Grid<usize>> {
+        let times = if part == 1 { 2 } else { ENHANCEMENTS };
+        let mut image = image.enhance();
+
+        for _ in 1..times {
+            image = image.enhance();
+        }
+
+        Some(image.bits.map(|&c| (c == '#') as usize))
+    }
+}
+
+/// How many times part 2 enhances the image
+const ENHANCEMENTS: usize = 50;
+
+/// The enhancement algorithm and the known part of the image. Every pixel
+/// outside that part is the same, dark or lit.
+pub struct Image {
+    alg: Vec<char>,
+    bits: Grid<char>,
+    assume_zero: bool,
+}
+
+const PIXEL: &str = "`.` or `#`";
+
+fn pixel(p: char) -> Option<char> {
+    Some(p).filter(|p| *p == '.' || *p == '#')
+}
+
+impl Image {
+    /// Reads the algorithm, a blank line, then the image
+    pub fn from(v: impl Iterator<Item = String>) -> Result<Image, ParseError> {
+        let mut lines = v.enumerate().map(|(i, line)| (i + 1, line)).peekable();
+
+        let alg_str = lines.next().map(|(_, line)| line).unwrap_or_default();
+        let mut c = Cursor::new(1, &alg_str);
+        let mut alg = vec![];
+        while !c.is_empty() {
+            alg.push(c.char_map(PIXEL, pixel)?);
+        }
+        if alg.len() != 512 {
+            return Err(ParseError::new(1, alg.len() + 1, "512 pixels"));
+        }
+
+        while lines.next_if(|(_, line)| line.is_empty()).is_some() {}
+        let first = lines.peek().map_or(1, |&(i, _)| i);
+        let bits = Grid::parse_from(first, lines.map(|(_, line)| line), PIXEL, pixel)?;
+
+        Ok(Image {
+            alg,
+            bits,
+            assume_zero: false,
+        })
+    }
+
+    fn get_input_pixel(&self, x: isize, y: isize) -> char {
+        match self.bits.get_signed(x, y) {
+            Some(&pixel) => pixel,
+            // Handle the "infinite" case by returning the "zero" value if
+            // requested. This will happen if zero is "#" which will cause the
+            // border to flash. Rather than rendering a solid border out to infinity,
+            // we can just assume it will be set to this when calculating the inner values.
+            None if self.assume_zero => self.alg[0],
+            None => '.',
+        }
+    }
+
+    fn get_output_pixel(&self, x: isize, y: isize) -> char {
+        let mut index = 0;
+        let mut i = 8;
+
+        for iy in y - 1..=y + 1 {
+            for ix in x - 1..=x + 1 {
+                let pixel = self.get_input_pixel(ix, iy);
+                index |= match pixel {
+                    '.' => 0,
+                    '#' => 1 << i,
+                    _ => panic!("Invalid pixel {}", pixel),
+                };
+                i -= 1;
+            }
+        }
+
+        self.alg[index]
+    }
+
+    /// How many pixels are lit in the known part of the image
+    pub fn lit(&self) -> usize {
+        self.bits.iter().filter(|&&c| c == '#').count()
+    }
+
+    /// Runs the algorithm once, over the image and a pixel on every side
+    pub fn enhance(&self) -> Image {
+        // The output grows by one pixel on every side
+        let bits = Grid::from_fn(self.bits.width() + 2, self.bits.height() + 2, |(x, y)| {
+            self.get_output_pixel(x as isize - 1, y as isize - 1)
+        });
+
+        Image {
+            alg: self.alg.clone(),
+            bits,
+            // This inverts with each iteration because that's how the flashing
+            // border would work if alg[0] is '#'
+            assume_zero: !self.assume_zero,
+        }
+    }
+}
+
+/// The image growing through each of part 2's enhancements
+pub struct Enhancement {
+    image: Image,
+    steps: usize,
+}
+
+impl From<Image> for Enhancement {
+    fn from(image: Image) -> Enhancement {
+        Enhancement { image, steps: 0 }
+    }
+}
+
+impl Simulation for Enhancement {
+    fn step(&mut self) -> bool {
+        if self.steps == ENHANCEMENTS {
+            return false;
+        }
+        self.image = self.image.enhance();
+        self.steps += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        self.image.bits.to_string()
+    }
+}
+
+impl Explore for Enhancement {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        vec![
+            (
+                "enhance [n]",
+                "Enhance n times (default 1) and show the image",
+            ),
+            ("show", "Show the image"),
+            ("lit", "Count the lit pixels"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>> {
+        match command {
+            "enhance" => Some(repl::count(args).map(|n| repl::steps(self, n))),
+            "lit" => Some(Ok(format!(
+                "{} lit after {} enhancements",
+                self.image.lit(),
+                self.steps
+            ))),
+            _ => repl::simulate(self, command, args),
+        }
+    }
+}
+
+#[test]
+fn day20_part1_test() {
+    let v = vec![
+        String::from("..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#"),
+        String::from(""),
+        String::from("#..#."),
+        String::from("#...."),
+        String::from("##..#"),
+        String::from("..#.."),
+        String::from("..###"),
+    ];
+    let answer = Day20::part1(&Day20::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(35, answer);
+}
+
+#[test]
+fn day20_part2_test() {
+    let v = vec![
+        String::from("..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#"),
+        String::from(""),
+        String::from("#..#."),
+        String::from("#...."),
+        String::from("##..#"),
+        String::from("..#.."),
+        String::from("..###"),
+    ];
+    let answer = Day20::part2(&Day20::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(3351, answer);
+}
+
+/// A random algorithm and `width` by `height` image. When the algorithm lights
+/// every dark pixel it also darkens every lit one, or the infinite image would
+/// stay lit forever.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let width = params.at_least("width", 100, 1);
+    let height = params.at_least("height", 100, 1);
+    let lit = params.get("lit", 50);
+
+    let mut alg = (0..512)
+        .map(|_| if rng.chance(lit) { '#' } else { '.' })
+        .collect::<Vec<_>>();
+    if alg[0] == '#' {
+        alg[511] = '.';
+    }
+    let mut out = alg.into_iter().collect::<String>();
+    out += "\n\n";
+    for _ in 0..height {
+        for _ in 0..width {
+            out.push(if rng.chance(lit) { '#' } else { '.' });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn day20_generate_test() {
+    let params = Params::parse(&[String::from("width=8"), String::from("height=5")]).unwrap();
+    let input = generate(&mut Rng::new(20), &params);
+    let image = Day20::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!((8, 5), (image.bits.width(), image.bits.height()));
+    Day20::part1(&image).unwrap();
+}
@@ -0,0 +1,28 @@
+use day20::Image;
+
+const ALGORITHM: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#";
+
+fn image(algorithm: &str, pixels: &str) -> Image {
+    let lines = [algorithm, ""].into_iter().chain(pixels.lines());
+    Image::from(lines.map(String::from)).unwrap()
+}
+
+#[test]
+fn enhance_test() {
+    let image = image(ALGORITHM, "#..#.\n#....\n##..#\n..#..\n..###");
+    assert_eq!(10, image.lit());
+    assert_eq!(24, image.enhance().lit());
+    assert_eq!(35, image.enhance().enhance().lit());
+}
+
+#[test]
+fn flashing_test() {
+    // An algorithm that lights every dark pixel and darkens every lit one
+    let algorithm = format!("#{}.", ".".repeat(510));
+    let image = image(&algorithm, "#").enhance();
+    assert_eq!(0, image.lit());
+
+    // The pixels past the edge are all lit now, so only the middle one is dark
+    // all round and lights up
+    assert_eq!(1, image.enhance().lit());
+}
@@ -0,0 +1,206 @@
+//! Day 21: Dirac Dice. Plays the game with a deterministic die, then counts
+//! the universes each player wins in with the Dirac die.
+
+use aoc_core::{
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+use std::{collections::HashMap, hash::Hash};
+
+/// Plays with the deterministic die, then counts the Dirac die's universes
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<u32>;
+    const DAY: u8 = 21;
+
+    fn parse(mut v: impl Iterator<Item = String>) -> Result<Vec<u32>, ParseError> {
+        let mut spaces = vec![];
+        for player in 1..=2 {
+            let line = v.next().unwrap_or_default();
+            let mut c = Cursor::new(player, &line);
+            c.literal(&format!("Player {} starting position: ", player))?;
+            let column = c.column();
+            match c.number()? {
+                space @ 1..=10 => spaces.push(space),
+                _ => return Err(ParseError::new(player, column, "a space from 1 to 10")),
+            }
+            c.end()?;
+        }
+        for (i, line) in v.enumerate() {
+            Cursor::new(i + 3, &line).end()?;
+        }
+        Ok(spaces)
+    }
+
+    fn part1(spaces: &Vec<u32>) -> Result<Answer, String> {
+        Ok(day21_part1(spaces).into())
+    }
+
+    fn part2(spaces: &Vec<u32>) -> Result<Answer, String> {
+        Ok(day21_part2(spaces).into())
+    }
+}
+
+struct Player {
+    space: u32,
+    score: u32,
+}
+
+impl Player {
+    fn roll(&mut self, die_roller: &mut impl Iterator<Item = u32>, rolls: &mut u32) {
+        for _ in 0..3 {
+            if let Some(roll) = die_roller.next() {
+                self.space += roll;
+            }
+            *rolls += 1;
+        }
+        while self.space > 10 {
+            self.space -= 10;
+        }
+        self.score += self.space;
+    }
+}
+
+fn day21_part1(spaces: &[u32]) -> u32 {
+    let mut die_roller = (1..=100).cycle();
+    let mut rolls = 0;
+
+    let mut players = spaces
+        .iter()
+        .map(|&space| Player { space, score: 0 })
+        .collect::<Vec<_>>();
+
+    'game: loop {
+        for player in players.iter_mut() {
+            player.roll(&mut die_roller, &mut rolls);
+            if player.score >= 1000 {
+                break 'game;
+            }
+        }
+    }
+
+    let loser = players.iter().find(|p| p.score < 1000).unwrap();
+
+    loser.score * rolls
+}
+
+#[test]
+fn day21_part1_test() {
+    let v = vec![
+        String::from("Player 1 starting position: 4"),
+        String::from("Player 2 starting position: 8"),
+    ];
+    let answer = Day21::part1(&Day21::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(739785, answer);
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+struct Turn {
+    spaces: [u32; 2],
+    scores: [u32; 2],
+}
+
+impl Turn {
+    fn next_turn(&self, i: usize, r1: u32, r2: u32, r3: u32) -> Turn {
+        let mut spaces = self.spaces;
+        spaces[i] += r1 + r2 + r3;
+
+        if spaces[i] > 10 {
+            spaces[i] -= 10;
+        }
+
+        let mut scores = self.scores;
+        scores[i] += spaces[i];
+
+        Self { spaces, scores }
+    }
+
+    fn is_win(&self, i: usize) -> bool {
+        self.scores[i] >= 21
+    }
+}
+
+fn day21_part2(spaces: &[u32]) -> u64 {
+    let rolls = (1..=3)
+        .flat_map(|r1| (1..=3).map(move |r2| (1..=3).map(move |r3| (r1, r2, r3))))
+        .flatten();
+
+    let mut turns: HashMap<Turn, u64> = HashMap::from([(
+        Turn {
+            spaces: [spaces[0], spaces[1]],
+            scores: [0, 0],
+        },
+        1,
+    )]);
+
+    let mut wins: [u64; 2] = [0, 0];
+
+    while !turns.is_empty() {
+        for (p, player_wins) in wins.iter_mut().enumerate() {
+            let mut next_turns = HashMap::new();
+
+            for (turn, count) in turns.clone() {
+                for (r1, r2, r3) in rolls.clone() {
+                    let new_turn = turn.next_turn(p, r1, r2, r3);
+                    if new_turn.is_win(p) {
+                        *player_wins += count;
+                    } else {
+                        let new_count = next_turns.entry(new_turn).or_insert(0);
+                        *new_count += count;
+                    }
+                }
+            }
+
+            turns = next_turns;
+        }
+    }
+
+    *wins.iter().max().unwrap()
+}
+
+#[test]
+fn day21_part2_test() {
+    let v = vec![
+        String::from("Player 1 starting position: 4"),
+        String::from("Player 2 starting position: 8"),
+    ];
+    let answer = Day21::part2(&Day21::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(444356092776315_u64, answer);
+}
+
+#[test]
+fn day21_parse_error_test() {
+    let parse = |s: &str| Day21::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(2, 1, "`Player 2 starting position: `")),
+        parse("Player 1 starting position: 4")
+    );
+    assert_eq!(
+        Some(ParseError::new(1, 29, "a space from 1 to 10")),
+        parse("Player 1 starting position: 11\nPlayer 2 starting position: 8")
+    );
+    assert_eq!(Some(ParseError::new(3, 1, "end of line")), parse("Player 1 starting position: 4\nPlayer 2 starting position: 8\nPlayer 3 starting position: 8"));
+}
+
+/// Two players on random starting spaces. The board and scores are fixed by
+/// the puzzle, so there is nothing to size.
+pub fn generate(rng: &mut Rng, _params: &Params) -> String {
+    let first = rng.range(1, 10);
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        first,
+        rng.range(1, 10)
+    )
+}
+
+#[test]
+fn day21_generate_test() {
+    let input = generate(&mut Rng::new(21), &Params::default());
+    let spaces = Day21::parse(input.lines().map(String::from)).unwrap();
+    assert!(spaces.iter().all(|s| (1..=10).contains(s)));
+    Day21::part1(&spaces).unwrap();
+}
@@ -0,0 +1,37 @@
+use aoc_core::Solution;
+use day21::Day21;
+
+fn game(one: u32, two: u32) -> Vec<u32> {
+    let lines = [
+        format!("Player 1 starting position: {}", one),
+        format!("Player 2 starting position: {}", two),
+    ];
+    Day21::parse(lines.into_iter()).unwrap()
+}
+
+#[test]
+fn play_test() {
+    let example = game(4, 8);
+    assert_eq!(vec![4, 8], example);
+    assert_eq!(739785, Day21::part1(&example).unwrap());
+    assert_eq!(444356092776315_u64, Day21::part2(&example).unwrap());
+}
+
+#[test]
+fn parse_test() {
+    let parse = |lines: &[&str]| Day21::parse(lines.iter().map(|line| line.to_string()));
+    assert_eq!(
+        Ok(vec![10, 1]),
+        parse(&[
+            "Player 1 starting position: 10",
+            "Player 2 starting position: 1",
+            "",
+        ])
+    );
+    assert!(parse(&["Player 1 starting position: 4"]).is_err());
+    assert!(parse(&[
+        "Player 1 starting position: 4",
+        "Player 2 starting position: 11",
+    ])
+    .is_err());
+}
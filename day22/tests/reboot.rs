@@ -0,0 +1,30 @@
+use aoc_core::Solution;
+use day22::{Day22, Step};
+
+fn steps(input: &str) -> Vec<Step> {
+    Day22::parse(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn reboot_test() {
+    let example = steps(
+        "on x=10..12,y=10..12,z=10..12\non x=11..13,y=11..13,z=11..13\n\
+         off x=9..11,y=9..11,z=9..11\non x=10..10,y=10..10,z=10..10",
+    );
+    assert_eq!(39, Day22::part1(&example).unwrap());
+    assert_eq!(39, Day22::part2(&example).unwrap());
+}
+
+#[test]
+fn outside_test() {
+    // Only cubes within 50 of the origin count for part 1
+    let far = steps("on x=-60..60,y=0..0,z=0..0\noff x=0..0,y=0..0,z=0..0");
+    assert_eq!(100, Day22::part1(&far).unwrap());
+    assert_eq!(120, Day22::part2(&far).unwrap());
+}
+
+#[test]
+fn display_test() {
+    let line = "off x=-5..7,y=0..0,z=-3..-1";
+    assert_eq!(line, steps(line)[0].to_string());
+}
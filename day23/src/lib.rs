@@ -0,0 +1,490 @@
+//! Day 23: Amphipod. Searches for the cheapest way to move every amphipod on
+//! a [`Board`] into its room.
+
+use aoc_core::{
+    anim::Simulation,
+    gen::{Params, Rng},
+    repl::{self, Explore},
+    search,
+    search::Search,
+    Answer, Cursor, ParseError, Solution,
+};
+use core::fmt;
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+/// The least energy to organize the amphipods, then with the folded-out rooms
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Board<2>;
+    const DAY: u8 = 23;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Board<2>, ParseError> {
+        Board::from(v)
+    }
+
+    fn part1(board: &Board<2>) -> Result<Answer, String> {
+        solve(board.clone())
+            .cost()
+            .map(Answer::from)
+            .ok_or(String::from("The burrow can't be organized"))
+    }
+
+    fn part2(board: &Board<2>) -> Result<Answer, String> {
+        solve(board.unfold())
+            .cost()
+            .map(Answer::from)
+            .ok_or(String::from("The burrow can't be organized"))
+    }
+}
+
+fn distance_est(a: &Loc, b: &Loc, cost: u32) -> u32 {
+    ((a.0 - b.0).abs() + (a.1 - b.1).abs()) as u32 * cost
+}
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+enum Amphipod {
+    A = 1,
+    B = 10,
+    C = 100,
+    D = 1000,
+}
+
+impl Amphipod {
+    fn from(char: char) -> Option<Amphipod> {
+        match char {
+            'A' => Some(Self::A),
+            'B' => Some(Self::B),
+            'C' => Some(Self::C),
+            'D' => Some(Self::D),
+            _ => None,
+        }
+    }
+
+    fn from_index(i: usize) -> Option<Self> {
+        match i {
+            0 => Some(Self::A),
+            1 => Some(Self::B),
+            2 => Some(Self::C),
+            3 => Some(Self::D),
+            _ => None,
+        }
+    }
+
+    fn cost(&self) -> u32 {
+        *self as u32
+    }
+
+    fn room_index(&self) -> i32 {
+        match self {
+            Self::A => 2,
+            Self::B => 4,
+            Self::C => 6,
+            Self::D => 8,
+        }
+    }
+}
+
+type Loc = (i32, i32);
+
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+enum Space {
+    Hallway,
+    Doorway,
+    Room(Amphipod),
+}
+
+/// The burrow's hallway and rooms, each room `SIZE` amphipods deep
+#[derive(Clone, PartialEq, Eq)]
+pub struct Board<const SIZE: usize> {
+    spaces: HashMap<Loc, Space>,
+    amphipods: HashMap<Loc, Amphipod>,
+}
+
+impl<const SIZE: usize> Board<SIZE> {
+    fn from(v: impl Iterator<Item = String>) -> Result<Board<SIZE>, ParseError> {
+        let mut board = Board::empty();
+        let expected = "`#`, `.` or an amphipod from A to D";
+
+        for (y, line) in v.enumerate().skip(1) {
+            let mut c = Cursor::new(y + 1, &line);
+            for x in -1..line.len() as i32 - 1 {
+                let cell = c.char_map(expected, |char| match char {
+                    '#' | '.' | ' ' => Some(None),
+                    char => Amphipod::from(char).map(Some),
+                })?;
+                if let Some(amph) = cell {
+                    board.amphipods.insert((x, y as i32 - 1), amph);
+                }
+            }
+        }
+
+        for i in 0..4 {
+            let species = Amphipod::from_index(i);
+            let count = board.amphipods.values().filter(|&&a| Some(a) == species);
+            if count.count() != SIZE {
+                let expected = format!("{} amphipods of each kind", SIZE);
+                return Err(ParseError::new(3, 1, expected));
+            }
+        }
+
+        Ok(board)
+    }
+
+    fn empty() -> Board<SIZE> {
+        let mut board = Board {
+            spaces: HashMap::new(),
+            amphipods: HashMap::new(),
+        };
+
+        for i in 0..4 {
+            let species = Amphipod::from_index(i).unwrap();
+            let x = species.room_index();
+            for y in 1..=SIZE {
+                board.spaces.insert((x, y as i32), Space::Room(species));
+            }
+        }
+
+        board.spaces.insert((0, 0), Space::Hallway);
+        board.spaces.insert((1, 0), Space::Hallway);
+        board.spaces.insert((2, 0), Space::Doorway);
+        board.spaces.insert((3, 0), Space::Hallway);
+        board.spaces.insert((4, 0), Space::Doorway);
+        board.spaces.insert((5, 0), Space::Hallway);
+        board.spaces.insert((6, 0), Space::Doorway);
+        board.spaces.insert((7, 0), Space::Hallway);
+        board.spaces.insert((8, 0), Space::Doorway);
+        board.spaces.insert((9, 0), Space::Hallway);
+        board.spaces.insert((10, 0), Space::Hallway);
+
+        board
+    }
+
+    fn is_occupied(&self, loc: &Loc) -> bool {
+        !self.spaces.contains_key(loc) || self.amphipods.contains_key(loc)
+    }
+
+    fn last_empty_room_slot(&self, species: Amphipod) -> Option<Loc> {
+        let x = species.room_index();
+        for y in (1..=SIZE).rev() {
+            let loc = (x, y as i32);
+            if !self.is_occupied(&loc) {
+                return Some(loc);
+            }
+        }
+        None
+    }
+
+    fn room_is_ok(&self, species: Amphipod) -> bool {
+        let x = species.room_index();
+        for y in 1..=SIZE {
+            if let Some(amph) = self.amphipods.get(&(x, y as i32)) {
+                if *amph != species {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn is_finished(&self) -> bool {
+        self.amphipods
+            .iter()
+            .all(|(loc, amp)| self.spaces.get(loc) == Some(&Space::Room(*amp)))
+    }
+
+    fn successors(&self, &(x, y): &Loc, cost: u32) -> Vec<(Loc, u32)> {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|p| !self.is_occupied(p))
+            .map(|p| (p, cost))
+            .collect()
+    }
+
+    fn possible_moves(&self) -> Vec<(Amphipod, (Loc, Loc), u32)> {
+        let mut room_moves = vec![];
+        let mut hall_moves = vec![];
+
+        for (start, amph) in self.amphipods.iter() {
+            let &cur_space = self.spaces.get(start).unwrap();
+            if cur_space == Space::Room(*amph) && self.room_is_ok(*amph) {
+                continue;
+            }
+
+            for (dest, &space) in self.spaces.iter() {
+                if dest == start || self.is_occupied(dest) {
+                    continue;
+                }
+                match space {
+                    Space::Doorway => (),
+                    Space::Hallway => {
+                        if cur_space != Space::Hallway {
+                            // They never move from a hallway to a hallway
+                            let result = search::astar(
+                                *start,
+                                |p| self.successors(p, amph.cost()),
+                                |p| distance_est(p, dest, amph.cost()),
+                                |p| p == dest,
+                            );
+                            if let Some(cost) = result.cost() {
+                                hall_moves.push((*amph, (*start, *dest), cost));
+                            }
+                        }
+                    }
+                    Space::Room(species) => {
+                        // Only walk into a room if it's the same species
+                        if species == *amph
+                            && self.room_is_ok(species)
+                            && self.last_empty_room_slot(species).unwrap() == *dest
+                        {
+                            let result = search::astar(
+                                *start,
+                                |p| self.successors(p, amph.cost()),
+                                |p| distance_est(p, dest, amph.cost()),
+                                |p| p == dest,
+                            );
+                            if let Some(cost) = result.cost() {
+                                // Short circuit if we can move directly into a destination room
+                                room_moves.push((*amph, (*start, *dest), cost));
+                            }
+                        }
+                    }
+                };
+            }
+        }
+
+        room_moves.sort_by_key(|m| m.2);
+        hall_moves.sort_by_key(|m| m.2);
+
+        room_moves.append(&mut hall_moves);
+        room_moves
+    }
+
+    fn move_amphipod(&self, amph: &Amphipod, from: Loc, to: Loc) -> Board<SIZE> {
+        let mut new_board = self.clone();
+        new_board.amphipods.remove(&from);
+        new_board.amphipods.insert(to, *amph);
+        new_board
+    }
+}
+
+// Every board of the same SIZE has the same spaces, so only the amphipods
+// need hashing. They are sorted so equal boards hash the same.
+impl<const SIZE: usize> Hash for Board<SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut amphipods = self.amphipods.iter().collect::<Vec<_>>();
+        amphipods.sort_by_key(|&(loc, _)| *loc);
+        amphipods.hash(state);
+    }
+}
+
+impl<const SIZE: usize> Debug for Board<SIZE> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+impl<const SIZE: usize> Display for Board<SIZE> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        #[rustfmt::skip]
+        let mut lines  = vec![
+            ['#', '#', '#', '#', '#', '#', '#', '#', '#', '#', '#', '#', '#'],
+            ['#', '.', '.', '.', '.', '.', '.', '.', '.', '.', '.', '.', '#'],
+            ['#', '#', '#', '.', '#', '.', '#', '.', '#', '.', '#', '#', '#'],
+            [' ', ' ', '#', '#', '#', '#', '#', '#', '#', '#', '#', ' ', ' '],
+        ];
+
+        for _ in 1..SIZE {
+            lines.insert(
+                3,
+                [
+                    ' ', ' ', '#', '.', '#', '.', '#', '.', '#', '.', '#', ' ', ' ',
+                ],
+            );
+        }
+
+        for (loc, amp) in &self.amphipods {
+            lines[loc.1 as usize + 1][loc.0 as usize + 1] = match amp {
+                Amphipod::A => 'A',
+                Amphipod::B => 'B',
+                Amphipod::C => 'C',
+                Amphipod::D => 'D',
+            };
+        }
+        let rendered = lines
+            .into_iter()
+            .map(|l| l.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n");
+        write!(f, "{}", rendered)
+    }
+}
+
+impl Board<2> {
+    /// Inserts the two folded-up rows of the diagram between the top and
+    /// bottom rows of each room:
+    ///
+    /// ```text
+    ///   #D#C#B#A#
+    ///   #D#B#A#C#
+    /// ```
+    fn unfold(&self) -> Board<4> {
+        let mut board = Board::<4>::empty();
+
+        for (&(x, y), &amph) in self.amphipods.iter() {
+            let y = if y == 2 { 4 } else { y };
+            board.amphipods.insert((x, y), amph);
+        }
+
+        let folded = ["DCBA", "DBAC"];
+        for (row, species) in folded.iter().enumerate() {
+            for (i, char) in species.chars().enumerate() {
+                let x = Amphipod::from_index(i).unwrap().room_index();
+                board
+                    .amphipods
+                    .insert((x, row as i32 + 2), Amphipod::from(char).unwrap());
+            }
+        }
+
+        board
+    }
+}
+
+fn solve<const SIZE: usize>(board: Board<SIZE>) -> Search<Board<SIZE>, u32> {
+    let moves = |board: &Board<SIZE>| {
+        board
+            .possible_moves()
+            .into_iter()
+            .map(|(amph, (from, to), cost)| (board.move_amphipod(&amph, from, to), cost))
+            .collect::<Vec<_>>()
+    };
+    let search = search::dijkstra(board, moves, Board::is_finished);
+    search.record();
+    search
+}
+
+/// The cheapest way of organizing the amphipods, one move at a time
+pub struct Moves {
+    boards: Vec<Board<2>>,
+    at: usize,
+}
+
+impl From<Board<2>> for Moves {
+    fn from(board: Board<2>) -> Moves {
+        let boards = match solve(board.clone()).found {
+            Some((boards, _)) => boards,
+            None => vec![board],
+        };
+        Moves { boards, at: 0 }
+    }
+}
+
+impl Simulation for Moves {
+    fn step(&mut self) -> bool {
+        if self.at + 1 == self.boards.len() {
+            return false;
+        }
+        self.at += 1;
+        true
+    }
+
+    fn frame(&self) -> String {
+        format!("{}\n", self.boards[self.at])
+    }
+}
+
+impl Explore for Moves {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        repl::SIMULATION_COMMANDS.to_vec()
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>> {
+        repl::simulate(self, command, args)
+    }
+}
+
+#[test]
+fn day23_part1_test() {
+    let v = vec![
+        String::from("#############"),
+        String::from("#...........#"),
+        String::from("###B#C#B#D###"),
+        String::from("  #A#D#C#A#"),
+        String::from("  #########"),
+    ];
+
+    let answer = Day23::part1(&Day23::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(Amphipod::A.cost(), 1);
+    assert_eq!(Amphipod::B.cost(), 10);
+    assert_eq!(Amphipod::C.cost(), 100);
+    assert_eq!(Amphipod::D.cost(), 1000);
+
+    assert_eq!(12521, answer);
+}
+
+#[test]
+fn day23_part1_test_3() {
+    let v = vec![
+        String::from("#############"),
+        String::from("#...B.....A.#"),
+        String::from("###A#D#C#.###"),
+        String::from("  #C#D#B#.#"),
+        String::from("  #########"),
+    ];
+
+    let test_board = Board::<2>::from(v.clone().into_iter()).unwrap();
+    println!("{:?}", test_board);
+    let possibles = test_board.possible_moves();
+
+    for (amph, (from, to), cost) in possibles {
+        let new_board = test_board.move_amphipod(&amph, from, to);
+        println!("{:?}", cost);
+        println!("{:?}", new_board);
+    }
+}
+
+#[test]
+fn day23_part2_test() {
+    let v = vec![
+        String::from("#############"),
+        String::from("#...........#"),
+        String::from("###B#C#B#D###"),
+        String::from("  #A#D#C#A#"),
+        String::from("  #########"),
+    ];
+
+    let answer = Day23::part2(&Day23::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(44169, answer);
+}
+
+/// Two of each amphipod shuffled into the rooms. The burrow's size is fixed by
+/// the puzzle, so there is nothing to size.
+pub fn generate(rng: &mut Rng, _params: &Params) -> String {
+    let mut amphipods = "AABBCCDD".chars().collect::<Vec<_>>();
+    rng.shuffle(&mut amphipods);
+    let (top, bottom) = amphipods.split_at(4);
+    let row = |row: &[char]| {
+        row.iter()
+            .map(char::to_string)
+            .collect::<Vec<_>>()
+            .join("#")
+    };
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        row(top),
+        row(bottom)
+    )
+}
+
+#[test]
+fn day23_generate_test() {
+    // Only parsed, as solving takes a while in debug builds
+    let input = generate(&mut Rng::new(23), &Params::default());
+    let board = Day23::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(8, board.amphipods.len());
+}
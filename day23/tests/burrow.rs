@@ -0,0 +1,32 @@
+use aoc_core::{anim, Solution};
+use day23::{Board, Day23, Moves};
+
+const EXAMPLE: &str = "#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#\n  #########";
+
+fn board(input: &str) -> Board<2> {
+    Day23::parse(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn organize_test() {
+    assert_eq!(12521, Day23::part1(&board(EXAMPLE)).unwrap());
+
+    // Only the B and A swap places, through the hallway
+    let swapped = board("#############\n#...........#\n###B#A#C#D###\n  #A#B#C#D#\n  #########");
+    assert_eq!(46, Day23::part1(&swapped).unwrap());
+}
+
+#[test]
+fn moves_test() {
+    let sorted = "#############\n#...........#\n###A#B#C#D###\n  #A#B#C#D#\n  #########";
+    assert_eq!(0, Day23::part1(&board(sorted)).unwrap());
+    let frames = anim::frames(&mut Moves::from(board(sorted))).count();
+    assert_eq!(1, frames);
+
+    // The first frame is the board as it was drawn, each row as wide as the
+    // hallway
+    let mut moves = Moves::from(board(EXAMPLE));
+    let first = anim::frames(&mut moves).next().unwrap();
+    let rows = EXAMPLE.lines().map(|row| format!("{:13}\n", row));
+    assert_eq!(rows.collect::<String>(), first);
+}
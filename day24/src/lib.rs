@@ -0,0 +1,462 @@
+//! Day 24: Arithmetic Logic Unit. [`Instruction`] reads and runs ALU code, and
+//! the solver searches MONAD one digit block at a time.
+
+use aoc_core::{
+    diag,
+    gen::{Params, Rng},
+    Answer, Cursor, ParseError, Solution,
+};
+use std::{collections::HashMap, fmt};
+
+/// The largest model number MONAD accepts, then the smallest
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Instruction>;
+    const DAY: u8 = 24;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<Vec<Instruction>, ParseError> {
+        let program = v
+            .enumerate()
+            .map(|(i, x)| Instruction::from(&mut Cursor::new(i + 1, &x)))
+            .collect::<Result<Vec<_>, _>>()?;
+        check_blocks(&program)?;
+        Ok(program)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<Answer, String> {
+        Ok(model_number(instructions, [9, 8, 7, 6, 5, 4, 3, 2, 1]).into())
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<Answer, String> {
+        Ok(model_number(instructions, [1, 2, 3, 4, 5, 6, 7, 8, 9]).into())
+    }
+}
+
+/// Digits in a model number
+const DIGITS: usize = 14;
+
+// The solver runs one block per digit, so the program has to be made of
+// equally long blocks that each start by reading a digit
+fn check_blocks(program: &[Instruction]) -> Result<(), ParseError> {
+    let len = (program.len() / DIGITS).max(1);
+    let expected = || {
+        format!(
+            "{} blocks of {} instructions, each starting with `inp`",
+            DIGITS, len
+        )
+    };
+
+    let mut blocks = 0;
+    for (i, instruction) in program.iter().enumerate() {
+        let starts_block = i % len == 0 && i / len < DIGITS;
+        if matches!(instruction, Instruction::Inp(_)) != starts_block {
+            return Err(ParseError::new(i + 1, 1, expected()));
+        }
+        blocks += starts_block as usize;
+    }
+    if blocks < DIGITS || !program.len().is_multiple_of(DIGITS) {
+        return Err(ParseError::new(program.len() + 1, 1, expected()));
+    }
+    Ok(())
+}
+
+/// Register `w`'s index, as used by [`Op::Reg`] and [`run`]'s result
+pub const W: usize = 0;
+/// Register `x`'s index
+pub const X: usize = 1;
+/// Register `y`'s index
+pub const Y: usize = 2;
+/// Register `z`'s index
+pub const Z: usize = 3;
+
+/// An instruction's second operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// A register's value
+    Reg(usize),
+    /// A number
+    Val(i64),
+}
+
+fn to_reg(c: &mut Cursor) -> Result<usize, ParseError> {
+    match c.one_of(&["w", "x", "y", "z"])? {
+        "w" => Ok(W),
+        "x" => Ok(X),
+        "y" => Ok(Y),
+        _ => Ok(Z),
+    }
+}
+
+const REGISTERS: [char; 4] = ['w', 'x', 'y', 'z'];
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Op::Reg(r) => write!(f, "{}", REGISTERS[*r]),
+            Op::Val(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Op {
+    fn from(c: &mut Cursor) -> Result<Op, ParseError> {
+        match c.peek() {
+            Some('-' | '0'..='9') => Ok(Op::Val(c.number()?)),
+            _ => Ok(Op::Reg(to_reg(c)?)),
+        }
+    }
+}
+
+/// One line of ALU code, with its first operand as a register index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// Reads the next input into the register
+    Inp(usize),
+    /// Adds the operand to the register
+    Add(usize, Op),
+    /// Multiplies the register by the operand
+    Mul(usize, Op),
+    /// Divides the register by the operand, rounding towards zero
+    Div(usize, Op),
+    /// Leaves the remainder of dividing the register by the operand
+    Mod(usize, Op),
+    /// Sets the register to 1 if it equals the operand, otherwise 0
+    Eql(usize, Op),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, a, b) = match self {
+            Instruction::Inp(a) => return write!(f, "inp {}", REGISTERS[*a]),
+            Instruction::Add(a, b) => ("add", a, b),
+            Instruction::Mul(a, b) => ("mul", a, b),
+            Instruction::Div(a, b) => ("div", a, b),
+            Instruction::Mod(a, b) => ("mod", a, b),
+            Instruction::Eql(a, b) => ("eql", a, b),
+        };
+        write!(f, "{} {} {}", name, REGISTERS[*a], b)
+    }
+}
+
+impl Instruction {
+    /// Reads one instruction, e.g. `add x -3`. Dividing by zero, or taking a
+    /// remainder by anything but a positive number, is rejected.
+    pub fn from(c: &mut Cursor) -> Result<Instruction, ParseError> {
+        let inst = c.one_of(&["inp", "add", "mul", "div", "mod", "eql"])?;
+        c.literal(" ")?;
+        let a = to_reg(c)?;
+        if inst == "inp" {
+            c.end()?;
+            return Ok(Instruction::Inp(a));
+        }
+
+        c.literal(" ")?;
+        let column = c.column();
+        let b = Op::from(c)?;
+        c.end()?;
+        match (inst, b) {
+            ("div", Op::Val(0)) => {
+                return Err(ParseError::new(c.line(), column, "a non-zero divisor"))
+            }
+            ("mod", Op::Val(n)) if n <= 0 => {
+                return Err(ParseError::new(c.line(), column, "a positive divisor"))
+            }
+            _ => {}
+        }
+        match inst {
+            "add" => Ok(Instruction::Add(a, b)),
+            "mul" => Ok(Instruction::Mul(a, b)),
+            "div" => Ok(Instruction::Div(a, b)),
+            "mod" => Ok(Instruction::Mod(a, b)),
+            _ => Ok(Instruction::Eql(a, b)),
+        }
+    }
+
+    fn op(op: &Op, reg: &[i64; 4]) -> i64 {
+        match op {
+            Op::Val(n) => *n,
+            Op::Reg(r) => reg[*r],
+        }
+    }
+
+    /// Runs the instruction on the registers, with `input` as the number read
+    /// by `inp`. Returns `None`, leaving the registers alone, if `inp` has
+    /// nothing to read or a register holds a divisor that isn't allowed.
+    pub fn exec(&self, reg: &mut [i64; 4], input: Option<i64>) -> Option<()> {
+        match self {
+            Instruction::Inp(a) => reg[*a] = input?,
+            Instruction::Add(a, b) => reg[*a] += Instruction::op(b, reg),
+            Instruction::Mul(a, b) => reg[*a] *= Instruction::op(b, reg),
+            Instruction::Div(a, b) => {
+                let b = Instruction::op(b, reg);
+                if b == 0 {
+                    return None;
+                }
+                reg[*a] /= b;
+            }
+            Instruction::Mod(a, b) => {
+                let b = Instruction::op(b, reg);
+                if b <= 0 {
+                    return None;
+                }
+                reg[*a] %= b;
+            }
+            Instruction::Eql(a, b) => {
+                let b = Instruction::op(b, reg);
+                reg[*a] = if reg[*a] == b { 1 } else { 0 };
+            }
+        }
+        Some(())
+    }
+}
+
+/// Runs a whole program from zeroed registers, reading `input` in order, and
+/// returns the registers at the end. `None` if it stops early, see
+/// [`Instruction::exec`].
+pub fn run(program: &[Instruction], input: &[i64]) -> Option<[i64; 4]> {
+    let mut reg = [0; 4];
+    let mut input = input.iter().copied();
+    for instruction in program {
+        let next = match instruction {
+            Instruction::Inp(_) => input.next(),
+            _ => None,
+        };
+        instruction.exec(&mut reg, next)?;
+    }
+    Some(reg)
+}
+
+/// The first model number MONAD accepts, trying each digit in `range` order
+fn model_number(instructions: &[Instruction], range: [i64; 9]) -> i64 {
+    let mut cpu = MonadCPU::<DIGITS>::new(instructions, range);
+    let number = cpu.execute(0, [0, 0, 0, 0]).unwrap();
+    diag::record("cache_hits", cpu.hits);
+    diag::record("cache_misses", cpu.misses);
+    diag::record("digits_pruned", cpu.pruned);
+    reverse_number(number)
+}
+
+struct MonadCPU<'a, const LEN: usize> {
+    cache: HashMap<(usize, i64), Option<i64>>,
+    digit_inst: Vec<&'a [Instruction]>,
+    range: [i64; 9],
+    hits: u64,
+    misses: u64,
+    /// Digits given up on because their block can't run with them
+    pruned: u64,
+}
+
+impl<'a, const LEN: usize> MonadCPU<'a, LEN> {
+    fn new(instructions: &'a [Instruction], range: [i64; 9]) -> MonadCPU<'a, LEN> {
+        let digit_inst = instructions
+            .chunks(instructions.len() / LEN)
+            .collect::<Vec<_>>();
+
+        assert_eq!(digit_inst.len(), LEN);
+
+        MonadCPU {
+            cache: HashMap::new(),
+            digit_inst,
+            range,
+            hits: 0,
+            misses: 0,
+            pruned: 0,
+        }
+    }
+
+    fn execute(&mut self, position: usize, reg: [i64; 4]) -> Option<i64> {
+        if let Some(&best_digit) = self.cache.get(&(position, reg[Z])) {
+            self.hits += 1;
+            return best_digit;
+        }
+        self.misses += 1;
+
+        // Search for the first digit (9 through 1, or 1 through 9) that produces a target Z
+        // value (initially 0) for the current instruction block. Each block of instructions maps
+        // to a position in the resulting number, starting from the left.
+        'digits: for digit in self.range {
+            let mut reg = reg;
+
+            for &instruction in self.digit_inst[position] {
+                if instruction.exec(&mut reg, Some(digit)).is_none() {
+                    self.pruned += 1;
+                    continue 'digits;
+                }
+            }
+
+            // The Z register values for each successive digit will be consistent on their
+            // way to producing 0 for the last digit.
+            let z = reg[Z];
+
+            if position < LEN - 1 {
+                // Search the next digit in the next instruction block
+                if let Some(best_digit) = self.execute(position + 1, reg) {
+                    self.cache.insert((position, z), Some(best_digit));
+
+                    // Construct the resulting number (which is actually in reverse, but we
+                    // take care of that later)
+                    return Some(best_digit * 10 + digit);
+                }
+            } else if z == 0 {
+                // Last digit, and we found a solution
+                self.cache.insert((position, 0), Some(digit));
+                return Some(digit);
+            }
+        }
+
+        // No solution found
+        self.cache.insert((position, reg[Z]), None);
+        None
+    }
+}
+
+fn reverse_number(n: i64) -> i64 {
+    let mut n = n;
+    let mut result = 0;
+
+    while n > 0 {
+        result *= 10;
+        result += n % 10;
+        n /= 10;
+    }
+
+    result
+}
+
+/// A MONAD program: 14 blocks that each read a digit, where 7 blocks push a
+/// value onto `z` (as a base 26 stack) and 7 pop one off, paired like brackets
+/// in a random order. Each pair allows some digits through, so there is
+/// always a valid model number. The solver expects 14 digits, so the number
+/// of blocks is fixed; `instructions` (at least 18) pads each block to that
+/// length with instructions that change nothing, to make bigger programs.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let instructions = params.at_least("instructions", 18, 18);
+    let mut blocks = vec![];
+    let mut pushed = vec![];
+    for digit in 0..DIGITS {
+        let pops = (digit - pushed.len()) / 2;
+        let push = pushed.is_empty() || (pops + pushed.len() < DIGITS / 2 && rng.chance(50));
+        let (div, check, offset) = if push {
+            let offset = rng.range(0, 15);
+            pushed.push(offset);
+            // Never equal to a digit, so the block always pushes
+            (1, rng.range(10, 16), offset)
+        } else {
+            // The popped digit plus its offset, plus this, must be a digit
+            let popped = pushed.pop().unwrap();
+            (26, rng.range(-8, 8) - popped, rng.range(0, 15))
+        };
+        let block = format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y",
+            div, check, offset
+        );
+        blocks.push(block.lines().map(String::from).collect::<Vec<_>>());
+    }
+
+    // Padded once every block is made, so the seed picks the same MONAD
+    // however long the blocks are
+    let mut out = String::new();
+    for mut lines in blocks {
+        for _ in lines.len()..instructions {
+            // Anywhere after `inp`, which has to start the block
+            let at = 1 + rng.below(lines.len());
+            let register = rng.pick(&REGISTERS);
+            let padding = match rng.below(3) {
+                0 => format!("add {} 0", register),
+                1 => format!("mul {} 1", register),
+                _ => format!("div {} 1", register),
+            };
+            lines.insert(at, padding);
+        }
+        for line in lines {
+            out += &line;
+            out.push('\n');
+        }
+    }
+    out
+}
+
+#[test]
+fn day24_generate_test() {
+    let input = generate(&mut Rng::new(24), &Params::default());
+    let instructions = Day24::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!(14 * 18, instructions.len());
+    let largest = Day24::part1(&instructions).unwrap().to_string();
+    assert_eq!(14, largest.len());
+    assert!(!largest.contains('0'));
+
+    let mut params = Params::parse(&[String::from("instructions=40")]).unwrap();
+    let padded = generate(&mut Rng::new(24), &params);
+    let padded = Day24::parse(padded.lines().map(String::from)).unwrap();
+    assert_eq!(14 * 40, padded.len());
+    let digits = largest
+        .bytes()
+        .map(|b| (b - b'0') as i64)
+        .collect::<Vec<_>>();
+    assert_eq!(Some(0), run(&instructions, &digits).map(|reg| reg[Z]));
+    assert_eq!(Some(0), run(&padded, &digits).map(|reg| reg[Z]));
+
+    params = Params::parse(&[String::from("instructions=3")]).unwrap();
+    assert_eq!(input, generate(&mut Rng::new(24), &params));
+}
+
+#[test]
+fn day24_parse_error_test() {
+    let parse = |program: &str| Day24::parse(program.lines().map(String::from));
+
+    assert_eq!(
+        Err(ParseError::new(1, 7, "a non-zero divisor")),
+        parse("div z 0")
+    );
+    assert_eq!(
+        Err(ParseError::new(1, 7, "a positive divisor")),
+        parse("mod x -3")
+    );
+
+    let block = "inp w\nadd z w\n";
+    assert_eq!(
+        Ok(28),
+        parse(&block.repeat(DIGITS)).map(|program| program.len())
+    );
+    let expected = "14 blocks of 2 instructions, each starting with `inp`";
+    assert_eq!(
+        Err(ParseError::new(30, 1, expected)),
+        parse(&(block.repeat(DIGITS) + "add z w"))
+    );
+    let swapped = String::from(block) + "add z w\ninp w\n" + &block.repeat(DIGITS - 2);
+    assert_eq!(Err(ParseError::new(3, 1, expected)), parse(&swapped));
+}
+
+#[cfg(test)]
+fn instruction() -> impl proptest::strategy::Strategy<Value = Instruction> {
+    use proptest::prelude::*;
+
+    let op = prop_oneof![
+        (0..4usize).prop_map(Op::Reg),
+        any::<i64>().prop_map(Op::Val)
+    ];
+    prop_oneof![
+        (0..4usize).prop_map(Instruction::Inp),
+        (0..4usize, op.clone()).prop_map(|(a, b)| Instruction::Add(a, b)),
+        (0..4usize, op.clone()).prop_map(|(a, b)| Instruction::Mul(a, b)),
+        (0..4usize, op.clone()).prop_filter_map("zero divisor", |(a, b)| {
+            (b != Op::Val(0)).then_some(Instruction::Div(a, b))
+        }),
+        (0..4usize, op.clone()).prop_filter_map("non-positive divisor", |(a, b)| {
+            (!matches!(b, Op::Val(n) if n <= 0)).then_some(Instruction::Mod(a, b))
+        }),
+        (0..4usize, op).prop_map(|(a, b)| Instruction::Eql(a, b)),
+    ]
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn day24_round_trip_test(instruction in instruction()) {
+        let line = instruction.to_string();
+        proptest::prop_assert_eq!(Ok(instruction), Instruction::from(&mut Cursor::new(1, &line)));
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_core::Cursor;
+use day24::{run, Instruction, Op, W, X, Z};
+
+fn program(lines: &[&str]) -> Vec<Instruction> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| Instruction::from(&mut Cursor::new(i + 1, line)).unwrap())
+        .collect()
+}
+
+#[test]
+fn from_test() {
+    let parse = |line: &str| Instruction::from(&mut Cursor::new(1, line));
+    assert_eq!(Ok(Instruction::Add(X, Op::Val(-3))), parse("add x -3"));
+    assert_eq!(Ok(Instruction::Eql(Z, Op::Reg(W))), parse("eql z w"));
+    assert_eq!("mul z 26", parse("mul z 26").unwrap().to_string());
+    assert!(parse("div x 0").is_err());
+    assert!(parse("sub x 1").is_err());
+}
+
+#[test]
+fn run_test() {
+    // The puzzle's example: the bits of the input, lowest in z
+    let binary = program(&[
+        "inp w", "add z w", "mod z 2", "div w 2", "add y w", "mod y 2", "div w 2", "add x w",
+        "mod x 2", "div w 2", "mod w 2",
+    ]);
+    assert_eq!(Some([1, 0, 1, 1]), run(&binary, &[0b1011]));
+
+    let negate = program(&["inp x", "mul x -1"]);
+    assert_eq!(Some([0, -7, 0, 0]), run(&negate, &[7]));
+    assert_eq!(None, run(&negate, &[]));
+
+    let divide = program(&["inp x", "div x y"]);
+    assert_eq!(None, run(&divide, &[4]));
+}
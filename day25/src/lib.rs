@@ -0,0 +1,222 @@
+//! Day 25: Sea Cucumber. [`Herds`] moves the cucumbers on a [`SeaFloor`]
+//! until none of them can move.
+
+use aoc_core::{
+    anim::Simulation,
+    gen::{Params, Rng},
+    repl::{self, Explore},
+    Answer, Grid, ParseError, Solution,
+};
+
+/// The first step on which no sea cucumber moves
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = SeaFloor;
+    const DAY: u8 = 25;
+
+    fn parse(v: impl Iterator<Item = String>) -> Result<SeaFloor, ParseError> {
+        // Cucumbers that move off one edge reappear on the opposite edge
+        let grid = Grid::parse(v, "`>`, `v` or `.`", |ch| match ch {
+            '.' => Some(None),
+            ch => Cucumber::from(ch).map(Some),
+        })?;
+        Ok(grid.wrapping())
+    }
+
+    fn part1(grid: &SeaFloor) -> Result<Answer, String> {
+        let mut grid = grid.clone();
+        let mut iterations = 1;
+
+        while let Some(next_grid) = next_state(&grid) {
+            grid = next_grid;
+            iterations += 1;
+        }
+
+        Ok(iterations.into())
+    }
+
+    /// There is no second puzzle on the last day
+    fn part2(_grid: &SeaFloor) -> Result<Answer, String> {
+        Ok("Merry Christmas!".into())
+    }
+}
+
+/// A sea cucumber, by the direction it moves in
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Cucumber {
+    /// `>`
+    East,
+    /// `v`
+    South,
+}
+
+impl Cucumber {
+    fn from(c: char) -> Option<Cucumber> {
+        match c {
+            '>' => Some(Cucumber::East),
+            'v' => Some(Cucumber::South),
+            _ => None,
+        }
+    }
+}
+
+/// What is on each space of the sea floor, which wraps around at its edges
+pub type SeaFloor = Grid<Option<Cucumber>>;
+
+/// Moves every cucumber of `herd` that faces an empty space by one step in
+/// `dir`, returning the number that moved
+fn move_herd(grid: &mut SeaFloor, herd: Cucumber, dir: (isize, isize)) -> usize {
+    let moving = grid
+        .cells()
+        .filter(|&(_, &c)| c == Some(herd))
+        .map(|(pos, _)| (pos, grid.step(pos, dir).unwrap()))
+        .filter(|&(_, next)| grid[next].is_none())
+        .collect::<Vec<_>>();
+
+    for &(pos, next) in moving.iter() {
+        grid[pos] = None;
+        grid[next] = Some(herd);
+    }
+    moving.len()
+}
+
+fn next_state(grid: &SeaFloor) -> Option<SeaFloor> {
+    let mut next = grid.clone();
+    let moves = move_herd(&mut next, Cucumber::East, (1, 0))
+        + move_herd(&mut next, Cucumber::South, (0, 1));
+
+    if moves > 0 {
+        Some(next)
+    } else {
+        None
+    }
+}
+
+/// The herds moving step by step until none of them can
+pub struct Herds {
+    floor: SeaFloor,
+}
+
+impl From<SeaFloor> for Herds {
+    fn from(floor: SeaFloor) -> Herds {
+        Herds { floor }
+    }
+}
+
+impl Simulation for Herds {
+    fn step(&mut self) -> bool {
+        match next_state(&self.floor) {
+            Some(next) => {
+                self.floor = next;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn frame(&self) -> String {
+        self.floor
+            .map(|c| match c {
+                Some(Cucumber::East) => '>',
+                Some(Cucumber::South) => 'v',
+                None => '.',
+            })
+            .to_string()
+    }
+}
+
+impl Explore for Herds {
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        repl::SIMULATION_COMMANDS.to_vec()
+    }
+
+    fn run(&mut self, command: &str, args: &[&str]) -> Option<Result<String, String>> {
+        repl::simulate(self, command, args)
+    }
+}
+
+#[test]
+fn day25_part1_test() {
+    let v = vec![
+        String::from("v...>>.vv>"),
+        String::from(".vv>>.vv.."),
+        String::from(">>.>v>...v"),
+        String::from(">>v>>.>.v."),
+        String::from("v>v.vv.v.."),
+        String::from(">.>>..v..."),
+        String::from(".vv..>.>v."),
+        String::from("v.v..>>v.v"),
+        String::from("....v..v.>"),
+    ];
+    let answer = Day25::part1(&Day25::parse(v.into_iter()).unwrap()).unwrap();
+
+    assert_eq!(58, answer);
+}
+
+#[test]
+fn day25_herds_test() {
+    let v = vec![String::from("...>>>>>...")];
+    let mut herds = Herds::from(Day25::parse(v.into_iter()).unwrap());
+    let frames = aoc_core::anim::frames(&mut herds)
+        .take(3)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec!["...>>>>>...\n", "...>>>>.>..\n", "...>>>.>.>.\n"],
+        frames
+    );
+}
+
+#[test]
+fn day25_parse_error_test() {
+    let parse = |s: &str| Day25::parse(s.lines().map(String::from)).err();
+
+    assert_eq!(
+        Some(ParseError::new(2, 2, "`>`, `v` or `.`")),
+        parse("..>\n.x.")
+    );
+    assert_eq!(
+        Some(ParseError::new(2, 3, "`>`, `v` or `.`")),
+        parse("..>\n..")
+    );
+}
+
+/// A `width` by `height` sea floor where `east` and `south` percent of the
+/// spaces hold each herd. Herds on a wrapping floor can move forever, so one
+/// full row of east-facing cucumbers and one full column of south-facing ones
+/// block each other and form walls that nothing gets past.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let width = params.at_least("width", 139, 1);
+    let height = params.at_least("height", 137, 1);
+    let east = params.get("east", 25);
+    let south = params.get("south", 25);
+    let (wall_x, wall_y) = (rng.below(width), rng.below(height));
+
+    let mut out = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            let roll = rng.below(100);
+            out.push(if x == wall_x {
+                'v'
+            } else if y == wall_y || roll < east {
+                '>'
+            } else if roll < east + south {
+                'v'
+            } else {
+                '.'
+            });
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[test]
+fn day25_generate_test() {
+    let params = Params::parse(&[String::from("width=20"), String::from("height=10")]).unwrap();
+    let input = generate(&mut Rng::new(25), &params);
+    let floor = Day25::parse(input.lines().map(String::from)).unwrap();
+    assert_eq!((20, 10), (floor.width(), floor.height()));
+    Day25::part1(&floor).unwrap();
+}
@@ -0,0 +1,26 @@
+use aoc_core::{anim, Solution};
+use day25::{Cucumber, Day25, Herds, SeaFloor};
+
+fn floor(input: &str) -> SeaFloor {
+    Day25::parse(input.lines().map(String::from)).unwrap()
+}
+
+#[test]
+fn herds_test() {
+    let example = floor("v...>>.vv>\n.vv>>.vv..\n>>.>v>...v\n>>v>>.>.v.\nv>v.vv.v..\n>.>>..v...\n.vv..>.>v.\nv.v..>>v.v\n....v..v.>");
+    assert_eq!(58, Day25::part1(&example).unwrap());
+    // The starting floor, then each step where something moved
+    assert_eq!(58, anim::frames(&mut Herds::from(example)).count());
+}
+
+#[test]
+fn wrap_test() {
+    // East moves first, then south, and both wrap round the edges
+    let floor = floor("..>\nv..\n...");
+    assert_eq!(Some(Cucumber::East), floor[(2, 0)]);
+    let frames = anim::frames(&mut Herds::from(floor))
+        .take(3)
+        .collect::<Vec<_>>();
+    assert_eq!(">..\n...\nv..\n", frames[1]);
+    assert_eq!("v>.\n...\n...\n", frames[2]);
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Any input may be rejected, but only with a parse error
+fuzz_target!(|input: &str| {
+    let _ = aoc_core::check::<day05::Day05>(input);
+});